@@ -0,0 +1,1080 @@
+//! Derive macros for the [`cc-traits`](https://crates.io/crates/cc-traits) crate.
+//!
+//! Each derive macro implements the trait of the same name
+//! by forwarding it to a field of the annotated structure.
+//! The structure must either have a single field,
+//! or mark the field to delegate to with the `#[delegate]` attribute.
+//!
+//! Each implementation is bounded by the field type implementing the trait,
+//! and traits with a type parameter (such as `Get<T>`)
+//! are implemented for every parameter supported by the field.
+//!
+//! ```
+//! use cc_traits::{Collection, CollectionMut, CollectionRef, Get, GetMut, Len, MapInsert, Remove};
+//! use std::collections::HashMap;
+//!
+//! #[derive(Default, Collection, CollectionRef, CollectionMut, Len, Get, GetMut, MapInsert, Remove)]
+//! struct Registry<V>(HashMap<String, V>);
+//!
+//! #[derive(Default, Collection, CollectionRef, Len, Get)]
+//! struct Labelled {
+//!     name: &'static str,
+//!     #[delegate]
+//!     values: Vec<u32>,
+//! }
+//!
+//! let mut registry = Registry::default();
+//! registry.insert("a".to_string(), 1);
+//! *registry.get_mut("a").unwrap() += 1;
+//! assert_eq!(registry.get("a").map(|v| *v), Some(2));
+//! assert_eq!(registry.remove("a"), Some(2));
+//! assert!(registry.is_empty());
+//!
+//! let labelled = Labelled {
+//!     name: "primes",
+//!     values: vec![2, 3, 5],
+//! };
+//! assert_eq!(labelled.len(), 3);
+//! assert_eq!(labelled.get(1).map(|v| *v), Some(3));
+//! # let _ = labelled.name;
+//! ```
+//!
+//! # Enum dispatch
+//!
+//! The macros can also be derived for enums whose variants each wrap
+//! a different collection, following the same field selection rules.
+//! Each method is dispatched to the current variant by matching on it.
+//! The associated types (such as `Item` or `ItemRef<'a>`)
+//! are the ones of the first variant,
+//! and the other variants are required to use the same ones.
+//! Because of a current limitation of the type checker,
+//! requiring the equality of generic associated types (such as `ItemRef<'a>`)
+//! only works for `'static` collection types.
+//!
+//! Constructors (such as `WithCapacity::with_capacity`) build the first variant.
+//!
+//! Iterators are the exception: deriving `Iter`, `IterMut`, `MapIter` or `MapIterMut`
+//! for an enum also defines an enum iterator named after the enum
+//! (`<Enum>Iter`, `<Enum>IterMut`, `<Enum>MapIter` and `<Enum>MapIterMut`),
+//! with one variant per variant of the enum wrapping its iterator,
+//! so that each variant can use a different iterator type.
+//!
+//! ```
+//! use cc_traits::{Collection, Len, PopBack, PushBack, WithCapacity};
+//! use std::collections::VecDeque;
+//!
+//! #[derive(Collection, WithCapacity, Len, PushBack, PopBack)]
+//! enum Buffer {
+//!     Contiguous(Vec<u32>),
+//!     Ring(VecDeque<u32>),
+//! }
+//!
+//! let mut buffer = Buffer::with_capacity(4);
+//! buffer.push_back(1);
+//! assert_eq!(buffer.len(), 1);
+//!
+//! buffer = Buffer::Ring(VecDeque::from([1, 2, 3]));
+//! buffer.push_back(4);
+//! assert_eq!(buffer.len(), 4);
+//! assert_eq!(buffer.pop_back(), Some(4));
+//! ```
+//!
+//! ```
+//! use cc_traits::{Collection, CollectionRef, Iter, Keyed, KeyedRef, MapIter};
+//! use std::collections::{BTreeMap, HashMap, LinkedList};
+//!
+//! #[derive(Collection, CollectionRef, Iter)]
+//! enum Values {
+//!     Vec(Vec<u32>),
+//!     List(LinkedList<u32>),
+//! }
+//!
+//! #[derive(Collection, CollectionRef, Keyed, KeyedRef, MapIter)]
+//! enum Index {
+//!     Hashed(HashMap<u32, char>),
+//!     Sorted(BTreeMap<u32, char>),
+//! }
+//!
+//! let values = Values::List(LinkedList::from([1, 2, 3]));
+//! assert_eq!(values.iter().map(|v| *v).sum::<u32>(), 6);
+//! assert!(matches!(values.iter(), ValuesIter::List(_)));
+//!
+//! let index = Index::Sorted(BTreeMap::from([(2, 'b'), (1, 'a')]));
+//! let keys: Vec<_> = index.iter().map(|(k, _)| *k).collect();
+//! assert_eq!(keys, [1, 2]);
+//! ```
+//!
+//! You should not depend on this crate directly,
+//! but enable the `derive` feature of `cc-traits` instead.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+	parse_macro_input, parse_quote, Data, DeriveInput, Fields, Generics, Ident, Member, Type,
+	Visibility,
+};
+
+/// Field to delegate the implementations to.
+struct Target {
+	/// Path of the structure or variant holding the field.
+	path: TokenStream2,
+
+	/// Name of the structure or variant.
+	ident: Ident,
+	fields: Fields,
+	index: usize,
+	ty: Type,
+	member: Member,
+}
+
+impl Target {
+	fn new(path: TokenStream2, ident: &Ident, fields: Fields) -> syn::Result<Self> {
+		let index =
+			if fields.len() == 1 {
+				0
+			} else {
+				let mut marked = fields
+					.iter()
+					.enumerate()
+					.filter(|(_, f)| f.attrs.iter().any(|a| a.path().is_ident("delegate")));
+
+				match (marked.next(), marked.next()) {
+					(Some((i, _)), None) => i,
+					_ => return Err(syn::Error::new_spanned(
+						ident,
+						"expected a single field, or exactly one field marked with `#[delegate]`",
+					)),
+				}
+			};
+
+		let field = fields.iter().nth(index).unwrap();
+		let member = match &field.ident {
+			Some(ident) => Member::Named(ident.clone()),
+			None => Member::Unnamed(index.into()),
+		};
+
+		Ok(Self {
+			path,
+			ident: ident.clone(),
+			ty: field.ty.clone(),
+			fields,
+			index,
+			member,
+		})
+	}
+}
+
+/// Structure or enum whose trait implementations are delegated to its fields.
+struct Delegate {
+	vis: Visibility,
+	ident: Ident,
+	generics: Generics,
+	is_enum: bool,
+
+	/// Delegated fields, one per variant for enums.
+	targets: Vec<Target>,
+}
+
+impl Delegate {
+	fn new(input: DeriveInput) -> syn::Result<Self> {
+		let (is_enum, targets) = match input.data {
+			Data::Struct(s) => (
+				false,
+				vec![Target::new(quote!(Self), &input.ident, s.fields)?],
+			),
+			Data::Enum(e) => {
+				let targets = e
+					.variants
+					.into_iter()
+					.map(|v| {
+						let ident = v.ident;
+						Target::new(quote!(Self::#ident), &ident, v.fields)
+					})
+					.collect::<syn::Result<Vec<_>>>()?;
+
+				if targets.is_empty() {
+					return Err(syn::Error::new_spanned(
+						&input.ident,
+						"cc-traits traits cannot be derived for empty enums",
+					));
+				}
+
+				(true, targets)
+			}
+			Data::Union(_) => {
+				return Err(syn::Error::new_spanned(
+					&input.ident,
+					"cc-traits traits cannot be derived for unions",
+				))
+			}
+		};
+
+		Ok(Self {
+			vis: input.vis,
+			ident: input.ident,
+			generics: input.generics,
+			is_enum,
+			targets,
+		})
+	}
+
+	/// Type of the first delegated field, providing the associated types.
+	fn first(&self) -> &Type {
+		&self.targets[0].ty
+	}
+
+	/// Builds `Self` (or its first variant) from the given delegate value,
+	/// using the default value for the other fields.
+	fn construct(&self, value: TokenStream2) -> TokenStream2 {
+		let target = &self.targets[0];
+		let values = target.fields.iter().enumerate().map(|(i, f)| {
+			let value = if i == target.index {
+				value.clone()
+			} else {
+				quote!(::core::default::Default::default())
+			};
+
+			match &f.ident {
+				Some(ident) => quote!(#ident: #value),
+				None => value,
+			}
+		});
+
+		let path = &target.path;
+		match &target.fields {
+			Fields::Named(_) => quote!(#path { #(#values),* }),
+			_ => quote!(#path(#(#values),*)),
+		}
+	}
+
+	/// Calls a method on the delegated field.
+	///
+	/// The `call` function is given, for each target,
+	/// the `<T as Trait>` qualified path and a reference to the field.
+	/// For enums, this generates a `match` on the current variant.
+	fn dispatch(
+		&self,
+		trait_: &TokenStream2,
+		mutable: bool,
+		call: impl Fn(TokenStream2, TokenStream2) -> TokenStream2,
+	) -> TokenStream2 {
+		self.dispatch_targets(trait_, mutable, |_, inner, field| call(inner, field))
+	}
+
+	/// Like [`Self::dispatch`], but wraps the result for each variant of an enum
+	/// in the variant of the same name of the `wrapper` enum.
+	fn dispatch_wrapped(
+		&self,
+		trait_: &TokenStream2,
+		mutable: bool,
+		wrapper: &Ident,
+		call: impl Fn(TokenStream2, TokenStream2) -> TokenStream2,
+	) -> TokenStream2 {
+		self.dispatch_targets(trait_, mutable, |t, inner, field| {
+			let body = call(inner, field);
+			if self.is_enum {
+				let ident = &t.ident;
+				quote!(#wrapper::#ident(#body))
+			} else {
+				body
+			}
+		})
+	}
+
+	fn dispatch_targets(
+		&self,
+		trait_: &TokenStream2,
+		mutable: bool,
+		call: impl Fn(&Target, TokenStream2, TokenStream2) -> TokenStream2,
+	) -> TokenStream2 {
+		if self.is_enum {
+			let arms = self.targets.iter().map(|t| {
+				let path = &t.path;
+				let member = &t.member;
+				let ty = &t.ty;
+				let body = call(t, quote!(<#ty as #trait_>), quote!(__field));
+				quote!(#path { #member: __field, .. } => #body)
+			});
+
+			quote! {
+				match self {
+					#(#arms),*
+				}
+			}
+		} else {
+			let t = &self.targets[0];
+			let member = &t.member;
+			let ty = &t.ty;
+			let field = if mutable {
+				quote!(&mut self.#member)
+			} else {
+				quote!(&self.#member)
+			};
+
+			call(t, quote!(<#ty as #trait_>), field)
+		}
+	}
+
+	/// Defines the `name` enum iterator,
+	/// with one variant per variant of the enum wrapping the iterator of its field.
+	fn iterator_enum(&self, name: &Ident) -> TokenStream2 {
+		let vis = &self.vis;
+		let params: Vec<_> = (0..self.targets.len())
+			.map(|i| format_ident!("__I{}", i))
+			.collect();
+		let variants: Vec<_> = self.targets.iter().map(|t| &t.ident).collect();
+		let first = &params[0];
+		let rest = &params[1..];
+
+		let doc = format!(
+			"Iterator over a [`{}`], wrapping the iterator of the current variant.",
+			self.ident
+		);
+		let variant_docs = variants
+			.iter()
+			.map(|v| format!("Iterator of the `{}` variant.", v));
+
+		quote! {
+			#[doc = #doc]
+			#vis enum #name<#(#params),*> {
+				#(
+					#[doc = #variant_docs]
+					#variants(#params)
+				),*
+			}
+
+			impl<#(#params),*> ::core::iter::Iterator for #name<#(#params),*>
+			where
+				#first: ::core::iter::Iterator,
+				#(#rest: ::core::iter::Iterator<Item = <#first as ::core::iter::Iterator>::Item>,)*
+			{
+				type Item = <#first as ::core::iter::Iterator>::Item;
+
+				#[inline(always)]
+				fn next(&mut self) -> ::core::option::Option<Self::Item> {
+					match self {
+						#(Self::#variants(__iter) => __iter.next()),*
+					}
+				}
+
+				#[inline(always)]
+				fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+					match self {
+						#(Self::#variants(__iter) => __iter.size_hint()),*
+					}
+				}
+			}
+
+			impl<#(#params),*> ::core::iter::DoubleEndedIterator for #name<#(#params),*>
+			where
+				#first: ::core::iter::DoubleEndedIterator,
+				#(#rest: ::core::iter::DoubleEndedIterator<Item = <#first as ::core::iter::Iterator>::Item>,)*
+			{
+				#[inline(always)]
+				fn next_back(&mut self) -> ::core::option::Option<Self::Item> {
+					match self {
+						#(Self::#variants(__iter) => __iter.next_back()),*
+					}
+				}
+			}
+		}
+	}
+
+	/// Implements `trait_` for the structure, provided the delegate types implement it.
+	///
+	/// The `equal` associated types of the delegate types other than the first one
+	/// are required to be equal to the ones of the first.
+	fn implement(
+		&self,
+		trait_: &TokenStream2,
+		param: bool,
+		equal: &[Equal],
+		body: TokenStream2,
+	) -> TokenStream2 {
+		let mut generics = self.generics.clone();
+		if param {
+			generics.params.push(parse_quote!(__Q));
+		}
+
+		let first = self.first();
+		let where_clause = generics.make_where_clause();
+		for (i, t) in self.targets.iter().enumerate() {
+			let ty = &t.ty;
+			where_clause.predicates.push(parse_quote!(#ty: #trait_));
+
+			if i > 0 {
+				for e in equal {
+					where_clause.predicates.push(e.predicate(first, ty));
+				}
+			}
+		}
+
+		let ident = &self.ident;
+		let (impl_generics, _, where_clause) = generics.split_for_impl();
+		let (_, ty_generics, _) = self.generics.split_for_impl();
+
+		quote! {
+			impl #impl_generics #trait_ for #ident #ty_generics #where_clause {
+				#body
+			}
+		}
+	}
+}
+
+/// Associated type that must be equal among the delegate types.
+struct Equal {
+	trait_: TokenStream2,
+	name: &'static str,
+
+	/// Whether the associated type has a lifetime parameter.
+	generic: bool,
+
+	/// Whether the trait takes the `__Q` type parameter.
+	param: bool,
+}
+
+impl Equal {
+	fn new(trait_: TokenStream2, name: &'static str) -> Self {
+		Self {
+			trait_,
+			name,
+			generic: false,
+			param: false,
+		}
+	}
+
+	fn with_param(trait_: TokenStream2, name: &'static str) -> Self {
+		Self {
+			trait_,
+			name,
+			generic: false,
+			param: true,
+		}
+	}
+
+	fn generic(trait_: TokenStream2, name: &'static str) -> Self {
+		Self {
+			trait_,
+			name,
+			generic: true,
+			param: false,
+		}
+	}
+
+	fn predicate(&self, first: &Type, ty: &Type) -> syn::WherePredicate {
+		let trait_ = &self.trait_;
+		let name = format_ident!("{}", self.name);
+		if self.generic {
+			parse_quote!(#ty: for<'a> #trait_<#name<'a> = <#first as #trait_>::#name<'a>>)
+		} else if self.param {
+			parse_quote!(#ty: #trait_<__Q, #name = <#first as #trait_<__Q>>::#name>)
+		} else {
+			parse_quote!(#ty: #trait_<#name = <#first as #trait_>::#name>)
+		}
+	}
+}
+
+fn collection_item() -> Equal {
+	Equal::new(quote!(::cc_traits::Collection), "Item")
+}
+
+fn collection_item_ref() -> Equal {
+	Equal::generic(quote!(::cc_traits::CollectionRef), "ItemRef")
+}
+
+fn collection_item_mut() -> Equal {
+	Equal::generic(quote!(::cc_traits::CollectionMut), "ItemMut")
+}
+
+fn keyed_key() -> Equal {
+	Equal::new(quote!(::cc_traits::Keyed), "Key")
+}
+
+fn keyed_key_ref() -> Equal {
+	Equal::generic(quote!(::cc_traits::KeyedRef), "KeyRef")
+}
+
+fn derive(
+	input: TokenStream,
+	trait_: TokenStream2,
+	param: bool,
+	equal: impl FnOnce(&TokenStream2) -> Vec<Equal>,
+	body: impl FnOnce(&Delegate, &TokenStream2) -> TokenStream2,
+) -> TokenStream {
+	match Delegate::new(parse_macro_input!(input as DeriveInput)) {
+		Ok(d) => {
+			let equal = equal(&trait_);
+			let trait_ = if param { quote!(#trait_<__Q>) } else { trait_ };
+			let body = body(&d, &trait_);
+			d.implement(&trait_, param, &equal, body).into()
+		}
+		Err(e) => e.to_compile_error().into(),
+	}
+}
+
+/// Derives `cc_traits::Collection`.
+#[proc_macro_derive(Collection, attributes(delegate))]
+pub fn derive_collection(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Collection),
+		false,
+		|_| vec![collection_item()],
+		|d, t| {
+			let first = d.first();
+			quote! {
+				type Item = <#first as #t>::Item;
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::CollectionRef`.
+#[proc_macro_derive(CollectionRef, attributes(delegate))]
+pub fn derive_collection_ref(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::CollectionRef),
+		false,
+		|_| vec![collection_item(), collection_item_ref()],
+		|d, t| {
+			let first = d.first();
+			quote! {
+				type ItemRef<'a> = <#first as #t>::ItemRef<'a> where Self: 'a;
+
+				#[inline(always)]
+				fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+				where
+					Self: 'long,
+				{
+					<#first as #t>::upcast_item_ref(r)
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::CollectionMut`.
+#[proc_macro_derive(CollectionMut, attributes(delegate))]
+pub fn derive_collection_mut(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::CollectionMut),
+		false,
+		|_| vec![collection_item(), collection_item_mut()],
+		|d, t| {
+			let first = d.first();
+			quote! {
+				type ItemMut<'a> = <#first as #t>::ItemMut<'a> where Self: 'a;
+
+				#[inline(always)]
+				fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+				where
+					Self: 'long,
+				{
+					<#first as #t>::upcast_item_mut(r)
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::Keyed`.
+#[proc_macro_derive(Keyed, attributes(delegate))]
+pub fn derive_keyed(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Keyed),
+		false,
+		|t| vec![collection_item(), Equal::new(t.clone(), "Key")],
+		|d, t| {
+			let first = d.first();
+			quote! {
+				type Key = <#first as #t>::Key;
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::KeyedRef`.
+#[proc_macro_derive(KeyedRef, attributes(delegate))]
+pub fn derive_keyed_ref(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::KeyedRef),
+		false,
+		|t| {
+			vec![
+				collection_item(),
+				keyed_key(),
+				Equal::generic(t.clone(), "KeyRef"),
+			]
+		},
+		|d, t| {
+			let first = d.first();
+			quote! {
+				type KeyRef<'a> = <#first as #t>::KeyRef<'a> where Self: 'a;
+
+				#[inline(always)]
+				fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+				where
+					Self: 'long,
+				{
+					<#first as #t>::upcast_key_ref(r)
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::WithCapacity`.
+///
+/// The other fields of the structure are initialized with their default value.
+#[proc_macro_derive(WithCapacity, attributes(delegate))]
+pub fn derive_with_capacity(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::WithCapacity),
+		false,
+		|_| Vec::new(),
+		|d, t| {
+			let first = d.first();
+			let value = d.construct(quote!(<#first as #t>::with_capacity(capacity)));
+			quote! {
+				#[inline(always)]
+				fn with_capacity(capacity: usize) -> Self {
+					#value
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::Len`.
+#[proc_macro_derive(Len, attributes(delegate))]
+pub fn derive_len(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Len),
+		false,
+		|_| Vec::new(),
+		|d, t| {
+			let len = d.dispatch(t, false, |inner, c| quote!(#inner::len(#c)));
+			let is_empty = d.dispatch(t, false, |inner, c| quote!(#inner::is_empty(#c)));
+			quote! {
+				#[inline(always)]
+				fn len(&self) -> usize {
+					#len
+				}
+
+				#[inline(always)]
+				fn is_empty(&self) -> bool {
+					#is_empty
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::Capacity`.
+#[proc_macro_derive(Capacity, attributes(delegate))]
+pub fn derive_capacity(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Capacity),
+		false,
+		|_| Vec::new(),
+		|d, t| {
+			let capacity = d.dispatch(t, false, |inner, c| quote!(#inner::capacity(#c)));
+			quote! {
+				#[inline(always)]
+				fn capacity(&self) -> usize {
+					#capacity
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::Reserve`.
+#[proc_macro_derive(Reserve, attributes(delegate))]
+pub fn derive_reserve(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Reserve),
+		false,
+		|_| Vec::new(),
+		|d, t| {
+			let reserve = d.dispatch(t, true, |inner, c| quote!(#inner::reserve(#c, additional)));
+			quote! {
+				#[inline(always)]
+				fn reserve(&mut self, additional: usize) {
+					#reserve
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::Clear`.
+#[proc_macro_derive(Clear, attributes(delegate))]
+pub fn derive_clear(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Clear),
+		false,
+		|_| Vec::new(),
+		|d, t| {
+			let clear = d.dispatch(t, true, |inner, c| quote!(#inner::clear(#c)));
+			quote! {
+				#[inline(always)]
+				fn clear(&mut self) {
+					#clear
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::Get<Q>` for every `Q` supported by the field.
+#[proc_macro_derive(Get, attributes(delegate))]
+pub fn derive_get(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Get),
+		true,
+		|_| vec![collection_item(), collection_item_ref()],
+		|d, t| {
+			let get = d.dispatch(t, false, |inner, c| quote!(#inner::get(#c, key)));
+			let contains = d.dispatch(t, false, |inner, c| quote!(#inner::contains(#c, key)));
+			quote! {
+				#[inline(always)]
+				fn get(&self, key: __Q) -> Option<Self::ItemRef<'_>> {
+					#get
+				}
+
+				#[inline(always)]
+				fn contains(&self, key: __Q) -> bool {
+					#contains
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::GetMut<Q>` for every `Q` supported by the field.
+#[proc_macro_derive(GetMut, attributes(delegate))]
+pub fn derive_get_mut(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::GetMut),
+		true,
+		|_| {
+			vec![
+				collection_item(),
+				collection_item_ref(),
+				collection_item_mut(),
+			]
+		},
+		|d, t| {
+			let get_mut = d.dispatch(t, true, |inner, c| quote!(#inner::get_mut(#c, key)));
+			quote! {
+				#[inline(always)]
+				fn get_mut(&mut self, key: __Q) -> Option<Self::ItemMut<'_>> {
+					#get_mut
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::Insert`.
+#[proc_macro_derive(Insert, attributes(delegate))]
+pub fn derive_insert(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Insert),
+		false,
+		|t| vec![collection_item(), Equal::new(t.clone(), "Output")],
+		|d, t| {
+			let first = d.first();
+			let insert = d.dispatch(t, true, |inner, c| quote!(#inner::insert(#c, element)));
+			quote! {
+				type Output = <#first as #t>::Output;
+
+				#[inline(always)]
+				fn insert(&mut self, element: Self::Item) -> Self::Output {
+					#insert
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::MapInsert<K>` for every `K` supported by the field.
+#[proc_macro_derive(MapInsert, attributes(delegate))]
+pub fn derive_map_insert(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::MapInsert),
+		true,
+		|t| vec![collection_item(), Equal::with_param(t.clone(), "Output")],
+		|d, t| {
+			let first = d.first();
+			let insert = d.dispatch(t, true, |inner, c| quote!(#inner::insert(#c, key, value)));
+			quote! {
+				type Output = <#first as #t>::Output;
+
+				#[inline(always)]
+				fn insert(&mut self, key: __Q, value: Self::Item) -> Self::Output {
+					#insert
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::Remove<Q>` for every `Q` supported by the field.
+#[proc_macro_derive(Remove, attributes(delegate))]
+pub fn derive_remove(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::Remove),
+		true,
+		|_| vec![collection_item()],
+		|d, t| {
+			let remove = d.dispatch(t, true, |inner, c| quote!(#inner::remove(#c, key)));
+			quote! {
+				#[inline(always)]
+				fn remove(&mut self, key: __Q) -> Option<Self::Item> {
+					#remove
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::PushFront`.
+#[proc_macro_derive(PushFront, attributes(delegate))]
+pub fn derive_push_front(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::PushFront),
+		false,
+		|t| vec![collection_item(), Equal::new(t.clone(), "Output")],
+		|d, t| {
+			let first = d.first();
+			let push = d.dispatch(t, true, |inner, c| quote!(#inner::push_front(#c, element)));
+			quote! {
+				type Output = <#first as #t>::Output;
+
+				#[inline(always)]
+				fn push_front(&mut self, element: Self::Item) -> Self::Output {
+					#push
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::PushBack`.
+#[proc_macro_derive(PushBack, attributes(delegate))]
+pub fn derive_push_back(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::PushBack),
+		false,
+		|t| vec![collection_item(), Equal::new(t.clone(), "Output")],
+		|d, t| {
+			let first = d.first();
+			let push = d.dispatch(t, true, |inner, c| quote!(#inner::push_back(#c, element)));
+			quote! {
+				type Output = <#first as #t>::Output;
+
+				#[inline(always)]
+				fn push_back(&mut self, element: Self::Item) -> Self::Output {
+					#push
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::PopFront`.
+#[proc_macro_derive(PopFront, attributes(delegate))]
+pub fn derive_pop_front(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::PopFront),
+		false,
+		|_| vec![collection_item()],
+		|d, t| {
+			let pop = d.dispatch(t, true, |inner, c| quote!(#inner::pop_front(#c)));
+			quote! {
+				#[inline(always)]
+				fn pop_front(&mut self) -> Option<Self::Item> {
+					#pop
+				}
+			}
+		},
+	)
+}
+
+/// Derives `cc_traits::PopBack`.
+#[proc_macro_derive(PopBack, attributes(delegate))]
+pub fn derive_pop_back(input: TokenStream) -> TokenStream {
+	derive(
+		input,
+		quote!(::cc_traits::PopBack),
+		false,
+		|_| vec![collection_item()],
+		|d, t| {
+			let pop = d.dispatch(t, true, |inner, c| quote!(#inner::pop_back(#c)));
+			quote! {
+				#[inline(always)]
+				fn pop_back(&mut self) -> Option<Self::Item> {
+					#pop
+				}
+			}
+		},
+	)
+}
+
+/// Derives an iterator trait,
+/// whose `assoc` associated type is returned by the `method` method
+/// (taking `&mut self` if `mutable`).
+///
+/// For enums, the iterators of the variants are wrapped in an enum iterator
+/// named after the enum and the `suffix`,
+/// so that each variant can use a different iterator type.
+fn derive_iterator(
+	input: TokenStream,
+	trait_: TokenStream2,
+	equal: Vec<Equal>,
+	assoc: &str,
+	method: &str,
+	mutable: bool,
+	suffix: &str,
+) -> TokenStream {
+	let d = match Delegate::new(parse_macro_input!(input as DeriveInput)) {
+		Ok(d) => d,
+		Err(e) => return e.to_compile_error().into(),
+	};
+
+	let assoc = format_ident!("{}", assoc);
+	let method = format_ident!("{}", method);
+	let receiver = if mutable {
+		quote!(&mut self)
+	} else {
+		quote!(&self)
+	};
+	let wrapper = format_ident!("{}{}", d.ident, suffix);
+	let iter = d.dispatch_wrapped(
+		&trait_,
+		mutable,
+		&wrapper,
+		|inner, c| quote!(#inner::#method(#c)),
+	);
+
+	let (definition, ty) = if d.is_enum {
+		let iters = d.targets.iter().map(|t| {
+			let ty = &t.ty;
+			quote!(<#ty as #trait_>::#assoc<'a>)
+		});
+
+		(d.iterator_enum(&wrapper), quote!(#wrapper<#(#iters),*>))
+	} else {
+		let first = d.first();
+		(quote!(), quote!(<#first as #trait_>::#assoc<'a>))
+	};
+
+	let body = quote! {
+		type #assoc<'a> = #ty where Self: 'a;
+
+		#[inline(always)]
+		fn #method(#receiver) -> Self::#assoc<'_> {
+			#iter
+		}
+	};
+
+	let implementation = d.implement(&trait_, false, &equal, body);
+	quote!(#definition #implementation).into()
+}
+
+/// Derives `cc_traits::Iter`.
+///
+/// When derived for an enum, this also defines the `<Enum>Iter` enum iterator,
+/// so that each variant can use a different iterator type.
+#[proc_macro_derive(Iter, attributes(delegate))]
+pub fn derive_iter(input: TokenStream) -> TokenStream {
+	derive_iterator(
+		input,
+		quote!(::cc_traits::Iter),
+		vec![collection_item(), collection_item_ref()],
+		"Iter",
+		"iter",
+		false,
+		"Iter",
+	)
+}
+
+/// Derives `cc_traits::IterMut`.
+///
+/// When derived for an enum, this also defines the `<Enum>IterMut` enum iterator,
+/// so that each variant can use a different iterator type.
+#[proc_macro_derive(IterMut, attributes(delegate))]
+pub fn derive_iter_mut(input: TokenStream) -> TokenStream {
+	derive_iterator(
+		input,
+		quote!(::cc_traits::IterMut),
+		vec![collection_item(), collection_item_mut()],
+		"IterMut",
+		"iter_mut",
+		true,
+		"IterMut",
+	)
+}
+
+/// Derives `cc_traits::MapIter`.
+///
+/// When derived for an enum, this also defines the `<Enum>MapIter` enum iterator,
+/// so that each variant can use a different iterator type.
+#[proc_macro_derive(MapIter, attributes(delegate))]
+pub fn derive_map_iter(input: TokenStream) -> TokenStream {
+	derive_iterator(
+		input,
+		quote!(::cc_traits::MapIter),
+		vec![
+			collection_item(),
+			collection_item_ref(),
+			keyed_key(),
+			keyed_key_ref(),
+		],
+		"Iter",
+		"iter",
+		false,
+		"MapIter",
+	)
+}
+
+/// Derives `cc_traits::MapIterMut`.
+///
+/// When derived for an enum, this also defines the `<Enum>MapIterMut` enum iterator,
+/// so that each variant can use a different iterator type.
+#[proc_macro_derive(MapIterMut, attributes(delegate))]
+pub fn derive_map_iter_mut(input: TokenStream) -> TokenStream {
+	derive_iterator(
+		input,
+		quote!(::cc_traits::MapIterMut),
+		vec![
+			collection_item(),
+			collection_item_mut(),
+			keyed_key(),
+			keyed_key_ref(),
+		],
+		"IterMut",
+		"iter_mut",
+		true,
+		"MapIterMut",
+	)
+}
@@ -1,6 +1,7 @@
 use cc_traits::{Back, Collection, PushBack};
 
 /// Ordered stack.
+#[derive(Default)]
 pub struct Ordered<S> {
 	inner: S,
 }
@@ -0,0 +1,302 @@
+use crate::{
+	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Insert,
+	Iter, IterMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapIterMut, PopBack, PopFront,
+	PushBack, PushFront, Remove, Update,
+};
+use std::sync::Arc;
+
+/// Copy-on-write shared collection.
+///
+/// Cloning an `ArcMap` is cheap, since the wrapped collection is shared behind an [`Arc`].
+/// Read-only traits are implemented by dereferencing the `Arc`,
+/// while mutable traits first clone the wrapped collection
+/// if it is shared (see [`Arc::make_mut`]).
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{ArcMap, Get, MapInsert};
+/// use std::collections::HashMap;
+///
+/// let mut map = ArcMap::new(HashMap::new());
+/// map.insert("a", 1);
+///
+/// let snapshot = map.clone();
+/// map.insert("a", 2);
+///
+/// assert_eq!(map.get(&"a").map(|v| *v), Some(2));
+/// assert_eq!(snapshot.get(&"a").map(|v| *v), Some(1));
+/// ```
+pub struct ArcMap<C> {
+	collection: Arc<C>,
+}
+
+impl<C> ArcMap<C> {
+	/// Wraps the given `collection` in a new shared collection.
+	pub fn new(collection: C) -> Self {
+		Self::from_arc(Arc::new(collection))
+	}
+
+	/// Wraps an already shared collection.
+	pub fn from_arc(collection: Arc<C>) -> Self {
+		Self { collection }
+	}
+
+	/// Returns a mutable reference to the wrapped collection,
+	/// cloning it first if it is shared.
+	pub fn make_mut(&mut self) -> &mut C
+	where
+		C: Clone,
+	{
+		Arc::make_mut(&mut self.collection)
+	}
+
+	/// Checks if the wrapped collection is shared with other `ArcMap`s or `Arc`s.
+	pub fn is_shared(&self) -> bool {
+		Arc::strong_count(&self.collection) > 1 || Arc::weak_count(&self.collection) > 0
+	}
+
+	/// Returns the shared collection.
+	pub fn into_arc(self) -> Arc<C> {
+		self.collection
+	}
+}
+
+impl<C> Clone for ArcMap<C> {
+	fn clone(&self) -> Self {
+		Self::from_arc(self.collection.clone())
+	}
+}
+
+impl<C: Default> Default for ArcMap<C> {
+	fn default() -> Self {
+		Self::new(C::default())
+	}
+}
+
+impl<C> From<Arc<C>> for ArcMap<C> {
+	fn from(collection: Arc<C>) -> Self {
+		Self::from_arc(collection)
+	}
+}
+
+impl<C: Collection> Collection for ArcMap<C> {
+	type Item = C::Item;
+}
+
+impl<C: CollectionRef> CollectionRef for ArcMap<C> {
+	type ItemRef<'a>
+		= C::ItemRef<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_ref(r)
+	}
+}
+
+impl<C: CollectionMut + Clone> CollectionMut for ArcMap<C> {
+	type ItemMut<'a>
+		= C::ItemMut<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_mut(r)
+	}
+}
+
+impl<C: Keyed> Keyed for ArcMap<C> {
+	type Key = C::Key;
+}
+
+impl<C: KeyedRef> KeyedRef for ArcMap<C> {
+	type KeyRef<'a>
+		= C::KeyRef<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_key_ref(r)
+	}
+}
+
+impl<C: Len> Len for ArcMap<C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.collection.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.collection.is_empty()
+	}
+}
+
+impl<C: Capacity> Capacity for ArcMap<C> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.collection.capacity()
+	}
+}
+
+impl<Q, C: Get<Q>> Get<Q> for ArcMap<C> {
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		self.collection.get(key)
+	}
+}
+
+impl<Q, C: GetKeyValue<Q>> GetKeyValue<Q> for ArcMap<C> {
+	#[inline(always)]
+	fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		self.collection.get_key_value(key)
+	}
+}
+
+impl<Q, C: GetMut<Q> + Clone> GetMut<Q> for ArcMap<C> {
+	#[inline(always)]
+	fn get_mut(&mut self, key: Q) -> Option<Self::ItemMut<'_>> {
+		self.make_mut().get_mut(key)
+	}
+}
+
+impl<C: Insert + Clone> Insert for ArcMap<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, element: Self::Item) -> Self::Output {
+		self.make_mut().insert(element)
+	}
+}
+
+impl<K, C: MapInsert<K> + Clone> MapInsert<K> for ArcMap<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: Self::Item) -> Self::Output {
+		self.make_mut().insert(key, value)
+	}
+}
+
+impl<Q, C: Remove<Q> + Clone> Remove<Q> for ArcMap<C> {
+	#[inline(always)]
+	fn remove(&mut self, key: Q) -> Option<Self::Item> {
+		self.make_mut().remove(key)
+	}
+}
+
+impl<C: Update + Clone> Update for ArcMap<C> {
+	#[inline(always)]
+	fn update<F>(&mut self, key: Self::Key, f: F)
+	where
+		F: FnOnce(Option<Self::Item>) -> Option<Self::Item>,
+	{
+		self.make_mut().update(key, f)
+	}
+}
+
+impl<C: PushFront + Clone> PushFront for ArcMap<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn push_front(&mut self, element: Self::Item) -> Self::Output {
+		self.make_mut().push_front(element)
+	}
+}
+
+impl<C: PushBack + Clone> PushBack for ArcMap<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn push_back(&mut self, element: Self::Item) -> Self::Output {
+		self.make_mut().push_back(element)
+	}
+}
+
+impl<C: PopFront + Clone> PopFront for ArcMap<C> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<Self::Item> {
+		self.make_mut().pop_front()
+	}
+}
+
+impl<C: PopBack + Clone> PopBack for ArcMap<C> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<Self::Item> {
+		self.make_mut().pop_back()
+	}
+}
+
+impl<C: Clear + Default> Clear for ArcMap<C> {
+	/// Clears the collection.
+	///
+	/// A shared collection is replaced by a new empty collection instead of being cloned.
+	#[inline(always)]
+	fn clear(&mut self) {
+		match Arc::get_mut(&mut self.collection) {
+			Some(collection) => collection.clear(),
+			None => self.collection = Arc::new(C::default()),
+		}
+	}
+}
+
+impl<C: Iter> Iter for ArcMap<C> {
+	type Iter<'a>
+		= C::Iter<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.collection.iter()
+	}
+}
+
+impl<C: IterMut + Clone> IterMut for ArcMap<C> {
+	type IterMut<'a>
+		= C::IterMut<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.make_mut().iter_mut()
+	}
+}
+
+impl<C: MapIter> MapIter for ArcMap<C> {
+	type Iter<'a>
+		= C::Iter<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> <Self as MapIter>::Iter<'_> {
+		MapIter::iter(&*self.collection)
+	}
+}
+
+impl<C: MapIterMut + Clone> MapIterMut for ArcMap<C> {
+	type IterMut<'a>
+		= C::IterMut<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		MapIterMut::iter_mut(self.make_mut())
+	}
+}
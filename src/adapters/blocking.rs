@@ -0,0 +1,126 @@
+use crate::{
+	AsyncCollection, AsyncGet, AsyncInsert, AsyncLen, AsyncRemove, Collection, CollectionRef, Get,
+	Len, LockRef, MapInsert, Remove,
+};
+use std::{
+	convert::Infallible,
+	sync::{RwLock, RwLockReadGuard},
+};
+
+const POISONED_LOCK: &str = "poisoned lock";
+
+/// Adapter implementing the asynchronous traits for a synchronous collection.
+///
+/// The collection is stored behind a [`RwLock`](std::sync::RwLock),
+/// so that the mutating operations can take `&self` like the asynchronous traits require.
+/// Each operation is performed when its future is first polled,
+/// completes immediately and never fails.
+///
+/// References returned by [`AsyncGet::get`] hold the read lock until they are dropped,
+/// so they should not be kept across an insertion or removal on the same task.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{AsyncGet, AsyncInsert, AsyncLen, AsyncRemove, Blocking};
+/// use std::{
+///   collections::HashMap,
+///   future::Future,
+///   pin::pin,
+///   task::{Context, Poll, Waker},
+/// };
+///
+/// fn ready<F: Future>(f: F) -> F::Output {
+///   match pin!(f).poll(&mut Context::from_waker(Waker::noop())) {
+///     Poll::Ready(output) => output,
+///     Poll::Pending => unreachable!(),
+///   }
+/// }
+///
+/// let map = Blocking::new(HashMap::new());
+/// ready(map.insert("a", 1)).unwrap();
+/// assert_eq!(ready(map.get(&"a")).unwrap().map(|v| *v), Some(1));
+/// assert_eq!(ready(map.remove(&"a")).unwrap(), Some(1));
+/// assert!(ready(map.is_empty()).unwrap());
+/// ```
+pub struct Blocking<C> {
+	collection: RwLock<C>,
+}
+
+impl<C> Blocking<C> {
+	/// Wraps the given `collection`.
+	pub fn new(collection: C) -> Self {
+		Self {
+			collection: RwLock::new(collection),
+		}
+	}
+
+	/// Returns a mutable reference to the wrapped collection.
+	pub fn inner_mut(&mut self) -> &mut C {
+		self.collection.get_mut().expect(POISONED_LOCK)
+	}
+
+	/// Returns the wrapped collection.
+	pub fn into_inner(self) -> C {
+		self.collection.into_inner().expect(POISONED_LOCK)
+	}
+}
+
+impl<C: Default> Default for Blocking<C> {
+	fn default() -> Self {
+		Self::new(C::default())
+	}
+}
+
+impl<C: Collection> Collection for Blocking<C> {
+	type Item = C::Item;
+}
+
+impl<C: Collection> AsyncCollection for Blocking<C> {
+	type Error = Infallible;
+}
+
+impl<C: Collection + Len + Send + Sync> AsyncLen for Blocking<C> {
+	async fn len(&self) -> Result<usize, Infallible> {
+		Ok(self.collection.read().expect(POISONED_LOCK).len())
+	}
+}
+
+impl<T: Send, C> AsyncGet<T> for Blocking<C>
+where
+	C: 'static
+		+ Send
+		+ Sync
+		+ Get<T>
+		+ for<'a> CollectionRef<ItemRef<'a> = &'a <C as Collection>::Item>,
+{
+	type Ref<'a>
+		= LockRef<RwLockReadGuard<'a, C>, C::Item>
+	where
+		Self: 'a;
+
+	async fn get(&self, key: T) -> Result<Option<Self::Ref<'_>>, Infallible> {
+		Ok(self.collection.get(key))
+	}
+}
+
+impl<K: Send, C: MapInsert<K> + Send + Sync> AsyncInsert<K> for Blocking<C>
+where
+	C::Item: Send,
+{
+	type Output = C::Output;
+
+	async fn insert(&self, key: K, value: C::Item) -> Result<C::Output, Infallible> {
+		Ok(self
+			.collection
+			.write()
+			.expect(POISONED_LOCK)
+			.insert(key, value))
+	}
+}
+
+impl<T: Send, C: Remove<T> + Send + Sync> AsyncRemove<T> for Blocking<C> {
+	async fn remove(&self, key: T) -> Result<Option<C::Item>, Infallible> {
+		Ok(self.collection.write().expect(POISONED_LOCK).remove(key))
+	}
+}
@@ -0,0 +1,200 @@
+use crate::{
+	Collection, CollectionRef, DynMap, DynSeq, Get, Keyed, KeyedRef, Len, MapInsert, PopBack,
+	PushBack, Remove,
+};
+
+/// Type-erased map, boxing any [`DynMap`] implementor.
+///
+/// Contrarily to `Box<dyn DynMap<K, V>>`, this type implements the
+/// traits of this crate, with plain references as item references.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{BoxedMap, Get, Len, MapInsert};
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let mut maps = vec![
+///   BoxedMap::new(HashMap::new()),
+///   BoxedMap::new(BTreeMap::new()),
+/// ];
+///
+/// for map in &mut maps {
+///   map.insert("answer".to_string(), 42);
+///   assert_eq!(map.get(&"answer".to_string()), Some(&42));
+///   assert_eq!(map.len(), 1);
+/// }
+/// ```
+pub struct BoxedMap<K, V> {
+	map: Box<dyn DynMap<K, V>>,
+}
+
+impl<K, V> BoxedMap<K, V> {
+	/// Boxes the given `map`.
+	pub fn new<C: DynMap<K, V> + 'static>(map: C) -> Self {
+		Self { map: Box::new(map) }
+	}
+
+	/// Returns the boxed map.
+	pub fn into_inner(self) -> Box<dyn DynMap<K, V>> {
+		self.map
+	}
+}
+
+impl<K, V> From<Box<dyn DynMap<K, V>>> for BoxedMap<K, V> {
+	fn from(map: Box<dyn DynMap<K, V>>) -> Self {
+		Self { map }
+	}
+}
+
+impl<K, V> Collection for BoxedMap<K, V> {
+	type Item = V;
+}
+
+impl<K, V> CollectionRef for BoxedMap<K, V> {
+	type ItemRef<'a>
+		= &'a V
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<K, V> Keyed for BoxedMap<K, V> {
+	type Key = K;
+}
+
+impl<K, V> KeyedRef for BoxedMap<K, V> {
+	type KeyRef<'a>
+		= &'a K
+	where
+		Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<K, V> Len for BoxedMap<K, V> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.map.dyn_len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.map.dyn_is_empty()
+	}
+}
+
+impl<'k, K, V> Get<&'k K> for BoxedMap<K, V> {
+	#[inline(always)]
+	fn get(&self, key: &'k K) -> Option<&V> {
+		self.map.dyn_get(key)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: &'k K) -> bool {
+		self.map.dyn_contains(key)
+	}
+}
+
+impl<K, V> MapInsert<K> for BoxedMap<K, V> {
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		self.map.dyn_insert(key, value)
+	}
+}
+
+impl<'k, K, V> Remove<&'k K> for BoxedMap<K, V> {
+	#[inline(always)]
+	fn remove(&mut self, key: &'k K) -> Option<V> {
+		self.map.dyn_remove(key)
+	}
+}
+
+/// Type-erased sequence, boxing any [`DynSeq`] implementor.
+///
+/// Contrarily to `Box<dyn DynSeq<T>>`, this type implements the
+/// traits of this crate, with plain references as item references.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Back, BoxedSeq, PopBack, PushBack};
+///
+/// let mut seq = BoxedSeq::new(std::vec::Vec::new());
+/// seq.push_back(1);
+/// seq.push_back(2);
+/// assert_eq!(seq.back(), Some(&2));
+/// assert_eq!(seq.pop_back(), Some(2));
+/// ```
+pub struct BoxedSeq<T> {
+	seq: Box<dyn DynSeq<T>>,
+}
+
+impl<T> BoxedSeq<T> {
+	/// Boxes the given `seq`.
+	pub fn new<C: DynSeq<T> + 'static>(seq: C) -> Self {
+		Self { seq: Box::new(seq) }
+	}
+
+	/// Returns the boxed sequence.
+	pub fn into_inner(self) -> Box<dyn DynSeq<T>> {
+		self.seq
+	}
+}
+
+impl<T> From<Box<dyn DynSeq<T>>> for BoxedSeq<T> {
+	fn from(seq: Box<dyn DynSeq<T>>) -> Self {
+		Self { seq }
+	}
+}
+
+impl<T> Collection for BoxedSeq<T> {
+	type Item = T;
+}
+
+impl<T> CollectionRef for BoxedSeq<T> {
+	type ItemRef<'a>
+		= &'a T
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T> Len for BoxedSeq<T> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.seq.dyn_len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.seq.dyn_is_empty()
+	}
+}
+
+impl<T> Get<usize> for BoxedSeq<T> {
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&T> {
+		self.seq.dyn_index(index)
+	}
+}
+
+impl<T> PushBack for BoxedSeq<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_back(&mut self, element: T) {
+		self.seq.dyn_push_back(element)
+	}
+}
+
+impl<T> PopBack for BoxedSeq<T> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<T> {
+		self.seq.dyn_pop_back()
+	}
+}
@@ -0,0 +1,273 @@
+use crate::{
+	Collection, CollectionMut, CollectionRef, Get, GetMut, Keyed, KeyedRef, Len, MapInsert,
+	MapIter, Remove, Update,
+};
+use std::ops::Deref;
+
+/// Total map wrapper, associating a default value to every key.
+///
+/// Querying a key that is not stored in the wrapped map returns
+/// the default value instead of `None`,
+/// and mutably accessing a key that is not stored first inserts
+/// a copy of the default value.
+///
+/// Only the stored entries are counted by [`Len`], iterated by [`MapIter`]
+/// and reported by [`Get::contains`].
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Defaulting, Get, GetMut, Len};
+/// use std::collections::HashMap;
+///
+/// let mut histogram: Defaulting<HashMap<char, usize>> = Defaulting::new(HashMap::new());
+/// for c in "hello".chars() {
+///   *histogram.get_mut(&c).unwrap() += 1;
+/// }
+///
+/// assert_eq!(histogram.get(&'l').map(|n| *n), Some(2));
+/// assert_eq!(histogram.get(&'z').map(|n| *n), Some(0));
+/// assert_eq!(histogram.len(), 4);
+/// assert!(!histogram.contains(&'z'));
+/// ```
+pub struct Defaulting<C: Collection> {
+	collection: C,
+	default: C::Item,
+}
+
+impl<C: Collection> Defaulting<C> {
+	/// Wraps the given `collection`, using `C::Item::default()` as default value.
+	pub fn new(collection: C) -> Self
+	where
+		C::Item: Default,
+	{
+		Self::with_default(collection, Default::default())
+	}
+
+	/// Wraps the given `collection`, using `default` as default value.
+	pub fn with_default(collection: C, default: C::Item) -> Self {
+		Self {
+			collection,
+			default,
+		}
+	}
+
+	/// Returns a reference to the default value.
+	pub fn default_value(&self) -> &C::Item {
+		&self.default
+	}
+
+	/// Returns a reference to the wrapped collection.
+	pub fn inner(&self) -> &C {
+		&self.collection
+	}
+
+	/// Returns a mutable reference to the wrapped collection.
+	pub fn inner_mut(&mut self) -> &mut C {
+		&mut self.collection
+	}
+
+	/// Returns the wrapped collection, dropping the default value.
+	pub fn into_inner(self) -> C {
+		self.collection
+	}
+}
+
+/// Item reference of a [`Defaulting`] map.
+pub enum DefaultingRef<'a, R, V> {
+	/// Reference to an item stored in the wrapped map.
+	Stored(R),
+
+	/// Reference to the default value.
+	Default(&'a V),
+}
+
+impl<R: Clone, V> Clone for DefaultingRef<'_, R, V> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::Stored(r) => Self::Stored(r.clone()),
+			Self::Default(v) => Self::Default(v),
+		}
+	}
+}
+
+impl<R: Deref<Target = V>, V> Deref for DefaultingRef<'_, R, V> {
+	type Target = V;
+
+	#[inline(always)]
+	fn deref(&self) -> &V {
+		match self {
+			Self::Stored(r) => r,
+			Self::Default(v) => v,
+		}
+	}
+}
+
+impl<C: Collection> Collection for Defaulting<C> {
+	type Item = C::Item;
+}
+
+impl<C: CollectionRef> CollectionRef for Defaulting<C> {
+	type ItemRef<'a>
+		= DefaultingRef<'a, C::ItemRef<'a>, C::Item>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		match r {
+			DefaultingRef::Stored(r) => DefaultingRef::Stored(C::upcast_item_ref(r)),
+			DefaultingRef::Default(v) => DefaultingRef::Default(v),
+		}
+	}
+}
+
+impl<C: CollectionMut> CollectionMut for Defaulting<C> {
+	type ItemMut<'a>
+		= C::ItemMut<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_mut(r)
+	}
+}
+
+impl<C: Keyed> Keyed for Defaulting<C> {
+	type Key = C::Key;
+}
+
+impl<C: KeyedRef> KeyedRef for Defaulting<C> {
+	type KeyRef<'a>
+		= C::KeyRef<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_key_ref(r)
+	}
+}
+
+impl<C: Collection + Len> Len for Defaulting<C> {
+	/// Returns the number of stored entries.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.collection.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.collection.is_empty()
+	}
+}
+
+impl<Q, C: Get<Q>> Get<Q> for Defaulting<C> {
+	/// Returns a reference to the item stored behind the given key,
+	/// or to the default value if there is none.
+	///
+	/// This never returns `None`.
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		Some(match self.collection.get(key) {
+			Some(item) => DefaultingRef::Stored(item),
+			None => DefaultingRef::Default(&self.default),
+		})
+	}
+
+	/// Checks if an item is stored behind the given key,
+	/// consistently with [`Len`] and [`MapIter`].
+	#[inline(always)]
+	fn contains(&self, key: Q) -> bool {
+		self.collection.contains(key)
+	}
+}
+
+impl<'k, K: Clone, C> GetMut<&'k K> for Defaulting<C>
+where
+	C: Keyed<Key = K> + GetMut<&'k K> + MapInsert<K>,
+	C::Item: Clone,
+{
+	/// Returns a mutable reference to the item stored behind the given key,
+	/// inserting a copy of the default value first if there is none.
+	///
+	/// The key is cloned when it is vacant. This never returns `None`.
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'k K) -> Option<Self::ItemMut<'_>> {
+		if !self.collection.contains(key) {
+			self.collection.insert(key.clone(), self.default.clone());
+		}
+
+		self.collection.get_mut(key)
+	}
+}
+
+impl<K, C: MapInsert<K>> MapInsert<K> for Defaulting<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: Self::Item) -> Self::Output {
+		self.collection.insert(key, value)
+	}
+}
+
+impl<Q, C: Remove<Q>> Remove<Q> for Defaulting<C> {
+	#[inline(always)]
+	fn remove(&mut self, key: Q) -> Option<Self::Item> {
+		self.collection.remove(key)
+	}
+}
+
+impl<C: Update> Update for Defaulting<C> {
+	/// Updates the item stored behind the given `key`,
+	/// giving `None` to `f` if there is none.
+	#[inline(always)]
+	fn update<F>(&mut self, key: Self::Key, f: F)
+	where
+		F: FnOnce(Option<Self::Item>) -> Option<Self::Item>,
+	{
+		self.collection.update(key, f)
+	}
+}
+
+impl<C: MapIter> MapIter for Defaulting<C> {
+	type Iter<'a>
+		= DefaultingIter<'a, C>
+	where
+		Self: 'a;
+
+	/// Iterates over the stored entries.
+	#[inline(always)]
+	fn iter(&self) -> <Self as MapIter>::Iter<'_> {
+		DefaultingIter(MapIter::iter(&self.collection))
+	}
+}
+
+/// Iterator over the stored entries of a [`Defaulting`] map.
+pub struct DefaultingIter<'a, C: MapIter + 'a>(C::Iter<'a>);
+
+impl<'a, C: MapIter> Iterator for DefaultingIter<'a, C> {
+	type Item = (C::KeyRef<'a>, DefaultingRef<'a, C::ItemRef<'a>, C::Item>);
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0
+			.next()
+			.map(|(key, item)| (key, DefaultingRef::Stored(item)))
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
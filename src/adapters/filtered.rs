@@ -0,0 +1,172 @@
+use crate::{Collection, CollectionRef, Get, GetKeyValue, Keyed, KeyedRef, Len, MapIter};
+use std::ops::Deref;
+
+/// Read-only view of a map hiding the entries failing a predicate.
+///
+/// Since the entries are filtered on the fly,
+/// computing the length of the view takes a time linear in
+/// the size of the wrapped map.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Filtered, Get, Len};
+/// use std::collections::HashMap;
+///
+/// let mut entries = HashMap::new();
+/// entries.insert(("tenant-a", 0), "foo");
+/// entries.insert(("tenant-a", 1), "bar");
+/// entries.insert(("tenant-b", 0), "baz");
+///
+/// let tenant_a = Filtered::new(entries, |(tenant, _), _| *tenant == "tenant-a");
+/// assert_eq!(tenant_a.len(), 2);
+/// assert_eq!(tenant_a.get(&("tenant-a", 1)).map(|v| *v), Some("bar"));
+/// assert!(tenant_a.get(&("tenant-b", 0)).is_none());
+/// ```
+pub struct Filtered<C, P> {
+	collection: C,
+	predicate: P,
+}
+
+impl<C: Keyed, P> Filtered<C, P> {
+	/// Creates a new view of `collection` only exposing the entries satisfying `predicate`.
+	pub fn new(collection: C, predicate: P) -> Self
+	where
+		P: Fn(&C::Key, &C::Item) -> bool,
+	{
+		Self {
+			collection,
+			predicate,
+		}
+	}
+
+	/// Returns a reference to the wrapped collection.
+	pub fn inner(&self) -> &C {
+		&self.collection
+	}
+
+	/// Returns the wrapped collection.
+	pub fn into_inner(self) -> C {
+		self.collection
+	}
+}
+
+impl<C: Collection, P> Collection for Filtered<C, P> {
+	type Item = C::Item;
+}
+
+impl<C: CollectionRef, P> CollectionRef for Filtered<C, P> {
+	type ItemRef<'a>
+		= C::ItemRef<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_ref(r)
+	}
+}
+
+impl<C: Keyed, P> Keyed for Filtered<C, P> {
+	type Key = C::Key;
+}
+
+impl<C: KeyedRef, P> KeyedRef for Filtered<C, P> {
+	type KeyRef<'a>
+		= C::KeyRef<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_key_ref(r)
+	}
+}
+
+impl<C: MapIter, P> Len for Filtered<C, P>
+where
+	P: Fn(&C::Key, &C::Item) -> bool,
+{
+	#[inline(always)]
+	fn len(&self) -> usize {
+		MapIter::iter(self).count()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		MapIter::iter(self).next().is_none()
+	}
+}
+
+impl<Q, C: GetKeyValue<Q>, P> Get<Q> for Filtered<C, P>
+where
+	P: Fn(&C::Key, &C::Item) -> bool,
+{
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		self.get_key_value(key).map(|(_, item)| item)
+	}
+}
+
+impl<Q, C: GetKeyValue<Q>, P> GetKeyValue<Q> for Filtered<C, P>
+where
+	P: Fn(&C::Key, &C::Item) -> bool,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		self.collection
+			.get_key_value(key)
+			.filter(|(key, item)| (self.predicate)(key, item))
+	}
+}
+
+/// Iterator over the entries of a [`Filtered`] view.
+pub struct FilteredIter<'a, I, P> {
+	inner: I,
+	predicate: &'a P,
+}
+
+impl<'a, K, V, I, P> Iterator for FilteredIter<'a, I, P>
+where
+	I: Iterator<Item = (K, V)>,
+	K: Deref,
+	V: Deref,
+	P: Fn(&K::Target, &V::Target) -> bool,
+{
+	type Item = (K, V);
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		let predicate = self.predicate;
+		self.inner.find(|(key, item)| predicate(key, item))
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, self.inner.size_hint().1)
+	}
+}
+
+impl<C: MapIter, P> MapIter for Filtered<C, P>
+where
+	P: Fn(&C::Key, &C::Item) -> bool,
+{
+	type Iter<'a>
+		= FilteredIter<'a, <C as MapIter>::Iter<'a>, P>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> <Self as MapIter>::Iter<'_> {
+		FilteredIter {
+			inner: MapIter::iter(&self.collection),
+			predicate: &self.predicate,
+		}
+	}
+}
@@ -0,0 +1,63 @@
+use crate::GetKeyValue;
+
+/// Read-only collection wrapper.
+///
+/// Only the read-only traits of the wrapped collection are implemented
+/// (such as [`Get`](crate::Get), [`GetKeyValue`], [`Len`](crate::Len),
+/// [`Iter`](crate::Iter) and [`MapIter`](crate::MapIter)),
+/// so that the wrapped collection cannot be modified through this type.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Frozen, Get, Len};
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert("a", 1);
+///
+/// let frozen = Frozen::new(map);
+/// assert_eq!(frozen.get(&"a").map(|v| *v), Some(1));
+/// assert_eq!(frozen.len(), 1);
+/// ```
+///
+/// Mutating traits are not implemented:
+///
+/// ```compile_fail
+/// use cc_traits::{Frozen, MapInsert};
+/// use std::collections::HashMap;
+///
+/// let mut frozen = Frozen::new(HashMap::new());
+/// frozen.insert("a", 1);
+/// ```
+pub struct Frozen<C> {
+	collection: C,
+}
+
+impl<C> Frozen<C> {
+	/// Freezes the given `collection`.
+	pub fn new(collection: C) -> Self {
+		Self { collection }
+	}
+
+	/// Returns a reference to the wrapped collection.
+	pub fn inner(&self) -> &C {
+		&self.collection
+	}
+
+	/// Unfreezes the wrapped collection.
+	pub fn into_inner(self) -> C {
+		self.collection
+	}
+}
+
+crate::delegate_collection!(impl<C> Frozen<C> => .collection: C; [
+	Collection, CollectionRef, Keyed, KeyedRef, Len, Capacity, Get, Iter, MapIter
+]);
+
+impl<Q, C: GetKeyValue<Q>> GetKeyValue<Q> for Frozen<C> {
+	#[inline(always)]
+	fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		self.collection.get_key_value(key)
+	}
+}
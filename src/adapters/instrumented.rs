@@ -0,0 +1,187 @@
+use crate::{Get, GetKeyValue, GetMut, Insert, MapInsert, Remove};
+use std::cell::Cell;
+
+/// Collection wrapper counting the operations performed on the wrapped collection.
+///
+/// Queries (through [`Get`], [`GetMut`] and [`GetKeyValue`]),
+/// insertions and removals are counted,
+/// and a snapshot of the counters can be taken with [`Instrumented::stats`].
+/// Every other trait is forwarded to the wrapped collection as is.
+///
+/// Since lookups are counted, so are the lookups performed by
+/// provided methods such as [`GetOrInsertExt::get_or_insert`](crate::GetOrInsertExt::get_or_insert).
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, Instrumented, MapInsert, OperationStats, Remove};
+/// use std::collections::HashMap;
+///
+/// let mut map = Instrumented::new(HashMap::new());
+/// map.insert("a", 1);
+/// assert!(map.get(&"a").is_some());
+/// assert!(map.get(&"b").is_none());
+/// map.remove(&"a");
+///
+/// assert_eq!(
+///   map.stats(),
+///   OperationStats {
+///     gets: 2,
+///     hits: 1,
+///     misses: 1,
+///     inserts: 1,
+///     removals: 1
+///   }
+/// );
+/// ```
+pub struct Instrumented<C> {
+	collection: C,
+	gets: Cell<usize>,
+	hits: Cell<usize>,
+	inserts: Cell<usize>,
+	removals: Cell<usize>,
+}
+
+/// Snapshot of the counters of an [`Instrumented`] collection.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct OperationStats {
+	/// Number of queries.
+	pub gets: usize,
+
+	/// Number of queries that found an item.
+	pub hits: usize,
+
+	/// Number of queries that found no item.
+	pub misses: usize,
+
+	/// Number of insertions.
+	pub inserts: usize,
+
+	/// Number of removals, successful or not.
+	pub removals: usize,
+}
+
+impl<C> Instrumented<C> {
+	/// Wraps the given `collection`, with all counters set to zero.
+	pub fn new(collection: C) -> Self {
+		Self {
+			collection,
+			gets: Cell::new(0),
+			hits: Cell::new(0),
+			inserts: Cell::new(0),
+			removals: Cell::new(0),
+		}
+	}
+
+	/// Returns a snapshot of the counters.
+	pub fn stats(&self) -> OperationStats {
+		OperationStats {
+			gets: self.gets.get(),
+			hits: self.hits.get(),
+			misses: self.gets.get() - self.hits.get(),
+			inserts: self.inserts.get(),
+			removals: self.removals.get(),
+		}
+	}
+
+	/// Sets all counters back to zero.
+	pub fn reset_stats(&self) {
+		self.gets.set(0);
+		self.hits.set(0);
+		self.inserts.set(0);
+		self.removals.set(0);
+	}
+
+	/// Returns a reference to the wrapped collection.
+	pub fn inner(&self) -> &C {
+		&self.collection
+	}
+
+	/// Returns the wrapped collection.
+	pub fn into_inner(self) -> C {
+		self.collection
+	}
+
+	/// Counts a query, and a hit if the item was `found`.
+	#[inline(always)]
+	fn record_get(&self, found: bool) {
+		self.gets.set(self.gets.get() + 1);
+		if found {
+			self.hits.set(self.hits.get() + 1)
+		}
+	}
+
+	#[inline(always)]
+	fn record_insert(&self) {
+		self.inserts.set(self.inserts.get() + 1)
+	}
+
+	#[inline(always)]
+	fn record_removal(&self) {
+		self.removals.set(self.removals.get() + 1)
+	}
+}
+
+crate::delegate_collection!(impl<C> Instrumented<C> => .collection: C; [
+	Collection, CollectionRef, CollectionMut, Keyed, KeyedRef, Len, Capacity, Reserve, Clear,
+	PushFront, PushBack, PopFront, PopBack, Iter, IterMut, MapIter, MapIterMut
+]);
+
+impl<Q, C: Get<Q>> Get<Q> for Instrumented<C> {
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		let item = self.collection.get(key);
+		self.record_get(item.is_some());
+		item
+	}
+}
+
+impl<Q, C: GetMut<Q>> GetMut<Q> for Instrumented<C> {
+	#[inline(always)]
+	fn get_mut(&mut self, key: Q) -> Option<Self::ItemMut<'_>> {
+		let item = self.collection.get_mut(key);
+		let found = item.is_some();
+		self.gets.set(self.gets.get() + 1);
+		if found {
+			self.hits.set(self.hits.get() + 1)
+		}
+		item
+	}
+}
+
+impl<Q, C: GetKeyValue<Q>> GetKeyValue<Q> for Instrumented<C> {
+	#[inline(always)]
+	fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		let entry = self.collection.get_key_value(key);
+		self.record_get(entry.is_some());
+		entry
+	}
+}
+
+impl<C: Insert> Insert for Instrumented<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, element: Self::Item) -> Self::Output {
+		self.record_insert();
+		self.collection.insert(element)
+	}
+}
+
+impl<K, C: MapInsert<K>> MapInsert<K> for Instrumented<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: Self::Item) -> Self::Output {
+		self.record_insert();
+		self.collection.insert(key, value)
+	}
+}
+
+impl<Q, C: Remove<Q>> Remove<Q> for Instrumented<C> {
+	#[inline(always)]
+	fn remove(&mut self, key: Q) -> Option<Self::Item> {
+		self.record_removal();
+		self.collection.remove(key)
+	}
+}
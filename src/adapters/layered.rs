@@ -0,0 +1,436 @@
+use crate::{
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed, KeyedRef,
+	Len, MapInsert, MapIter, Remove, Update,
+};
+use std::ops::{Deref, DerefMut};
+
+/// Map layering a top map over a bottom map.
+///
+/// Reads consult the top layer first, and fall through to the bottom layer.
+/// Writes always go to the top layer, leaving the bottom layer untouched:
+/// mutably accessing an entry of the bottom layer first copies it in the top layer,
+/// and removing an entry inserts a tombstone in the top layer.
+/// The top layer hence maps keys to `Option`s of values,
+/// where `None` is a tombstone hiding the entry of the bottom layer.
+///
+/// Since entries are deduplicated on the fly,
+/// computing the length of the map takes a time linear in
+/// the size of both layers.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, Layered, Len, MapInsert, Remove};
+/// use std::collections::HashMap;
+///
+/// let mut defaults = HashMap::new();
+/// defaults.insert("color", "red");
+/// defaults.insert("size", "medium");
+///
+/// let mut config = Layered::new(HashMap::new(), defaults);
+/// config.insert("size", "large");
+/// assert_eq!(config.remove(&"color"), Some("red"));
+///
+/// assert_eq!(config.get(&"size").map(|v| *v), Some("large"));
+/// assert!(config.get(&"color").is_none());
+/// assert_eq!(config.len(), 1);
+///
+/// // The bottom layer is left untouched.
+/// assert_eq!(config.bottom().len(), 2);
+/// ```
+pub struct Layered<Top, Bottom> {
+	top: Top,
+	bottom: Bottom,
+}
+
+impl<Top, Bottom> Layered<Top, Bottom> {
+	/// Layers `top` over `bottom`.
+	pub fn new(top: Top, bottom: Bottom) -> Self {
+		Self { top, bottom }
+	}
+
+	/// Returns a reference to the top layer.
+	pub fn top(&self) -> &Top {
+		&self.top
+	}
+
+	/// Returns a mutable reference to the top layer.
+	pub fn top_mut(&mut self) -> &mut Top {
+		&mut self.top
+	}
+
+	/// Returns a reference to the bottom layer.
+	pub fn bottom(&self) -> &Bottom {
+		&self.bottom
+	}
+
+	/// Returns a mutable reference to the bottom layer.
+	pub fn bottom_mut(&mut self) -> &mut Bottom {
+		&mut self.bottom
+	}
+
+	/// Returns the top and bottom layers.
+	pub fn into_inner(self) -> (Top, Bottom) {
+		(self.top, self.bottom)
+	}
+}
+
+/// Reference to a key or item of a [`Layered`] map, coming from one of its layers.
+#[derive(Clone, Copy)]
+pub enum LayeredRef<T, B> {
+	/// Reference coming from the top layer.
+	Top(T),
+
+	/// Reference coming from the bottom layer.
+	Bottom(B),
+}
+
+/// Unwraps item references of the top layer, pointing to `Option`s of values.
+///
+/// Those are never tombstones since tombstones are never exposed.
+impl<T, B, V> Deref for LayeredRef<T, B>
+where
+	T: Deref<Target = Option<V>>,
+	B: Deref<Target = V>,
+{
+	type Target = V;
+
+	#[inline(always)]
+	fn deref(&self) -> &V {
+		match self {
+			Self::Top(r) => r.as_ref().unwrap(),
+			Self::Bottom(r) => r,
+		}
+	}
+}
+
+/// Reference to a key of a [`Layered`] map, coming from one of its layers.
+#[derive(Clone, Copy)]
+pub enum LayeredKeyRef<T, B> {
+	/// Reference coming from the top layer.
+	Top(T),
+
+	/// Reference coming from the bottom layer.
+	Bottom(B),
+}
+
+impl<T, B, K> Deref for LayeredKeyRef<T, B>
+where
+	T: Deref<Target = K>,
+	B: Deref<Target = K>,
+{
+	type Target = K;
+
+	#[inline(always)]
+	fn deref(&self) -> &K {
+		match self {
+			Self::Top(r) => r,
+			Self::Bottom(r) => r,
+		}
+	}
+}
+
+/// Mutable reference to an item of a [`Layered`] map.
+///
+/// Such items always are in the top layer.
+pub struct LayeredMut<T>(T);
+
+impl<T: Deref<Target = Option<V>>, V> Deref for LayeredMut<T> {
+	type Target = V;
+
+	#[inline(always)]
+	fn deref(&self) -> &V {
+		self.0.as_ref().unwrap()
+	}
+}
+
+impl<T: DerefMut<Target = Option<V>>, V> DerefMut for LayeredMut<T> {
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut V {
+		self.0.as_mut().unwrap()
+	}
+}
+
+impl<Top, Bottom> Collection for Layered<Top, Bottom>
+where
+	Top: Collection<Item = Option<Bottom::Item>>,
+	Bottom: Collection,
+{
+	type Item = Bottom::Item;
+}
+
+impl<Top, Bottom> CollectionRef for Layered<Top, Bottom>
+where
+	Top: CollectionRef<Item = Option<Bottom::Item>>,
+	Bottom: CollectionRef,
+{
+	type ItemRef<'a>
+		= LayeredRef<Top::ItemRef<'a>, Bottom::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		match r {
+			LayeredRef::Top(r) => LayeredRef::Top(Top::upcast_item_ref(r)),
+			LayeredRef::Bottom(r) => LayeredRef::Bottom(Bottom::upcast_item_ref(r)),
+		}
+	}
+}
+
+impl<Top, Bottom> CollectionMut for Layered<Top, Bottom>
+where
+	Top: CollectionMut<Item = Option<Bottom::Item>>,
+	Bottom: Collection,
+{
+	type ItemMut<'a>
+		= LayeredMut<Top::ItemMut<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		LayeredMut(Top::upcast_item_mut(r.0))
+	}
+}
+
+impl<Top, Bottom> Keyed for Layered<Top, Bottom>
+where
+	Top: Keyed<Key = Bottom::Key, Item = Option<Bottom::Item>>,
+	Bottom: Keyed,
+{
+	type Key = Bottom::Key;
+}
+
+impl<Top, Bottom> KeyedRef for Layered<Top, Bottom>
+where
+	Top: KeyedRef<Key = Bottom::Key, Item = Option<Bottom::Item>>,
+	Bottom: KeyedRef,
+{
+	type KeyRef<'a>
+		= LayeredKeyRef<Top::KeyRef<'a>, Bottom::KeyRef<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		match r {
+			LayeredKeyRef::Top(r) => LayeredKeyRef::Top(Top::upcast_key_ref(r)),
+			LayeredKeyRef::Bottom(r) => LayeredKeyRef::Bottom(Bottom::upcast_key_ref(r)),
+		}
+	}
+}
+
+impl<Top, Bottom> Len for Layered<Top, Bottom>
+where
+	Top: MapIter<Key = Bottom::Key, Item = Option<Bottom::Item>> + for<'k> Get<&'k Bottom::Key>,
+	Bottom: MapIter,
+{
+	#[inline(always)]
+	fn len(&self) -> usize {
+		MapIter::iter(self).count()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		MapIter::iter(self).next().is_none()
+	}
+}
+
+impl<Q: Copy, Top, Bottom> Get<Q> for Layered<Top, Bottom>
+where
+	Top: Get<Q, Item = Option<Bottom::Item>>,
+	Bottom: Get<Q>,
+{
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		match self.top.get(key) {
+			Some(item) => item.is_some().then_some(LayeredRef::Top(item)),
+			None => self.bottom.get(key).map(LayeredRef::Bottom),
+		}
+	}
+}
+
+impl<Q: Copy, Top, Bottom> GetKeyValue<Q> for Layered<Top, Bottom>
+where
+	Top: GetKeyValue<Q, Key = Bottom::Key, Item = Option<Bottom::Item>>,
+	Bottom: GetKeyValue<Q>,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		match self.top.get_key_value(key) {
+			Some((key, item)) => item
+				.is_some()
+				.then_some((LayeredKeyRef::Top(key), LayeredRef::Top(item))),
+			None => self
+				.bottom
+				.get_key_value(key)
+				.map(|(key, item)| (LayeredKeyRef::Bottom(key), LayeredRef::Bottom(item))),
+		}
+	}
+}
+
+impl<Q: Copy, K: Clone, V: Clone, Top, Bottom> GetMut<Q> for Layered<Top, Bottom>
+where
+	Top: GetMut<Q, Item = Option<V>> + MapInsert<K>,
+	Bottom: Get<Q> + GetKeyValue<Q, Key = K, Item = V>,
+{
+	/// Returns a mutable reference to the item stored behind the given key (if any).
+	///
+	/// If the item is in the bottom layer, it is first copied in the top layer.
+	#[inline(always)]
+	fn get_mut(&mut self, key: Q) -> Option<Self::ItemMut<'_>> {
+		match self.top.get(key).map(|item| item.is_some()) {
+			Some(true) => (),
+			Some(false) => return None,
+			None => {
+				let (k, v) = self.bottom.get_key_value(key)?;
+				let (k, v) = ((*k).clone(), (*v).clone());
+				self.top.insert(k, Some(v));
+			}
+		}
+
+		self.top.get_mut(key).map(LayeredMut)
+	}
+}
+
+impl<K, Top, Bottom> MapInsert<K> for Layered<Top, Bottom>
+where
+	Top: MapInsert<K, Item = Option<Bottom::Item>>,
+	Bottom: Collection,
+{
+	/// Output of the insertion in the top layer.
+	type Output = Top::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: Self::Item) -> Self::Output {
+		self.top.insert(key, Some(value))
+	}
+}
+
+impl<Q: Copy, K: Clone, V: Clone, Top, Bottom> Remove<Q> for Layered<Top, Bottom>
+where
+	Top: GetMut<Q, Item = Option<V>> + MapInsert<K>,
+	Bottom: GetKeyValue<Q, Key = K, Item = V>,
+{
+	/// Removes the element identified by the given `key`, leaving a tombstone in the top layer.
+	#[inline(always)]
+	fn remove(&mut self, key: Q) -> Option<Self::Item> {
+		if self.top.contains(key) {
+			return self.top.get_mut(key).and_then(|mut item| item.take());
+		}
+
+		let (k, v) = self.bottom.get_key_value(key)?;
+		let (k, v) = ((*k).clone(), (*v).clone());
+		self.top.insert(k, None);
+		Some(v)
+	}
+}
+
+impl<K, V: Clone, Top, Bottom> Update for Layered<Top, Bottom>
+where
+	Top: Update + Keyed<Key = K, Item = Option<V>> + for<'a> Get<&'a K>,
+	Bottom: Keyed<Key = K, Item = V> + for<'a> Get<&'a K>,
+{
+	/// Updates the value behind the given `key` in the top layer,
+	/// starting from the value of the bottom layer if the top layer has no entry for it.
+	///
+	/// Removing an entry of the bottom layer leaves a tombstone in the top layer.
+	#[inline(always)]
+	fn update<F>(&mut self, key: K, f: F)
+	where
+		F: FnOnce(Option<V>) -> Option<V>,
+	{
+		let in_bottom = self.bottom.contains(&key);
+		let below = if in_bottom && !self.top.contains(&key) {
+			self.bottom.get(&key).map(|v| (*v).clone())
+		} else {
+			None
+		};
+
+		self.top
+			.update(key, |current| match f(current.flatten().or(below)) {
+				Some(value) => Some(Some(value)),
+				None if in_bottom => Some(None),
+				None => None,
+			})
+	}
+}
+
+impl<K: Clone, Top, Bottom> Clear for Layered<Top, Bottom>
+where
+	Top: Clear + MapInsert<K, Item = Option<Bottom::Item>>,
+	Bottom: MapIter<Key = K>,
+{
+	/// Clears the top layer, then hides every entry of the bottom layer behind a tombstone.
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.top.clear();
+		for (key, _) in MapIter::iter(&self.bottom) {
+			self.top.insert((*key).clone(), None);
+		}
+	}
+}
+
+/// Iterator over the entries of a [`Layered`] map.
+///
+/// Iterates over the entries of the top layer,
+/// then over the entries of the bottom layer that are not overridden by the top layer.
+pub struct LayeredIter<'a, Top: MapIter + 'a, Bottom: MapIter + 'a> {
+	top: &'a Top,
+	top_iter: <Top as MapIter>::Iter<'a>,
+	bottom_iter: <Bottom as MapIter>::Iter<'a>,
+}
+
+impl<'a, Top, Bottom> Iterator for LayeredIter<'a, Top, Bottom>
+where
+	Top: MapIter<Key = Bottom::Key, Item = Option<Bottom::Item>> + for<'k> Get<&'k Bottom::Key>,
+	Bottom: MapIter,
+{
+	type Item = (
+		LayeredKeyRef<Top::KeyRef<'a>, Bottom::KeyRef<'a>>,
+		LayeredRef<Top::ItemRef<'a>, Bottom::ItemRef<'a>>,
+	);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		for (key, item) in &mut self.top_iter {
+			if item.is_some() {
+				return Some((LayeredKeyRef::Top(key), LayeredRef::Top(item)));
+			}
+		}
+
+		let top = self.top;
+		self.bottom_iter
+			.find(|(key, _)| !top.contains(key))
+			.map(|(key, item)| (LayeredKeyRef::Bottom(key), LayeredRef::Bottom(item)))
+	}
+}
+
+impl<Top, Bottom> MapIter for Layered<Top, Bottom>
+where
+	Top: MapIter<Key = Bottom::Key, Item = Option<Bottom::Item>> + for<'k> Get<&'k Bottom::Key>,
+	Bottom: MapIter,
+{
+	type Iter<'a>
+		= LayeredIter<'a, Top, Bottom>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> <Self as MapIter>::Iter<'_> {
+		LayeredIter {
+			top: &self.top,
+			top_iter: MapIter::iter(&self.top),
+			bottom_iter: MapIter::iter(&self.bottom),
+		}
+	}
+}
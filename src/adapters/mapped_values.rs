@@ -0,0 +1,270 @@
+use crate::{Collection, CollectionRef, Get, GetKeyValue, Iter, Keyed, KeyedRef, Len, MapIter};
+use std::ops::Deref;
+
+/// Read-only view of a map whose values are projected by a function.
+///
+/// Item references are produced by applying the projection
+/// on the item references of the wrapped collection,
+/// without copying the values.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, MappedValues};
+/// use std::collections::HashMap;
+///
+/// pub struct User {
+///   name: String,
+///   password: String,
+/// }
+///
+/// let mut users = HashMap::new();
+/// users.insert(0, User { name: "alice".to_string(), password: "1234".to_string() });
+///
+/// let names = MappedValues::new(users, |user| &user.name);
+/// assert_eq!(names.get(&0).as_deref().map(String::as_str), Some("alice"));
+/// # assert_eq!(names.into_inner()[&0].password, "1234");
+/// ```
+pub struct MappedValues<C, F> {
+	collection: C,
+	projection: F,
+}
+
+impl<C: Collection, F> MappedValues<C, F> {
+	/// Creates a new view of `collection` projecting its values with `projection`.
+	pub fn new<U>(collection: C, projection: F) -> Self
+	where
+		F: Fn(&C::Item) -> &U,
+	{
+		Self {
+			collection,
+			projection,
+		}
+	}
+
+	/// Returns a reference to the wrapped collection.
+	pub fn inner(&self) -> &C {
+		&self.collection
+	}
+
+	/// Returns the wrapped collection.
+	pub fn into_inner(self) -> C {
+		self.collection
+	}
+}
+
+/// Projected item reference of a [`MappedValues`] view.
+pub struct MappedRef<'a, R, F> {
+	item: R,
+	projection: &'a F,
+}
+
+impl<'a, R: Clone, F> Clone for MappedRef<'a, R, F> {
+	fn clone(&self) -> Self {
+		Self {
+			item: self.item.clone(),
+			projection: self.projection,
+		}
+	}
+}
+
+impl<'a, R, F, U> Deref for MappedRef<'a, R, F>
+where
+	R: Deref,
+	F: Fn(&R::Target) -> &U,
+{
+	type Target = U;
+
+	#[inline(always)]
+	fn deref(&self) -> &U {
+		(self.projection)(&self.item)
+	}
+}
+
+impl<C: Collection, F, U> Collection for MappedValues<C, F>
+where
+	F: Fn(&C::Item) -> &U,
+{
+	type Item = U;
+}
+
+impl<C: CollectionRef, F, U> CollectionRef for MappedValues<C, F>
+where
+	F: Fn(&C::Item) -> &U,
+{
+	type ItemRef<'a>
+		= MappedRef<'a, C::ItemRef<'a>, F>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		MappedRef {
+			item: C::upcast_item_ref(r.item),
+			projection: r.projection,
+		}
+	}
+}
+
+impl<C: Keyed, F, U> Keyed for MappedValues<C, F>
+where
+	F: Fn(&C::Item) -> &U,
+{
+	type Key = C::Key;
+}
+
+impl<C: KeyedRef, F, U> KeyedRef for MappedValues<C, F>
+where
+	F: Fn(&C::Item) -> &U,
+{
+	type KeyRef<'a>
+		= C::KeyRef<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_key_ref(r)
+	}
+}
+
+impl<C: Len, F> Len for MappedValues<C, F> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.collection.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.collection.is_empty()
+	}
+}
+
+impl<Q, C: Get<Q>, F, U> Get<Q> for MappedValues<C, F>
+where
+	F: Fn(&C::Item) -> &U,
+{
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		self.collection.get(key).map(|item| MappedRef {
+			item,
+			projection: &self.projection,
+		})
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: Q) -> bool {
+		self.collection.contains(key)
+	}
+}
+
+impl<Q, C: GetKeyValue<Q>, F, U> GetKeyValue<Q> for MappedValues<C, F>
+where
+	F: Fn(&C::Item) -> &U,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		self.collection.get_key_value(key).map(|(key, item)| {
+			(
+				key,
+				MappedRef {
+					item,
+					projection: &self.projection,
+				},
+			)
+		})
+	}
+}
+
+/// Iterator over the projected items of a [`MappedValues`] view.
+pub struct MappedValuesIter<'a, I, F> {
+	inner: I,
+	projection: &'a F,
+}
+
+impl<'a, I: Iterator, F> Iterator for MappedValuesIter<'a, I, F> {
+	type Item = MappedRef<'a, I::Item, F>;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(|item| MappedRef {
+			item,
+			projection: self.projection,
+		})
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl<C: Iter, F, U> Iter for MappedValues<C, F>
+where
+	F: Fn(&C::Item) -> &U,
+{
+	type Iter<'a>
+		= MappedValuesIter<'a, C::Iter<'a>, F>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		MappedValuesIter {
+			inner: self.collection.iter(),
+			projection: &self.projection,
+		}
+	}
+}
+
+/// Iterator over the entries of a [`MappedValues`] view, with projected items.
+pub struct MappedValuesMapIter<'a, I, F> {
+	inner: I,
+	projection: &'a F,
+}
+
+impl<'a, K, R, I: Iterator<Item = (K, R)>, F> Iterator for MappedValuesMapIter<'a, I, F> {
+	type Item = (K, MappedRef<'a, R, F>);
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(|(key, item)| {
+			(
+				key,
+				MappedRef {
+					item,
+					projection: self.projection,
+				},
+			)
+		})
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl<C: MapIter, F, U> MapIter for MappedValues<C, F>
+where
+	F: Fn(&C::Item) -> &U,
+{
+	type Iter<'a>
+		= MappedValuesMapIter<'a, <C as MapIter>::Iter<'a>, F>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> <Self as MapIter>::Iter<'_> {
+		MappedValuesMapIter {
+			inner: MapIter::iter(&self.collection),
+			projection: &self.projection,
+		}
+	}
+}
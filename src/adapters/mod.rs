@@ -0,0 +1,28 @@
+//! Adapters wrapping a collection to change its behavior.
+mod arc_map;
+#[cfg(feature = "async")]
+mod blocking;
+mod boxed;
+mod defaulting;
+mod filtered;
+mod frozen;
+mod instrumented;
+mod layered;
+mod mapped_values;
+mod observed;
+mod transactional;
+mod union;
+
+pub use arc_map::*;
+#[cfg(feature = "async")]
+pub use blocking::*;
+pub use boxed::*;
+pub use defaulting::*;
+pub use filtered::*;
+pub use frozen::*;
+pub use instrumented::*;
+pub use layered::*;
+pub use mapped_values::*;
+pub use observed::*;
+pub use transactional::*;
+pub use union::*;
@@ -0,0 +1,141 @@
+use crate::{Clear, Get, GetMut, Keyed, MapInsert, Remove};
+
+/// Change of an [`Observed`] map.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Change<'a, K> {
+	/// A new entry is inserted behind the given key.
+	Inserted(&'a K),
+
+	/// The entry behind the given key is replaced or mutably accessed.
+	Updated(&'a K),
+
+	/// The entry behind the given key is removed.
+	Removed(&'a K),
+
+	/// The map is cleared.
+	Cleared,
+}
+
+/// Map wrapper notifying an observer of every modification.
+///
+/// The observer `F` is called with a [`Change`] right before
+/// the wrapped map is modified through [`MapInsert`], [`GetMut`], [`Remove`] or [`Clear`].
+/// Since it is reported by [`GetMut`], mutably accessing an entry counts
+/// as an update even if the entry is left unchanged.
+/// Provided methods built upon those traits, such as
+/// [`GetOrInsertExt::get_or_insert`](crate::GetOrInsertExt::get_or_insert), are observed as well.
+///
+/// Mutable iteration is not implemented, since it would bypass the observer.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Change, GetMut, MapInsert, Observed, Remove};
+/// use std::collections::HashMap;
+///
+/// let mut log = std::vec::Vec::new();
+/// let mut map = Observed::new(HashMap::new(), |change: Change<&str>| {
+///   log.push(format!("{:?}", change))
+/// });
+///
+/// map.insert("a", 1);
+/// map.insert("a", 2);
+/// *map.get_mut(&"a").unwrap() += 1;
+/// map.remove(&"a");
+/// map.remove(&"b");
+/// drop(map);
+///
+/// assert_eq!(
+///   log,
+///   ["Inserted(\"a\")", "Updated(\"a\")", "Updated(\"a\")", "Removed(\"a\")"]
+/// );
+/// ```
+pub struct Observed<C, F> {
+	collection: C,
+	observer: F,
+}
+
+impl<C, F> Observed<C, F> {
+	/// Wraps the given `collection`, notifying `observer` of every modification.
+	pub fn new(collection: C, observer: F) -> Self {
+		Self {
+			collection,
+			observer,
+		}
+	}
+
+	/// Returns a reference to the wrapped collection.
+	pub fn inner(&self) -> &C {
+		&self.collection
+	}
+
+	/// Returns the wrapped collection and the observer.
+	pub fn into_inner(self) -> (C, F) {
+		(self.collection, self.observer)
+	}
+}
+
+crate::delegate_collection!(impl<C, F> Observed<C, F> => .collection: C; [
+	Collection, CollectionRef, CollectionMut, Keyed, KeyedRef, Len, Capacity, Get, Iter, MapIter
+]);
+
+impl<'k, K, C, F> GetMut<&'k K> for Observed<C, F>
+where
+	C: Keyed<Key = K> + GetMut<&'k K>,
+	F: FnMut(Change<K>),
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'k K) -> Option<Self::ItemMut<'_>> {
+		if self.collection.contains(key) {
+			(self.observer)(Change::Updated(key))
+		}
+
+		self.collection.get_mut(key)
+	}
+}
+
+impl<K, C, F> MapInsert<K> for Observed<C, F>
+where
+	C: Keyed<Key = K> + MapInsert<K> + for<'k> Get<&'k K>,
+	F: FnMut(Change<K>),
+{
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: Self::Item) -> Self::Output {
+		if self.collection.contains(&key) {
+			(self.observer)(Change::Updated(&key))
+		} else {
+			(self.observer)(Change::Inserted(&key))
+		}
+
+		self.collection.insert(key, value)
+	}
+}
+
+impl<'k, K, C, F> Remove<&'k K> for Observed<C, F>
+where
+	C: Keyed<Key = K> + Remove<&'k K> + Get<&'k K>,
+	F: FnMut(Change<K>),
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'k K) -> Option<Self::Item> {
+		if self.collection.contains(key) {
+			(self.observer)(Change::Removed(key))
+		}
+
+		self.collection.remove(key)
+	}
+}
+
+impl<C, F> Clear for Observed<C, F>
+where
+	C: Keyed + Clear,
+	F: FnMut(Change<C::Key>),
+{
+	#[inline(always)]
+	fn clear(&mut self) {
+		(self.observer)(Change::Cleared);
+		self.collection.clear()
+	}
+}
@@ -0,0 +1,94 @@
+use crate::{Collection, Keyed, Layered, MapInsert, Remove};
+use std::collections::HashMap;
+
+/// Map wrapper buffering modifications until they are committed.
+///
+/// Insertions and removals are recorded in an overlay map `O`
+/// layered over the wrapped map (see [`Layered`]), mapping keys to `Option`s of values,
+/// where `None` marks a removal.
+/// Clearing the map marks every entry of the wrapped map as removed.
+/// Reads see the pending modifications.
+/// They are applied to the wrapped map by [`Transactional::commit`],
+/// or discarded by [`Transactional::rollback`].
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, MapInsert, Remove, Transactional};
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert("a", 1);
+///
+/// let mut map: Transactional<_> = Transactional::new(map);
+/// map.insert("b", 2);
+/// map.remove(&"a");
+/// assert!(map.get(&"a").is_none());
+///
+/// map.rollback();
+/// assert_eq!(map.get(&"a").map(|v| *v), Some(1));
+/// assert!(map.get(&"b").is_none());
+///
+/// map.insert("b", 2);
+/// map.commit();
+/// assert_eq!(map.inner().len(), 2);
+/// ```
+pub struct Transactional<C: Keyed, O = HashMap<<C as Keyed>::Key, Option<<C as Collection>::Item>>>
+{
+	layered: Layered<O, C>,
+}
+
+impl<C: Keyed, O: Default> Transactional<C, O> {
+	/// Wraps the given `collection`, with no pending modifications.
+	pub fn new(collection: C) -> Self {
+		Self {
+			layered: Layered::new(O::default(), collection),
+		}
+	}
+
+	/// Returns a reference to the wrapped map,
+	/// without the pending modifications.
+	pub fn inner(&self) -> &C {
+		self.layered.bottom()
+	}
+
+	/// Returns a reference to the overlay map holding the pending modifications.
+	pub fn pending(&self) -> &O {
+		self.layered.top()
+	}
+
+	/// Discards the pending modifications.
+	pub fn rollback(&mut self) {
+		*self.layered.top_mut() = O::default()
+	}
+
+	/// Applies the pending modifications to the wrapped map.
+	pub fn commit(&mut self)
+	where
+		O: IntoIterator<Item = (C::Key, Option<C::Item>)>,
+		C: MapInsert<C::Key> + for<'k> Remove<&'k C::Key>,
+	{
+		let pending = std::mem::take(self.layered.top_mut());
+		let collection = self.layered.bottom_mut();
+		for (key, value) in pending {
+			match value {
+				Some(value) => {
+					collection.insert(key, value);
+				}
+				None => {
+					collection.remove(&key);
+				}
+			}
+		}
+	}
+
+	/// Returns the wrapped map, discarding the pending modifications.
+	pub fn into_inner(self) -> C {
+		self.layered.into_inner().1
+	}
+}
+
+crate::delegate_collection!(impl<C: Keyed, O> Transactional<C, O> => .layered: Layered<O, C>; [
+	Collection, CollectionRef, CollectionMut, Keyed, KeyedRef, Len, Clear, Get, GetMut, GetKeyValue,
+	MapInsert, Remove, Update, MapIter
+]);
@@ -0,0 +1,372 @@
+use crate::{Collection, CollectionRef, Get, GetKeyValue, Keyed, KeyedRef, Len, MapIter};
+use std::{ops::Deref, rc::Rc};
+
+/// Read-only view of the union of two maps.
+///
+/// When both maps contain the same key,
+/// the conflict is resolved by the [`ConflictPolicy`] `P`,
+/// by default [`LeftBiased`].
+///
+/// Since entries are deduplicated on the fly,
+/// computing the length of the view takes a time linear in
+/// the size of the right map.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Combine, Get, Len, RightBiased, Union};
+/// use std::collections::HashMap;
+///
+/// let mut defaults = HashMap::new();
+/// defaults.insert("color", "red");
+/// defaults.insert("size", "medium");
+///
+/// let mut settings = HashMap::new();
+/// settings.insert("size", "large");
+///
+/// let config = Union::with_policy(defaults, settings, RightBiased);
+/// assert_eq!(config.get(&"color").map(|v| *v), Some("red"));
+/// assert_eq!(config.get(&"size").map(|v| *v), Some("large"));
+/// assert_eq!(config.len(), 2);
+///
+/// let mut a = HashMap::new();
+/// a.insert("x", 1);
+/// let mut b = HashMap::new();
+/// b.insert("x", 2);
+///
+/// let sums = Union::with_policy(a, b, Combine(|a: &i32, b: &i32| a + b));
+/// assert_eq!(sums.get(&"x").map(|v| *v), Some(3));
+/// ```
+pub struct Union<A, B, P = LeftBiased> {
+	left: A,
+	right: B,
+	policy: P,
+}
+
+impl<A, B> Union<A, B> {
+	/// Creates the left-biased union of the `left` and `right` maps.
+	pub fn new(left: A, right: B) -> Self {
+		Self::with_policy(left, right, LeftBiased)
+	}
+}
+
+impl<A, B, P> Union<A, B, P> {
+	/// Creates the union of the `left` and `right` maps, resolving conflicts with `policy`.
+	pub fn with_policy(left: A, right: B, policy: P) -> Self {
+		Self {
+			left,
+			right,
+			policy,
+		}
+	}
+
+	/// Returns a reference to the left map.
+	pub fn left(&self) -> &A {
+		&self.left
+	}
+
+	/// Returns a reference to the right map.
+	pub fn right(&self) -> &B {
+		&self.right
+	}
+
+	/// Returns the left and right maps.
+	pub fn into_inner(self) -> (A, B) {
+		(self.left, self.right)
+	}
+}
+
+/// Item reference of a [`Union`] view.
+pub enum UnionRef<L, R, V> {
+	/// Reference to an item of the left map.
+	Left(L),
+
+	/// Reference to an item of the right map.
+	Right(R),
+
+	/// Item combined from both maps.
+	Combined(Rc<V>),
+}
+
+impl<L: Clone, R: Clone, V> Clone for UnionRef<L, R, V> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::Left(r) => Self::Left(r.clone()),
+			Self::Right(r) => Self::Right(r.clone()),
+			Self::Combined(v) => Self::Combined(v.clone()),
+		}
+	}
+}
+
+impl<L, R, V> Deref for UnionRef<L, R, V>
+where
+	L: Deref<Target = V>,
+	R: Deref<Target = V>,
+{
+	type Target = V;
+
+	#[inline(always)]
+	fn deref(&self) -> &V {
+		match self {
+			Self::Left(r) => r,
+			Self::Right(r) => r,
+			Self::Combined(v) => v,
+		}
+	}
+}
+
+/// Key reference of a [`Union`] view.
+#[derive(Clone, Copy)]
+pub enum UnionKeyRef<L, R> {
+	/// Reference to a key of the left map.
+	Left(L),
+
+	/// Reference to a key of the right map.
+	Right(R),
+}
+
+impl<L, R, K> Deref for UnionKeyRef<L, R>
+where
+	L: Deref<Target = K>,
+	R: Deref<Target = K>,
+{
+	type Target = K;
+
+	#[inline(always)]
+	fn deref(&self) -> &K {
+		match self {
+			Self::Left(r) => r,
+			Self::Right(r) => r,
+		}
+	}
+}
+
+/// Policy resolving conflicts between the two maps of a [`Union`].
+pub trait ConflictPolicy<V> {
+	/// Resolves the conflict between the `left` and `right` items associated to the same key.
+	fn resolve<L, R>(&self, left: L, right: R) -> UnionRef<L, R, V>
+	where
+		L: Deref<Target = V>,
+		R: Deref<Target = V>;
+}
+
+/// Conflict policy keeping the item of the left map.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct LeftBiased;
+
+impl<V> ConflictPolicy<V> for LeftBiased {
+	#[inline(always)]
+	fn resolve<L, R>(&self, left: L, _right: R) -> UnionRef<L, R, V> {
+		UnionRef::Left(left)
+	}
+}
+
+/// Conflict policy keeping the item of the right map.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct RightBiased;
+
+impl<V> ConflictPolicy<V> for RightBiased {
+	#[inline(always)]
+	fn resolve<L, R>(&self, _left: L, right: R) -> UnionRef<L, R, V> {
+		UnionRef::Right(right)
+	}
+}
+
+/// Conflict policy combining the items of both maps with a function.
+#[derive(Clone, Copy, Debug)]
+pub struct Combine<F>(pub F);
+
+impl<V, F: Fn(&V, &V) -> V> ConflictPolicy<V> for Combine<F> {
+	#[inline(always)]
+	fn resolve<L, R>(&self, left: L, right: R) -> UnionRef<L, R, V>
+	where
+		L: Deref<Target = V>,
+		R: Deref<Target = V>,
+	{
+		UnionRef::Combined(Rc::new((self.0)(&left, &right)))
+	}
+}
+
+impl<A, B, P> Collection for Union<A, B, P>
+where
+	A: Collection,
+	B: Collection<Item = A::Item>,
+{
+	type Item = A::Item;
+}
+
+impl<A, B, P> CollectionRef for Union<A, B, P>
+where
+	A: CollectionRef,
+	B: CollectionRef<Item = A::Item>,
+{
+	type ItemRef<'a>
+		= UnionRef<A::ItemRef<'a>, B::ItemRef<'a>, A::Item>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		match r {
+			UnionRef::Left(r) => UnionRef::Left(A::upcast_item_ref(r)),
+			UnionRef::Right(r) => UnionRef::Right(B::upcast_item_ref(r)),
+			UnionRef::Combined(v) => UnionRef::Combined(v),
+		}
+	}
+}
+
+impl<A, B, P> Keyed for Union<A, B, P>
+where
+	A: Keyed,
+	B: Keyed<Key = A::Key, Item = A::Item>,
+{
+	type Key = A::Key;
+}
+
+impl<A, B, P> KeyedRef for Union<A, B, P>
+where
+	A: KeyedRef,
+	B: KeyedRef<Key = A::Key, Item = A::Item>,
+{
+	type KeyRef<'a>
+		= UnionKeyRef<A::KeyRef<'a>, B::KeyRef<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		match r {
+			UnionKeyRef::Left(r) => UnionKeyRef::Left(A::upcast_key_ref(r)),
+			UnionKeyRef::Right(r) => UnionKeyRef::Right(B::upcast_key_ref(r)),
+		}
+	}
+}
+
+impl<A, B, P> Len for Union<A, B, P>
+where
+	A: Keyed + Len + for<'k> Get<&'k A::Key>,
+	B: MapIter<Key = A::Key> + Len,
+{
+	/// Returns the number of entries of the left map,
+	/// plus the number of keys only present in the right map.
+	///
+	/// Conflicts are not resolved.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		let left = &self.left;
+		let right_only = MapIter::iter(&self.right)
+			.filter(|(key, _)| !left.contains(&**key))
+			.count();
+		left.len() + right_only
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.left.is_empty() && self.right.is_empty()
+	}
+}
+
+impl<Q: Copy, A, B, P> Get<Q> for Union<A, B, P>
+where
+	A: Get<Q>,
+	B: Get<Q, Item = A::Item>,
+	P: ConflictPolicy<A::Item>,
+{
+	#[inline(always)]
+	fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+		match (self.left.get(key), self.right.get(key)) {
+			(Some(left), Some(right)) => Some(self.policy.resolve(left, right)),
+			(Some(left), None) => Some(UnionRef::Left(left)),
+			(None, Some(right)) => Some(UnionRef::Right(right)),
+			(None, None) => None,
+		}
+	}
+}
+
+impl<Q: Copy, A, B, P> GetKeyValue<Q> for Union<A, B, P>
+where
+	A: GetKeyValue<Q>,
+	B: GetKeyValue<Q, Key = A::Key, Item = A::Item>,
+	P: ConflictPolicy<A::Item>,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		match (self.left.get_key_value(key), self.right.get_key_value(key)) {
+			(Some((key, left)), Some((_, right))) => {
+				Some((UnionKeyRef::Left(key), self.policy.resolve(left, right)))
+			}
+			(Some((key, left)), None) => Some((UnionKeyRef::Left(key), UnionRef::Left(left))),
+			(None, Some((key, right))) => Some((UnionKeyRef::Right(key), UnionRef::Right(right))),
+			(None, None) => None,
+		}
+	}
+}
+
+/// Iterator over the entries of a [`Union`] view.
+///
+/// Yields the entries of the left map first,
+/// then the entries of the right map that are not in the left map.
+pub struct UnionIter<'a, A: MapIter + 'a, B: MapIter + 'a, P> {
+	left: &'a A,
+	right: &'a B,
+	policy: &'a P,
+	left_iter: A::Iter<'a>,
+	right_iter: B::Iter<'a>,
+}
+
+impl<'a, A, B, P> Iterator for UnionIter<'a, A, B, P>
+where
+	A: MapIter + for<'k> Get<&'k A::Key>,
+	B: MapIter<Key = A::Key, Item = A::Item> + for<'k> Get<&'k A::Key>,
+	P: ConflictPolicy<A::Item>,
+{
+	type Item = (
+		UnionKeyRef<A::KeyRef<'a>, B::KeyRef<'a>>,
+		UnionRef<A::ItemRef<'a>, B::ItemRef<'a>, A::Item>,
+	);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some((key, left)) = self.left_iter.next() {
+			let item = match self.right.get(&*key) {
+				Some(right) => self.policy.resolve(left, right),
+				None => UnionRef::Left(left),
+			};
+
+			return Some((UnionKeyRef::Left(key), item));
+		}
+
+		let left = self.left;
+		self.right_iter
+			.find(|(key, _)| !left.contains(key))
+			.map(|(key, right)| (UnionKeyRef::Right(key), UnionRef::Right(right)))
+	}
+}
+
+impl<A, B, P> MapIter for Union<A, B, P>
+where
+	A: MapIter + for<'k> Get<&'k A::Key>,
+	B: MapIter<Key = A::Key, Item = A::Item> + for<'k> Get<&'k A::Key>,
+	P: ConflictPolicy<A::Item>,
+{
+	type Iter<'a>
+		= UnionIter<'a, A, B, P>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> <Self as MapIter>::Iter<'_> {
+		UnionIter {
+			left: &self.left,
+			right: &self.right,
+			policy: &self.policy,
+			left_iter: MapIter::iter(&self.left),
+			right_iter: MapIter::iter(&self.right),
+		}
+	}
+}
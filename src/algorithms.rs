@@ -0,0 +1,571 @@
+//! Generic algorithms over the traits of this crate.
+use crate::{
+	CollectionRef, Get, GetOrInsertExt, Iter, KeyedRef, Len, MapInsert, MapIter, PushBack, Reserve,
+};
+use std::{cmp::Ordering, fmt, iter::Peekable};
+
+/// Checks if two maps, possibly of different types, contain the same key-value pairs.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::map_eq;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let expected = BTreeMap::from([("a", 1), ("b", 2)]);
+/// let result = HashMap::from([("b", 2), ("a", 1)]);
+/// assert!(map_eq(&expected, &result));
+/// ```
+pub fn map_eq<A, B>(a: &A, b: &B) -> bool
+where
+	A: MapIter + Len,
+	B: Len + for<'k> Get<&'k A::Key>,
+	A::Item: PartialEq<B::Item>,
+{
+	a.len() == b.len()
+		&& MapIter::iter(a).all(|(key, item)| match b.get(&*key) {
+			Some(other) => *item == *other,
+			None => false,
+		})
+}
+
+/// Checks if two sequences, possibly of different types, contain the same elements in the same order.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::seq_eq;
+/// use std::collections::BTreeSet;
+///
+/// assert!(seq_eq(&vec![1, 2, 3], &BTreeSet::from([3, 1, 2])));
+/// assert!(!seq_eq(&vec![1, 2], &vec![1, 2, 3]));
+/// ```
+pub fn seq_eq<A, B>(a: &A, b: &B) -> bool
+where
+	A: Iter,
+	B: Iter,
+	A::Item: PartialEq<B::Item>,
+{
+	let mut a = a.iter();
+	let mut b = b.iter();
+	loop {
+		match (a.next(), b.next()) {
+			(Some(x), Some(y)) => {
+				if *x != *y {
+					return false;
+				}
+			}
+			(None, None) => return true,
+			_ => return false,
+		}
+	}
+}
+
+/// Difference between two maps, yielded by [`diff`].
+pub enum Difference<'a, A: MapIter + 'a, B: MapIter + 'a> {
+	/// Entry of the new map whose key is not in the old map.
+	Added(B::KeyRef<'a>, B::ItemRef<'a>),
+
+	/// Entry of the old map whose key is not in the new map.
+	Removed(A::KeyRef<'a>, A::ItemRef<'a>),
+
+	/// Key associated to different values in the old and new maps.
+	Changed(A::KeyRef<'a>, A::ItemRef<'a>, B::ItemRef<'a>),
+}
+
+impl<'a, A: MapIter + 'a, B: MapIter + 'a> fmt::Debug for Difference<'a, A, B>
+where
+	A::Key: fmt::Debug,
+	A::Item: fmt::Debug,
+	B::Key: fmt::Debug,
+	B::Item: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Added(key, item) => f.debug_tuple("Added").field(&**key).field(&**item).finish(),
+			Self::Removed(key, item) => f
+				.debug_tuple("Removed")
+				.field(&**key)
+				.field(&**item)
+				.finish(),
+			Self::Changed(key, old, new) => f
+				.debug_tuple("Changed")
+				.field(&**key)
+				.field(&**old)
+				.field(&**new)
+				.finish(),
+		}
+	}
+}
+
+/// Iterator over the differences between two maps, created by [`diff`].
+pub struct Diff<'a, A: MapIter + 'a, B: MapIter + 'a> {
+	old: &'a A,
+	new: &'a B,
+	old_iter: A::Iter<'a>,
+	new_iter: B::Iter<'a>,
+}
+
+impl<'a, A, B> Iterator for Diff<'a, A, B>
+where
+	A: MapIter + for<'k> Get<&'k A::Key>,
+	B: MapIter<Key = A::Key> + for<'k> Get<&'k A::Key>,
+	A::Item: PartialEq<B::Item>,
+{
+	type Item = Difference<'a, A, B>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		for (key, old) in &mut self.old_iter {
+			match self.new.get(&*key) {
+				Some(new) => {
+					if *old != *new {
+						return Some(Difference::Changed(key, old, new));
+					}
+				}
+				None => return Some(Difference::Removed(key, old)),
+			}
+		}
+
+		let old = self.old;
+		self.new_iter
+			.find(|(key, _)| !old.contains(&**key))
+			.map(|(key, new)| Difference::Added(key, new))
+	}
+}
+
+/// Computes the differences between an `old` and a `new` map.
+///
+/// Removed and changed entries are yielded first, in the order of the old map,
+/// followed by the added entries, in the order of the new map.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{diff, Difference};
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let old = BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]);
+/// let new = HashMap::from([("a", 1), ("b", 20), ("d", 4)]);
+///
+/// let changes: std::vec::Vec<_> = diff(&old, &new).map(|d| format!("{:?}", d)).collect();
+/// assert_eq!(changes, [
+///   "Changed(\"b\", 2, 20)",
+///   "Removed(\"c\", 3)",
+///   "Added(\"d\", 4)"
+/// ]);
+/// ```
+pub fn diff<'a, A, B>(old: &'a A, new: &'a B) -> Diff<'a, A, B>
+where
+	A: MapIter + for<'k> Get<&'k A::Key>,
+	B: MapIter<Key = A::Key> + for<'k> Get<&'k A::Key>,
+	A::Item: PartialEq<B::Item>,
+{
+	Diff {
+		old,
+		new,
+		old_iter: MapIter::iter(old),
+		new_iter: MapIter::iter(new),
+	}
+}
+
+/// Entry of an inner join between two maps, yielded by [`inner_join`].
+pub type InnerJoinItem<'a, A, B> = (
+	<A as KeyedRef>::KeyRef<'a>,
+	<A as CollectionRef>::ItemRef<'a>,
+	<B as CollectionRef>::ItemRef<'a>,
+);
+
+/// Entry of a left join between two maps, yielded by [`left_join`].
+pub type LeftJoinItem<'a, A, B> = (
+	<A as KeyedRef>::KeyRef<'a>,
+	<A as CollectionRef>::ItemRef<'a>,
+	Option<<B as CollectionRef>::ItemRef<'a>>,
+);
+
+/// Iterator over the keys present in both of two maps, created by [`inner_join`].
+pub struct InnerJoin<'a, A: MapIter + 'a, B: 'a> {
+	left_iter: A::Iter<'a>,
+	right: &'a B,
+}
+
+impl<'a, A, B> Iterator for InnerJoin<'a, A, B>
+where
+	A: MapIter,
+	B: for<'k> Get<&'k A::Key>,
+{
+	type Item = InnerJoinItem<'a, A, B>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let right = self.right;
+		self.left_iter
+			.find_map(|(key, left)| right.get(&*key).map(|item| (key, left, item)))
+	}
+}
+
+/// Joins the entries of two maps sharing the same key.
+///
+/// Entries are yielded in the order of the `left` map,
+/// and each one of them is looked up in the `right` map.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::inner_join;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let names = BTreeMap::from([(1, "alice"), (2, "bob"), (3, "carol")]);
+/// let ages = HashMap::from([(1, 30), (3, 40), (4, 50)]);
+///
+/// let rows: std::vec::Vec<_> = inner_join(&names, &ages)
+///   .map(|(id, name, age)| (*id, *name, *age))
+///   .collect();
+/// assert_eq!(rows, [(1, "alice", 30), (3, "carol", 40)]);
+/// ```
+pub fn inner_join<'a, A, B>(left: &'a A, right: &'a B) -> InnerJoin<'a, A, B>
+where
+	A: MapIter,
+	B: for<'k> Get<&'k A::Key>,
+{
+	InnerJoin {
+		left_iter: MapIter::iter(left),
+		right,
+	}
+}
+
+/// Iterator over the entries of a map joined with the entries of another map,
+/// created by [`left_join`].
+pub struct LeftJoin<'a, A: MapIter + 'a, B: 'a> {
+	left_iter: A::Iter<'a>,
+	right: &'a B,
+}
+
+impl<'a, A, B> Iterator for LeftJoin<'a, A, B>
+where
+	A: MapIter,
+	B: for<'k> Get<&'k A::Key>,
+{
+	type Item = LeftJoinItem<'a, A, B>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (key, left) = self.left_iter.next()?;
+		let right = self.right.get(&*key);
+		Some((key, left, right))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.left_iter.size_hint()
+	}
+}
+
+/// Joins every entry of the `left` map with the entry of the `right` map sharing its key, if any.
+///
+/// Entries are yielded in the order of the `left` map,
+/// and each one of them is looked up in the `right` map.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::left_join;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let names = BTreeMap::from([(1, "alice"), (2, "bob")]);
+/// let ages = HashMap::from([(1, 30)]);
+///
+/// let rows: std::vec::Vec<_> = left_join(&names, &ages)
+///   .map(|(id, name, age)| (*id, *name, age.map(|a| *a)))
+///   .collect();
+/// assert_eq!(rows, [(1, "alice", Some(30)), (2, "bob", None)]);
+/// ```
+pub fn left_join<'a, A, B>(left: &'a A, right: &'a B) -> LeftJoin<'a, A, B>
+where
+	A: MapIter,
+	B: for<'k> Get<&'k A::Key>,
+{
+	LeftJoin {
+		left_iter: MapIter::iter(left),
+		right,
+	}
+}
+
+/// Entry of an outer join between two maps, yielded by [`outer_join`] and [`merge_join`].
+pub enum JoinItem<'a, A: MapIter + 'a, B: MapIter + 'a> {
+	/// Key present in both maps.
+	Both(A::KeyRef<'a>, A::ItemRef<'a>, B::ItemRef<'a>),
+
+	/// Key present only in the left map.
+	Left(A::KeyRef<'a>, A::ItemRef<'a>),
+
+	/// Key present only in the right map.
+	Right(B::KeyRef<'a>, B::ItemRef<'a>),
+}
+
+impl<'a, A: MapIter + 'a, B: MapIter + 'a> JoinItem<'a, A, B> {
+	/// Returns the items of a key present in both maps, as in an [`inner_join`].
+	pub fn into_both(self) -> Option<InnerJoinItem<'a, A, B>> {
+		match self {
+			Self::Both(key, left, right) => Some((key, left, right)),
+			_ => None,
+		}
+	}
+
+	/// Returns the items of a key present in the left map, as in a [`left_join`].
+	pub fn into_left(self) -> Option<LeftJoinItem<'a, A, B>> {
+		match self {
+			Self::Both(key, left, right) => Some((key, left, Some(right))),
+			Self::Left(key, left) => Some((key, left, None)),
+			Self::Right(_, _) => None,
+		}
+	}
+}
+
+/// Iterator over the entries of two maps joined by key, created by [`outer_join`].
+pub struct OuterJoin<'a, A: MapIter + 'a, B: MapIter + 'a> {
+	left: &'a A,
+	right: &'a B,
+	left_iter: A::Iter<'a>,
+	right_iter: B::Iter<'a>,
+}
+
+impl<'a, A, B> Iterator for OuterJoin<'a, A, B>
+where
+	A: MapIter + for<'k> Get<&'k A::Key>,
+	B: MapIter<Key = A::Key> + for<'k> Get<&'k A::Key>,
+{
+	type Item = JoinItem<'a, A, B>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some((key, left)) = self.left_iter.next() {
+			return Some(match self.right.get(&*key) {
+				Some(right) => JoinItem::Both(key, left, right),
+				None => JoinItem::Left(key, left),
+			});
+		}
+
+		let left = self.left;
+		self.right_iter
+			.find(|(key, _)| !left.contains(&**key))
+			.map(|(key, right)| JoinItem::Right(key, right))
+	}
+}
+
+/// Joins the entries of two maps by key, keeping the keys present in only one of them.
+///
+/// The entries of the `left` map are yielded first, each one of them looked up in the `right` map,
+/// followed by the entries of the `right` map that are not in the `left` map.
+/// When both maps iterate in increasing key order, [`merge_join`] avoids the lookups.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{outer_join, JoinItem};
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let names = BTreeMap::from([(1, "alice"), (2, "bob")]);
+/// let ages = HashMap::from([(1, 30), (3, 40)]);
+///
+/// let ids: std::vec::Vec<_> = outer_join(&names, &ages)
+///   .map(|item| match item {
+///     JoinItem::Both(id, _, _) | JoinItem::Left(id, _) | JoinItem::Right(id, _) => *id,
+///   })
+///   .collect();
+/// assert_eq!(ids, [1, 2, 3]);
+/// ```
+pub fn outer_join<'a, A, B>(left: &'a A, right: &'a B) -> OuterJoin<'a, A, B>
+where
+	A: MapIter + for<'k> Get<&'k A::Key>,
+	B: MapIter<Key = A::Key> + for<'k> Get<&'k A::Key>,
+{
+	OuterJoin {
+		left,
+		right,
+		left_iter: MapIter::iter(left),
+		right_iter: MapIter::iter(right),
+	}
+}
+
+/// Iterator over the entries of two sorted maps joined by key, created by [`merge_join`].
+pub struct MergeJoin<'a, A: MapIter + 'a, B: MapIter + 'a> {
+	left_iter: Peekable<A::Iter<'a>>,
+	right_iter: Peekable<B::Iter<'a>>,
+}
+
+impl<'a, A, B> Iterator for MergeJoin<'a, A, B>
+where
+	A: MapIter,
+	B: MapIter<Key = A::Key>,
+	A::Key: Ord,
+{
+	type Item = JoinItem<'a, A, B>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let ordering = match (self.left_iter.peek(), self.right_iter.peek()) {
+			(Some((left, _)), Some((right, _))) => (**left).cmp(&**right),
+			(Some(_), None) => Ordering::Less,
+			(None, Some(_)) => Ordering::Greater,
+			(None, None) => return None,
+		};
+
+		Some(match ordering {
+			Ordering::Less => {
+				let (key, left) = self.left_iter.next()?;
+				JoinItem::Left(key, left)
+			}
+			Ordering::Greater => {
+				let (key, right) = self.right_iter.next()?;
+				JoinItem::Right(key, right)
+			}
+			Ordering::Equal => {
+				let (key, left) = self.left_iter.next()?;
+				let (_, right) = self.right_iter.next()?;
+				JoinItem::Both(key, left, right)
+			}
+		})
+	}
+}
+
+/// Joins the entries of two maps iterating in increasing key order,
+/// such as [`BTreeMap`](std::collections::BTreeMap),
+/// by merging their iterators instead of looking up keys.
+///
+/// This is an outer join yielding the entries in increasing key order.
+/// Inner and left joins are obtained with [`JoinItem::into_both`] and [`JoinItem::into_left`].
+/// If one of the maps does not iterate in increasing key order, the result is unspecified.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{merge_join, JoinItem};
+/// use std::collections::BTreeMap;
+///
+/// let names = BTreeMap::from([(1, "alice"), (2, "bob"), (3, "carol")]);
+/// let ages = BTreeMap::from([(1, 30), (3, 40), (4, 50)]);
+///
+/// let rows: std::vec::Vec<_> = merge_join(&names, &ages)
+///   .filter_map(JoinItem::into_both)
+///   .map(|(id, name, age)| (*id, *name, *age))
+///   .collect();
+/// assert_eq!(rows, [(1, "alice", 30), (3, "carol", 40)]);
+/// ```
+pub fn merge_join<'a, A, B>(left: &'a A, right: &'a B) -> MergeJoin<'a, A, B>
+where
+	A: MapIter,
+	B: MapIter<Key = A::Key>,
+	A::Key: Ord,
+{
+	MergeJoin {
+		left_iter: MapIter::iter(left).peekable(),
+		right_iter: MapIter::iter(right).peekable(),
+	}
+}
+
+/// Groups the given key-value pairs by key into a map of collections.
+///
+/// Each value is pushed on the back of the collection associated to its key in the `M` map,
+/// created empty the first time the key is encountered.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::group_by;
+/// use std::collections::{BTreeMap, VecDeque};
+///
+/// let words = ["apple", "avocado", "banana", "blueberry", "cherry"];
+/// let groups: BTreeMap<char, VecDeque<&str>> =
+///   group_by(words.iter().map(|w| (w.chars().next().unwrap(), *w)));
+///
+/// assert_eq!(groups[&'a'], ["apple", "avocado"]);
+/// assert_eq!(groups[&'b'], ["banana", "blueberry"]);
+/// assert_eq!(groups[&'c'], ["cherry"]);
+/// ```
+pub fn group_by<M, K, V>(entries: impl IntoIterator<Item = (K, V)>) -> M
+where
+	M: Default + GetOrInsertExt<Key = K>,
+	M::Item: Default + PushBack<Item = V>,
+{
+	let mut map = M::default();
+	for (key, value) in entries {
+		map.get_or_insert_default(key).push_back(value);
+	}
+	map
+}
+
+/// Groups the given key-value pairs by key into a map of collections,
+/// reserved upfront for the minimum number of pairs announced by the iterator.
+///
+/// This behaves like [`group_by`], but avoids growing the map
+/// when most keys are distinct.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::group_by_reserved;
+/// use std::collections::HashMap;
+///
+/// let words = ["apple", "avocado", "banana"];
+/// let groups: HashMap<char, Vec<&str>> =
+///   group_by_reserved(words.iter().map(|w| (w.chars().next().unwrap(), *w)));
+///
+/// assert_eq!(groups[&'a'], ["apple", "avocado"]);
+/// assert_eq!(groups[&'b'], ["banana"]);
+/// ```
+pub fn group_by_reserved<M, K, V>(entries: impl IntoIterator<Item = (K, V)>) -> M
+where
+	M: Default + Reserve + GetOrInsertExt<Key = K>,
+	M::Item: Default + PushBack<Item = V>,
+{
+	let entries = entries.into_iter();
+	let mut map = M::default();
+	map.reserve(entries.size_hint().0);
+	for (key, value) in entries {
+		map.get_or_insert_default(key).push_back(value);
+	}
+	map
+}
+
+/// Builds the reverse of the given map, where values become keys.
+///
+/// If the map is not injective, the key associated to a value
+/// is the last one yielded by the map.
+/// See [`invert_multi`] to keep all of them.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::invert;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let codes = HashMap::from([("red", 1), ("green", 2)]);
+/// let names: BTreeMap<i32, &str> = invert(codes);
+/// assert_eq!(names, BTreeMap::from([(1, "red"), (2, "green")]));
+/// ```
+pub fn invert<T, K, V>(map: impl IntoIterator<Item = (K, V)>) -> T
+where
+	T: Default + MapInsert<V, Item = K>,
+{
+	let mut result = T::default();
+	for (key, value) in map {
+		result.insert(value, key);
+	}
+	result
+}
+
+/// Builds the reverse of the given map, associating each value to the collection of its keys.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::invert_multi;
+/// use std::collections::{BTreeMap, VecDeque};
+///
+/// let colors = BTreeMap::from([("apple", "red"), ("banana", "yellow"), ("cherry", "red")]);
+/// let fruits: BTreeMap<&str, VecDeque<&str>> = invert_multi(colors);
+/// assert_eq!(fruits["red"], ["apple", "cherry"]);
+/// assert_eq!(fruits["yellow"], ["banana"]);
+/// ```
+pub fn invert_multi<T, K, V>(map: impl IntoIterator<Item = (K, V)>) -> T
+where
+	T: Default + GetOrInsertExt<Key = V>,
+	T::Item: Default + PushBack<Item = K>,
+{
+	group_by(map.into_iter().map(|(key, value)| (value, key)))
+}
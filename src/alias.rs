@@ -59,24 +59,6 @@ pub trait VecDeque<T> = Deque<T> + Vec<T>;
 /// See [`VecDeque`], [`DequeMut`] and [`VecMut`] for more details.
 pub trait VecDequeMut<T> = VecDeque<T> + DequeMut<T> + VecMut<T>;
 
-/// Imutable set data structure.
-///
-/// A set is an unordered collection storing at most one single copy of each element.
-pub trait Set<T> = Collection<Item = T> + Len + for<'a> Get<&'a T>;
-
-/// Mutable set data structure.
-pub trait SetMut<T> = Set<T> + Insert<Output = bool> + for<'a> Remove<&'a T>;
-
-/// Imutable map data structure.
-///
-/// A map is an unordered collection storing key-value pairs, indexed by the key.
-pub trait Map<K, V> =
-	Keyed<Key = K, Item = V> + Len + for<'a> Get<&'a K> + for<'a> GetKeyValue<&'a K>;
-
-/// Mutable map data structure.
-pub trait MapMut<K, V> =
-	Map<K, V> + for<'a> GetMut<&'a K> + MapInsert<K, Output = Option<V>> + for<'a> Remove<&'a K>;
-
 /// Imutable slab data structure.
 ///
 /// A slab is a linear collection storing each element at a given index.
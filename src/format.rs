@@ -0,0 +1,127 @@
+//! Formatting wrappers for collections.
+use crate::{Iter, MapIter};
+use std::{fmt, ops::Deref};
+
+/// Wrapper formatting the entries of a map with their `Debug` implementation.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::DebugMap;
+/// use std::collections::BTreeMap;
+///
+/// let map = BTreeMap::from([(1, "a"), (2, "b")]);
+/// assert_eq!(format!("{:?}", DebugMap(&map)), r#"{1: "a", 2: "b"}"#);
+/// ```
+pub struct DebugMap<'a, C>(pub &'a C);
+
+impl<C> fmt::Debug for DebugMap<'_, C>
+where
+	C: MapIter,
+	C::Key: fmt::Debug,
+	C::Item: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_map()
+			.entries(MapIter::iter(self.0).map(|(key, item)| (DebugEntry(key), DebugEntry(item))))
+			.finish()
+	}
+}
+
+/// Wrapper formatting the elements of a collection with their `Debug` implementation.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::DebugSeq;
+///
+/// assert_eq!(format!("{:?}", DebugSeq(&vec!["a", "b"])), r#"["a", "b"]"#);
+/// ```
+pub struct DebugSeq<'a, C>(pub &'a C);
+
+impl<C> fmt::Debug for DebugSeq<'_, C>
+where
+	C: Iter,
+	C::Item: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_list()
+			.entries(self.0.iter().map(DebugEntry))
+			.finish()
+	}
+}
+
+/// Wrapper formatting the entries of a map with their `Display` implementation.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::DisplayMap;
+/// use std::collections::BTreeMap;
+///
+/// let map = BTreeMap::from([(1, "a"), (2, "b")]);
+/// assert_eq!(DisplayMap(&map).to_string(), "{1: a, 2: b}");
+/// ```
+pub struct DisplayMap<'a, C>(pub &'a C);
+
+impl<C> fmt::Display for DisplayMap<'_, C>
+where
+	C: MapIter,
+	C::Key: fmt::Display,
+	C::Item: fmt::Display,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_map()
+			.entries(
+				MapIter::iter(self.0).map(|(key, item)| (DisplayEntry(key), DisplayEntry(item))),
+			)
+			.finish()
+	}
+}
+
+/// Wrapper formatting the elements of a collection with their `Display` implementation.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::DisplaySeq;
+///
+/// assert_eq!(DisplaySeq(&vec!["a", "b"]).to_string(), "[a, b]");
+/// ```
+pub struct DisplaySeq<'a, C>(pub &'a C);
+
+impl<C> fmt::Display for DisplaySeq<'_, C>
+where
+	C: Iter,
+	C::Item: fmt::Display,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_list()
+			.entries(self.0.iter().map(DisplayEntry))
+			.finish()
+	}
+}
+
+/// Reference to a key or item, formatted with the `Debug` implementation of its target.
+struct DebugEntry<R>(R);
+
+impl<R: Deref> fmt::Debug for DebugEntry<R>
+where
+	R::Target: fmt::Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Debug::fmt(&*self.0, f)
+	}
+}
+
+/// Reference to a key or item, formatted with the `Display` implementation of its target.
+struct DisplayEntry<R>(R);
+
+impl<R: Deref> fmt::Debug for DisplayEntry<R>
+where
+	R::Target: fmt::Display,
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&*self.0, f)
+	}
+}
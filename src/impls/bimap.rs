@@ -0,0 +1,129 @@
+use crate::{BiMap, BiMapMut, Clear, Len};
+use bimap::{BiBTreeMap, BiHashMap, Overwritten};
+use std::hash::Hash;
+
+impl<L: Hash + Eq, R: Hash + Eq> BiMap for BiHashMap<L, R> {
+	type Left = L;
+	type Right = R;
+
+	#[inline(always)]
+	fn get_by_left(&self, left: &L) -> Option<&R> {
+		self.get_by_left(left)
+	}
+
+	#[inline(always)]
+	fn get_by_right(&self, right: &R) -> Option<&L> {
+		self.get_by_right(right)
+	}
+
+	#[inline(always)]
+	fn contains_left(&self, left: &L) -> bool {
+		self.contains_left(left)
+	}
+
+	#[inline(always)]
+	fn contains_right(&self, right: &R) -> bool {
+		self.contains_right(right)
+	}
+}
+
+impl<L: Hash + Eq, R: Hash + Eq> BiMapMut for BiHashMap<L, R> {
+	type Output = Overwritten<L, R>;
+
+	#[inline(always)]
+	fn insert(&mut self, left: L, right: R) -> Overwritten<L, R> {
+		self.insert(left, right)
+	}
+
+	#[inline(always)]
+	fn remove_by_left(&mut self, left: &L) -> Option<(L, R)> {
+		self.remove_by_left(left)
+	}
+
+	#[inline(always)]
+	fn remove_by_right(&mut self, right: &R) -> Option<(L, R)> {
+		self.remove_by_right(right)
+	}
+}
+
+impl<L: Hash + Eq, R: Hash + Eq> Len for BiHashMap<L, R> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<L: Hash + Eq, R: Hash + Eq> Clear for BiHashMap<L, R> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<L: Ord, R: Ord> BiMap for BiBTreeMap<L, R> {
+	type Left = L;
+	type Right = R;
+
+	#[inline(always)]
+	fn get_by_left(&self, left: &L) -> Option<&R> {
+		self.get_by_left(left)
+	}
+
+	#[inline(always)]
+	fn get_by_right(&self, right: &R) -> Option<&L> {
+		self.get_by_right(right)
+	}
+
+	#[inline(always)]
+	fn contains_left(&self, left: &L) -> bool {
+		self.contains_left(left)
+	}
+
+	#[inline(always)]
+	fn contains_right(&self, right: &R) -> bool {
+		self.contains_right(right)
+	}
+}
+
+impl<L: Ord, R: Ord> BiMapMut for BiBTreeMap<L, R> {
+	type Output = Overwritten<L, R>;
+
+	#[inline(always)]
+	fn insert(&mut self, left: L, right: R) -> Overwritten<L, R> {
+		self.insert(left, right)
+	}
+
+	#[inline(always)]
+	fn remove_by_left(&mut self, left: &L) -> Option<(L, R)> {
+		self.remove_by_left(left)
+	}
+
+	#[inline(always)]
+	fn remove_by_right(&mut self, right: &R) -> Option<(L, R)> {
+		self.remove_by_right(right)
+	}
+}
+
+impl<L: Ord, R: Ord> Len for BiBTreeMap<L, R> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<L: Ord, R: Ord> Clear for BiBTreeMap<L, R> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
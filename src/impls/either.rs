@@ -0,0 +1,333 @@
+//! Implementations for `Either`.
+//!
+//! Each trait is implemented for `Either<L, R>` when `L` and `R` both implement it
+//! with the same item (and key) type, by dispatching to the current side.
+//! References and iterators are wrapped in an `Either` themselves.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Get, Len, MapInsert};
+//! use either::Either;
+//! use std::collections::{BTreeMap, HashMap};
+//!
+//! fn registry(sorted: bool) -> Either<HashMap<&'static str, u32>, BTreeMap<&'static str, u32>> {
+//!   if sorted {
+//!     Either::Right(BTreeMap::new())
+//!   } else {
+//!     Either::Left(HashMap::new())
+//!   }
+//! }
+//!
+//! for sorted in [false, true] {
+//!   let mut map = registry(sorted);
+//!   map.insert("a", 1);
+//!   assert_eq!(map.get(&"a").map(|v| *v), Some(1));
+//!   assert_eq!(map.len(), 1);
+//! }
+//! ```
+use crate::{
+	Capacity, Clear, Collection, CollectionMut, CollectionRef, Contains, Get, GetKeyValue, GetMut,
+	Insert, Iter, IterMut, Keyed, KeyedRef, Len, LenHint, MapInsert, MapIter, MapIterMut, PopBack,
+	PopFront, PushBack, PushFront, Remove, Reserve, Update,
+};
+use either::{for_both, Either, IterEither};
+use std::iter::Map;
+
+/// Type of the iterator over the entries of an `Either` map.
+pub type EitherMapIter<A, B, C, D, I> =
+	Map<I, fn(Either<(A, B), (C, D)>) -> (Either<A, C>, Either<B, D>)>;
+
+fn factor_entry<A, B, C, D>(entry: Either<(A, B), (C, D)>) -> (Either<A, C>, Either<B, D>) {
+	match entry {
+		Either::Left((key, value)) => (Either::Left(key), Either::Left(value)),
+		Either::Right((key, value)) => (Either::Right(key), Either::Right(value)),
+	}
+}
+
+impl<L: Collection, R: Collection<Item = L::Item>> Collection for Either<L, R> {
+	type Item = L::Item;
+}
+
+impl<L: CollectionRef, R: CollectionRef<Item = L::Item>> CollectionRef for Either<L, R> {
+	type ItemRef<'a>
+		= Either<L::ItemRef<'a>, R::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		r.map_either(L::upcast_item_ref, R::upcast_item_ref)
+	}
+}
+
+impl<L: CollectionMut, R: CollectionMut<Item = L::Item>> CollectionMut for Either<L, R> {
+	type ItemMut<'a>
+		= Either<L::ItemMut<'a>, R::ItemMut<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		r.map_either(L::upcast_item_mut, R::upcast_item_mut)
+	}
+}
+
+impl<L: Keyed, R: Keyed<Item = L::Item, Key = L::Key>> Keyed for Either<L, R> {
+	type Key = L::Key;
+}
+
+impl<L: KeyedRef, R: KeyedRef<Item = L::Item, Key = L::Key>> KeyedRef for Either<L, R> {
+	type KeyRef<'a>
+		= Either<L::KeyRef<'a>, R::KeyRef<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		r.map_either(L::upcast_key_ref, R::upcast_key_ref)
+	}
+}
+
+impl<L: Len, R: Len> Len for Either<L, R> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		for_both!(self, c => c.len())
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		for_both!(self, c => c.is_empty())
+	}
+}
+
+impl<L: LenHint, R: LenHint> LenHint for Either<L, R> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		for_both!(self, c => c.len_hint())
+	}
+}
+
+impl<L: Capacity, R: Capacity> Capacity for Either<L, R> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		for_both!(self, c => c.capacity())
+	}
+}
+
+impl<L: Reserve, R: Reserve> Reserve for Either<L, R> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		for_both!(self, c => c.reserve(additional))
+	}
+}
+
+impl<L: Clear, R: Clear> Clear for Either<L, R> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		for_both!(self, c => c.clear())
+	}
+}
+
+impl<T, L: Get<T>, R: Get<T, Item = L::Item>> Get<T> for Either<L, R> {
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<Self::ItemRef<'_>> {
+		match self {
+			Either::Left(c) => c.get(key).map(Either::Left),
+			Either::Right(c) => c.get(key).map(Either::Right),
+		}
+	}
+}
+
+impl<T, L: GetMut<T>, R: GetMut<T, Item = L::Item>> GetMut<T> for Either<L, R> {
+	#[inline(always)]
+	fn get_mut(&mut self, key: T) -> Option<Self::ItemMut<'_>> {
+		match self {
+			Either::Left(c) => c.get_mut(key).map(Either::Left),
+			Either::Right(c) => c.get_mut(key).map(Either::Right),
+		}
+	}
+}
+
+impl<T, L, R> GetKeyValue<T> for Either<L, R>
+where
+	L: GetKeyValue<T>,
+	R: GetKeyValue<T, Item = L::Item, Key = L::Key>,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		match self {
+			Either::Left(c) => c
+				.get_key_value(key)
+				.map(|(k, v)| (Either::Left(k), Either::Left(v))),
+			Either::Right(c) => c
+				.get_key_value(key)
+				.map(|(k, v)| (Either::Right(k), Either::Right(v))),
+		}
+	}
+}
+
+impl<T, L: Contains<T>, R: Contains<T, Item = L::Item>> Contains<T> for Either<L, R> {
+	#[inline(always)]
+	fn contains_value(&self, value: T) -> bool {
+		for_both!(self, c => c.contains_value(value))
+	}
+}
+
+impl<L: Insert, R: Insert<Item = L::Item, Output = L::Output>> Insert for Either<L, R> {
+	type Output = L::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, element: L::Item) -> L::Output {
+		for_both!(self, c => c.insert(element))
+	}
+}
+
+impl<K, L, R> MapInsert<K> for Either<L, R>
+where
+	L: MapInsert<K>,
+	R: MapInsert<K, Item = L::Item, Output = L::Output>,
+{
+	type Output = L::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: L::Item) -> L::Output {
+		for_both!(self, c => c.insert(key, value))
+	}
+}
+
+impl<T, L: Remove<T>, R: Remove<T, Item = L::Item>> Remove<T> for Either<L, R> {
+	#[inline(always)]
+	fn remove(&mut self, key: T) -> Option<L::Item> {
+		for_both!(self, c => c.remove(key))
+	}
+}
+
+impl<L: Update, R: Update<Item = L::Item, Key = L::Key>> Update for Either<L, R> {
+	#[inline(always)]
+	fn update<F>(&mut self, key: L::Key, f: F)
+	where
+		F: FnOnce(Option<L::Item>) -> Option<L::Item>,
+	{
+		for_both!(self, c => c.update(key, f))
+	}
+}
+
+impl<L: PushFront, R: PushFront<Item = L::Item, Output = L::Output>> PushFront for Either<L, R> {
+	type Output = L::Output;
+
+	#[inline(always)]
+	fn push_front(&mut self, element: L::Item) -> L::Output {
+		for_both!(self, c => c.push_front(element))
+	}
+}
+
+impl<L: PushBack, R: PushBack<Item = L::Item, Output = L::Output>> PushBack for Either<L, R> {
+	type Output = L::Output;
+
+	#[inline(always)]
+	fn push_back(&mut self, element: L::Item) -> L::Output {
+		for_both!(self, c => c.push_back(element))
+	}
+}
+
+impl<L: PopFront, R: PopFront<Item = L::Item>> PopFront for Either<L, R> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<L::Item> {
+		for_both!(self, c => c.pop_front())
+	}
+}
+
+impl<L: PopBack, R: PopBack<Item = L::Item>> PopBack for Either<L, R> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<L::Item> {
+		for_both!(self, c => c.pop_back())
+	}
+}
+
+impl<L: Iter, R: Iter<Item = L::Item>> Iter for Either<L, R> {
+	type Iter<'a>
+		= IterEither<L::Iter<'a>, R::Iter<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.as_ref()
+			.map_either(Iter::iter, Iter::iter)
+			.factor_into_iter()
+	}
+}
+
+impl<L: IterMut, R: IterMut<Item = L::Item>> IterMut for Either<L, R> {
+	type IterMut<'a>
+		= IterEither<L::IterMut<'a>, R::IterMut<'a>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.as_mut()
+			.map_either(IterMut::iter_mut, IterMut::iter_mut)
+			.factor_into_iter()
+	}
+}
+
+impl<L, R> MapIter for Either<L, R>
+where
+	L: MapIter,
+	R: MapIter<Item = L::Item, Key = L::Key>,
+{
+	type Iter<'a>
+		= EitherMapIter<
+		L::KeyRef<'a>,
+		L::ItemRef<'a>,
+		R::KeyRef<'a>,
+		R::ItemRef<'a>,
+		IterEither<L::Iter<'a>, R::Iter<'a>>,
+	>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.as_ref()
+			.map_either(MapIter::iter, MapIter::iter)
+			.factor_into_iter()
+			.map(factor_entry as fn(_) -> _)
+	}
+}
+
+impl<L, R> MapIterMut for Either<L, R>
+where
+	L: MapIterMut,
+	R: MapIterMut<Item = L::Item, Key = L::Key>,
+{
+	type IterMut<'a>
+		= EitherMapIter<
+		L::KeyRef<'a>,
+		L::ItemMut<'a>,
+		R::KeyRef<'a>,
+		R::ItemMut<'a>,
+		IterEither<L::IterMut<'a>, R::IterMut<'a>>,
+	>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.as_mut()
+			.map_either(MapIterMut::iter_mut, MapIterMut::iter_mut)
+			.factor_into_iter()
+			.map(factor_entry as fn(_) -> _)
+	}
+}
@@ -0,0 +1,293 @@
+//! Implementations for `heed` databases.
+//!
+//! A heed `Database` can only be accessed through a transaction,
+//! so the traits are implemented for the [`HeedRead`] and [`HeedWrite`] views
+//! pairing a database with a read or write transaction.
+//! Heed errors (I/O or decoding errors) cause a panic.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Get, HeedRead, HeedWrite, Len, MapInsert, Remove};
+//! use heed::{byteorder::BigEndian, types::{Str, U32}, EnvOpenOptions};
+//!
+//! let dir = std::env::temp_dir().join(format!("cc-traits-heed-example-{}", std::process::id()));
+//! std::fs::create_dir_all(&dir).unwrap();
+//! let env = unsafe { EnvOpenOptions::new().open(&dir) }.unwrap();
+//!
+//! let mut wtxn = env.write_txn().unwrap();
+//! let db = env
+//!   .create_database::<Str, U32<BigEndian>>(&mut wtxn, None)
+//!   .unwrap();
+//! let mut map = HeedWrite::new(db, &mut wtxn);
+//! map.insert("a", 1);
+//! map.insert("b", 2);
+//! assert_eq!(map.remove("b"), Some(2));
+//! wtxn.commit().unwrap();
+//!
+//! let rtxn = env.read_txn().unwrap();
+//! let map = HeedRead::new(db, &rtxn);
+//! assert_eq!(map.get("a").map(|v| *v), Some(1));
+//! assert_eq!(map.len(), 1);
+//!
+//! drop(rtxn);
+//! drop(env);
+//! std::fs::remove_dir_all(&dir).unwrap();
+//! ```
+use crate::{
+	Clear, Collection, CollectionRef, Get, Keyed, KeyedRef, Len, Len64, LenHint, MapInsert,
+	MapIter, Remove,
+};
+use heed::{BytesDecode, BytesEncode, Database, RoIter, RoTxn, RwTxn};
+use std::ops::Deref;
+
+const HEED_ERROR: &str = "heed error";
+
+/// Reference to a key or value decoded from a heed database.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HeedRef<T>(pub T);
+
+impl<T> Deref for HeedRef<T> {
+	type Target = T;
+
+	#[inline(always)]
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+
+/// Read-only view of a heed database through a transaction.
+///
+/// Keys and values are decoded without copy with the lifetime of the transaction.
+pub struct HeedRead<'t, KC, DC> {
+	database: Database<KC, DC>,
+	txn: &'t RoTxn<'t>,
+}
+
+impl<'t, KC, DC> HeedRead<'t, KC, DC> {
+	/// Creates a new view of the given database through the given transaction.
+	pub fn new(database: Database<KC, DC>, txn: &'t RoTxn<'t>) -> Self {
+		HeedRead { database, txn }
+	}
+}
+
+/// Iterator over the key-value pairs of a [`HeedRead`] view.
+pub struct HeedIter<'t, KC, DC>(RoIter<'t, KC, DC>);
+
+impl<'t, KC: BytesDecode<'t>, DC: BytesDecode<'t>> Iterator for HeedIter<'t, KC, DC> {
+	type Item = (HeedRef<KC::DItem>, HeedRef<DC::DItem>);
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|entry| {
+			entry
+				.map(|(k, v)| (HeedRef(k), HeedRef(v)))
+				.expect(HEED_ERROR)
+		})
+	}
+}
+
+impl<'t, KC, DC: BytesDecode<'t>> Collection for HeedRead<'t, KC, DC> {
+	type Item = DC::DItem;
+}
+
+impl<'t, KC, DC: BytesDecode<'t>> CollectionRef for HeedRead<'t, KC, DC>
+where
+	DC::DItem: Clone,
+{
+	type ItemRef<'a>
+		= HeedRef<DC::DItem>
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<'t, KC: BytesDecode<'t>, DC: BytesDecode<'t>> Keyed for HeedRead<'t, KC, DC> {
+	type Key = KC::DItem;
+}
+
+impl<'t, KC: BytesDecode<'t>, DC: BytesDecode<'t>> KeyedRef for HeedRead<'t, KC, DC>
+where
+	KC::DItem: Clone,
+{
+	type KeyRef<'a>
+		= HeedRef<KC::DItem>
+	where
+		Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<KC, DC> Len for HeedRead<'_, KC, DC> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.database.len(self.txn).expect(HEED_ERROR) as usize
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.database.is_empty(self.txn).expect(HEED_ERROR)
+	}
+}
+
+impl<KC, DC> Len64 for HeedRead<'_, KC, DC> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.database.len(self.txn).expect(HEED_ERROR)
+	}
+}
+
+impl<KC, DC> LenHint for HeedRead<'_, KC, DC> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'k, 't, KC: BytesEncode<'k>, DC: BytesDecode<'t>> Get<&'k KC::EItem> for HeedRead<'t, KC, DC>
+where
+	DC::DItem: Clone,
+{
+	#[inline(always)]
+	fn get(&self, key: &'k KC::EItem) -> Option<HeedRef<DC::DItem>> {
+		self.database
+			.get(self.txn, key)
+			.expect(HEED_ERROR)
+			.map(HeedRef)
+	}
+}
+
+impl<'t, KC: BytesDecode<'t>, DC: BytesDecode<'t>> MapIter for HeedRead<'t, KC, DC>
+where
+	KC::DItem: Clone,
+	DC::DItem: Clone,
+{
+	type Iter<'a>
+		= HeedIter<'t, KC, DC>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> HeedIter<'t, KC, DC> {
+		HeedIter(self.database.iter(self.txn).expect(HEED_ERROR))
+	}
+}
+
+/// Mutable view of a heed database through a write transaction.
+///
+/// Since the database can be modified, values are decoded into owned values:
+/// the value codec must decode into the same type regardless of the lifetime of the data,
+/// like `SerdeJson<T>` or the integer codecs.
+pub struct HeedWrite<'t, 'p, KC, DC> {
+	database: Database<KC, DC>,
+	txn: &'t mut RwTxn<'p>,
+}
+
+impl<'t, 'p, KC, DC> HeedWrite<'t, 'p, KC, DC> {
+	/// Creates a new view of the given database through the given write transaction.
+	pub fn new(database: Database<KC, DC>, txn: &'t mut RwTxn<'p>) -> Self {
+		HeedWrite { database, txn }
+	}
+}
+
+impl<KC, DC, V> Collection for HeedWrite<'_, '_, KC, DC>
+where
+	DC: for<'a> BytesDecode<'a, DItem = V>,
+{
+	type Item = V;
+}
+
+impl<KC, DC, V> CollectionRef for HeedWrite<'_, '_, KC, DC>
+where
+	DC: for<'a> BytesDecode<'a, DItem = V>,
+	V: Clone,
+{
+	type ItemRef<'a>
+		= HeedRef<V>
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<KC, DC> Len for HeedWrite<'_, '_, KC, DC> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.database.len(self.txn).expect(HEED_ERROR) as usize
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.database.is_empty(self.txn).expect(HEED_ERROR)
+	}
+}
+
+impl<KC, DC> Len64 for HeedWrite<'_, '_, KC, DC> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.database.len(self.txn).expect(HEED_ERROR)
+	}
+}
+
+impl<KC, DC> LenHint for HeedWrite<'_, '_, KC, DC> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'k, KC, DC, V> Get<&'k KC::EItem> for HeedWrite<'_, '_, KC, DC>
+where
+	KC: BytesEncode<'k>,
+	DC: for<'a> BytesDecode<'a, DItem = V>,
+	V: Clone,
+{
+	#[inline(always)]
+	fn get(&self, key: &'k KC::EItem) -> Option<HeedRef<V>> {
+		self.database
+			.get(self.txn, key)
+			.expect(HEED_ERROR)
+			.map(HeedRef)
+	}
+}
+
+impl<'k, KC, DC, K: ?Sized, V> MapInsert<&'k K> for HeedWrite<'_, '_, KC, DC>
+where
+	KC: for<'a> BytesEncode<'a, EItem = K>,
+	DC: for<'a> BytesDecode<'a, DItem = V> + for<'a> BytesEncode<'a, EItem = V>,
+{
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: &'k K, value: V) -> Option<V> {
+		let previous = self.database.get(self.txn, key).expect(HEED_ERROR);
+		self.database.put(self.txn, key, &value).expect(HEED_ERROR);
+		previous
+	}
+}
+
+impl<'k, KC, DC, V> Remove<&'k KC::EItem> for HeedWrite<'_, '_, KC, DC>
+where
+	KC: BytesEncode<'k>,
+	DC: for<'a> BytesDecode<'a, DItem = V>,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'k KC::EItem) -> Option<V> {
+		let previous = self.database.get(self.txn, key).expect(HEED_ERROR);
+		if previous.is_some() {
+			self.database.delete(self.txn, key).expect(HEED_ERROR);
+		}
+
+		previous
+	}
+}
+
+impl<KC, DC> Clear for HeedWrite<'_, '_, KC, DC> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.database.clear(self.txn).expect(HEED_ERROR)
+	}
+}
@@ -1,9 +1,12 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetKeyValueMut,
-	GetMut, Iter, IterMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapIterMut, PopBack, PushBack,
-	Remove, Reserve, WithCapacity,
+	AsMutSlice, AsSlice, Capacity, Clear, Collection, CollectionMut, CollectionRef, Fill, Get,
+	GetIndex, GetIndexMut, GetIndexOf, GetKeyValue, GetKeyValueMut, GetMut, Iter, IterMut, Keyed,
+	KeyedRef, Len, MapInsert, MapIter, MapIterMut, MergeFrom, OccupiedError, OrderedMapOps,
+	PopBack, PushBack, Remove, RemoveEntry, Reserve, SplitAtMut, Swap, TryInsert, Update,
+	WithCapacity,
 };
 use ijson::{IArray, IObject, IString, IValue};
+use std::cmp::Ordering;
 
 impl Collection for IObject {
 	type Item = IValue;
@@ -89,6 +92,28 @@ impl<Q: ijson::object::ObjectIndex> GetKeyValueMut<Q> for IObject {
 	}
 }
 
+impl GetIndex for IObject {
+	#[inline(always)]
+	fn get_index(&self, index: usize) -> Option<(&IString, &IValue)> {
+		self.iter().nth(index)
+	}
+}
+
+impl GetIndexMut for IObject {
+	#[inline(always)]
+	fn get_index_mut(&mut self, index: usize) -> Option<(&IString, &mut IValue)> {
+		self.iter_mut().nth(index)
+	}
+}
+
+impl<Q: ijson::object::ObjectIndex> GetIndexOf<Q> for IObject {
+	#[inline(always)]
+	fn get_index_of(&self, q: Q) -> Option<usize> {
+		let (key, _) = self.get_key_value(q)?;
+		self.keys().position(|k| k == key)
+	}
+}
+
 impl MapInsert<IString> for IObject {
 	type Output = Option<IValue>;
 
@@ -98,6 +123,102 @@ impl MapInsert<IString> for IObject {
 	}
 }
 
+impl MergeFrom for IObject {
+	#[inline(always)]
+	fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+	where
+		O: IntoIterator<Item = (IString, IValue)>,
+		F: FnMut(&mut IValue, IValue),
+	{
+		for (key, value) in other {
+			match self.entry(key) {
+				ijson::object::Entry::Occupied(entry) => resolve(entry.into_mut(), value),
+				ijson::object::Entry::Vacant(entry) => {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
+impl TryInsert<IString> for IObject {
+	#[inline(always)]
+	fn try_insert(
+		&mut self,
+		key: IString,
+		value: IValue,
+	) -> Result<&mut IValue, OccupiedError<'_, Self>> {
+		match self.entry(key) {
+			ijson::object::Entry::Occupied(entry) => Err(OccupiedError {
+				existing: entry.into_mut(),
+				value,
+			}),
+			ijson::object::Entry::Vacant(entry) => Ok(entry.insert(value)),
+		}
+	}
+}
+
+impl Update for IObject {
+	/// Updates the value behind the given `key` in place,
+	/// leaving `null` behind it while `f` runs.
+	#[inline(always)]
+	fn update<F>(&mut self, key: IString, f: F)
+	where
+		F: FnOnce(Option<IValue>) -> Option<IValue>,
+	{
+		match self.entry(key) {
+			ijson::object::Entry::Occupied(mut entry) => {
+				match f(Some(std::mem::take(entry.get_mut()))) {
+					Some(value) => *entry.get_mut() = value,
+					None => {
+						entry.remove();
+					}
+				}
+			}
+			ijson::object::Entry::Vacant(entry) => {
+				if let Some(value) = f(None) {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
+/// Rebuilds the object after reordering its entries.
+///
+/// `IObject` does not expose a way to reorder its entries in place.
+fn reorder_entries(object: &mut IObject, f: impl FnOnce(&mut Vec<(IString, IValue)>)) {
+	let mut entries: Vec<_> = std::mem::take(object).into_iter().collect();
+	f(&mut entries);
+	*object = entries.into_iter().collect()
+}
+
+impl OrderedMapOps for IObject {
+	fn move_index(&mut self, from: usize, to: usize) {
+		let len = self.len();
+		assert!(from < len && to < len, "index out of bounds");
+		reorder_entries(self, |entries| {
+			let entry = entries.remove(from);
+			entries.insert(to, entry)
+		})
+	}
+
+	fn swap_indices(&mut self, a: usize, b: usize) {
+		let len = self.len();
+		assert!(a < len && b < len, "index out of bounds");
+		reorder_entries(self, |entries| entries.swap(a, b))
+	}
+
+	fn sort_by<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&IString, &IValue, &IString, &IValue) -> Ordering,
+	{
+		reorder_entries(self, |entries| {
+			entries.sort_by(|(ka, va), (kb, vb)| f(ka, va, kb, vb))
+		})
+	}
+}
+
 impl<Q: ijson::object::ObjectIndex> Remove<Q> for IObject {
 	#[inline(always)]
 	fn remove(&mut self, key: Q) -> Option<IValue> {
@@ -105,6 +226,13 @@ impl<Q: ijson::object::ObjectIndex> Remove<Q> for IObject {
 	}
 }
 
+impl<Q: ijson::object::ObjectIndex> RemoveEntry<Q> for IObject {
+	#[inline(always)]
+	fn remove_entry(&mut self, key: Q) -> Option<(IString, IValue)> {
+		self.remove_entry(key)
+	}
+}
+
 impl Clear for IObject {
 	#[inline(always)]
 	fn clear(&mut self) {
@@ -118,18 +246,18 @@ impl Collection for IArray {
 
 impl CollectionRef for IArray {
 	type ItemRef<'a>
+		= &'a IValue
 	where
-		Self: 'a,
-	= &'a IValue;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
 impl CollectionMut for IArray {
 	type ItemMut<'a>
+		= &'a mut IValue
 	where
-		Self: 'a,
-	= &'a mut IValue;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
@@ -232,3 +360,54 @@ impl IterMut for IArray {
 		self.as_mut_slice().iter_mut()
 	}
 }
+
+impl AsSlice for IArray {
+	#[inline(always)]
+	fn as_slice(&self) -> &[IValue] {
+		self.as_slice()
+	}
+}
+
+impl AsMutSlice for IArray {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [IValue] {
+		self.as_mut_slice()
+	}
+}
+
+impl SplitAtMut for IArray {
+	type SplitMut<'a>
+		= &'a mut [IValue]
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn split_at_mut(&mut self, mid: usize) -> (&mut [IValue], &mut [IValue]) {
+		self.as_mut_slice().split_at_mut(mid)
+	}
+}
+
+impl Swap for IArray {
+	#[inline(always)]
+	fn swap(&mut self, a: usize, b: usize) {
+		self.as_mut_slice().swap(a, b)
+	}
+}
+
+impl Fill for IArray {
+	#[inline(always)]
+	fn fill(&mut self, value: IValue)
+	where
+		IValue: Clone,
+	{
+		self.as_mut_slice().fill(value)
+	}
+
+	#[inline(always)]
+	fn fill_with<F>(&mut self, f: F)
+	where
+		F: FnMut() -> IValue,
+	{
+		self.as_mut_slice().fill_with(f)
+	}
+}
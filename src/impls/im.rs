@@ -0,0 +1,645 @@
+//! Implementations for the `im` and `im-rc` persistent collections.
+//!
+//! Both crates share the same API (with thread-safe or reference-counted sharing),
+//! so the traits are implemented for both by the same macro.
+//! Modifying a collection only copies the nodes shared with its clones.
+//!
+//! ## Example
+//!
+//! ```
+//! # #[cfg(feature = "im")]
+//! # {
+//! use cc_traits::{Get, MapInsert, Remove};
+//!
+//! let mut map = im::HashMap::new();
+//! MapInsert::insert(&mut map, "a", 1);
+//! let snapshot = map.clone();
+//! Remove::remove(&mut map, &"a");
+//!
+//! assert!(Get::get(&map, &"a").is_none());
+//! assert_eq!(Get::get(&snapshot, &"a").map(|v| *v), Some(1));
+//! # }
+//! ```
+use crate::{
+	Append, BinarySearch, Clear, Collection, CollectionMut, CollectionRef, Contains, Get,
+	GetKeyValue, GetMut, InsertAt, Iter, IterMut, Keyed, KeyedRef, Len, LenHint, MapInsert,
+	MapIter, MapIterMut, MergeFrom, OccupiedError, PopBack, PopFront, PushBack, PushFront, Remove,
+	RemoveEntry, Swap, Truncate, TryInsert, Update,
+};
+use std::{
+	borrow::Borrow,
+	cmp::Ordering,
+	hash::{BuildHasher, Hash},
+};
+
+macro_rules! im_impls {
+	($im:ident) => {
+		impl<K, V, S> Collection for $im::HashMap<K, V, S> {
+			type Item = V;
+		}
+
+		impl<K, V, S> CollectionRef for $im::HashMap<K, V, S> {
+			type ItemRef<'a>
+				= &'a V
+			where
+				Self: 'a;
+
+			crate::covariant_item_ref!();
+		}
+
+		impl<K, V, S> CollectionMut for $im::HashMap<K, V, S> {
+			type ItemMut<'a>
+				= &'a mut V
+			where
+				Self: 'a;
+
+			crate::covariant_item_mut!();
+		}
+
+		impl<K, V, S> Keyed for $im::HashMap<K, V, S> {
+			type Key = K;
+		}
+
+		impl<K, V, S> KeyedRef for $im::HashMap<K, V, S> {
+			type KeyRef<'a>
+				= &'a K
+			where
+				Self: 'a;
+
+			crate::covariant_key_ref!();
+		}
+
+		impl<K, V, S> Len for $im::HashMap<K, V, S> {
+			#[inline(always)]
+			fn len(&self) -> usize {
+				self.len()
+			}
+
+			#[inline(always)]
+			fn is_empty(&self) -> bool {
+				self.is_empty()
+			}
+		}
+
+		impl<K, V, S> LenHint for $im::HashMap<K, V, S> {
+			#[inline(always)]
+			fn len_hint(&self) -> (usize, Option<usize>) {
+				(self.len(), Some(self.len()))
+			}
+		}
+
+		impl<'a, Q, K: Hash + Eq, V, S: BuildHasher> Get<&'a Q> for $im::HashMap<K, V, S>
+		where
+			K: Borrow<Q>,
+			Q: Hash + Eq + ?Sized,
+		{
+			#[inline(always)]
+			fn get(&self, key: &'a Q) -> Option<&V> {
+				self.get(key)
+			}
+		}
+
+		impl<'a, Q, K: Hash + Eq + Clone, V: Clone, S: BuildHasher> GetMut<&'a Q>
+			for $im::HashMap<K, V, S>
+		where
+			K: Borrow<Q>,
+			Q: Hash + Eq + ?Sized,
+		{
+			#[inline(always)]
+			fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+				self.get_mut(key)
+			}
+		}
+
+		impl<'a, Q, K: Hash + Eq, V, S: BuildHasher> GetKeyValue<&'a Q> for $im::HashMap<K, V, S>
+		where
+			K: Borrow<Q>,
+			Q: Hash + Eq + ?Sized,
+		{
+			#[inline(always)]
+			fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+				self.get_key_value(key)
+			}
+		}
+
+		impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher> MapInsert<K>
+			for $im::HashMap<K, V, S>
+		{
+			type Output = Option<V>;
+
+			#[inline(always)]
+			fn insert(&mut self, key: K, value: V) -> Option<V> {
+				self.insert(key, value)
+			}
+		}
+
+		impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher> MergeFrom for $im::HashMap<K, V, S> {
+			#[inline(always)]
+			fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+			where
+				O: IntoIterator<Item = (K, V)>,
+				F: FnMut(&mut V, V),
+			{
+				for (key, value) in other {
+					match self.entry(key) {
+						$im::hashmap::Entry::Occupied(entry) => resolve(entry.into_mut(), value),
+						$im::hashmap::Entry::Vacant(entry) => {
+							entry.insert(value);
+						}
+					}
+				}
+			}
+		}
+
+		impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher> TryInsert<K>
+			for $im::HashMap<K, V, S>
+		{
+			#[inline(always)]
+			fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, Self>> {
+				match self.entry(key) {
+					$im::hashmap::Entry::Occupied(entry) => Err(OccupiedError {
+						existing: entry.into_mut(),
+						value,
+					}),
+					$im::hashmap::Entry::Vacant(entry) => Ok(entry.insert(value)),
+				}
+			}
+		}
+
+		impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher> Update for $im::HashMap<K, V, S> {
+			#[inline(always)]
+			fn update<F>(&mut self, key: K, f: F)
+			where
+				F: FnOnce(Option<V>) -> Option<V>,
+			{
+				match self.entry(key) {
+					$im::hashmap::Entry::Occupied(entry) => {
+						let (key, value) = entry.remove_entry();
+						if let Some(value) = f(Some(value)) {
+							self.insert(key, value);
+						}
+					}
+					$im::hashmap::Entry::Vacant(entry) => {
+						if let Some(value) = f(None) {
+							entry.insert(value);
+						}
+					}
+				}
+			}
+		}
+
+		impl<'a, Q, K: Hash + Eq + Clone, V: Clone, S: BuildHasher> Remove<&'a Q>
+			for $im::HashMap<K, V, S>
+		where
+			K: Borrow<Q>,
+			Q: Hash + Eq + ?Sized,
+		{
+			#[inline(always)]
+			fn remove(&mut self, key: &'a Q) -> Option<V> {
+				self.remove(key)
+			}
+		}
+
+		impl<'a, Q, K: Hash + Eq + Clone, V: Clone, S: BuildHasher> RemoveEntry<&'a Q>
+			for $im::HashMap<K, V, S>
+		where
+			K: Borrow<Q>,
+			Q: Hash + Eq + ?Sized,
+		{
+			#[inline(always)]
+			fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+				self.remove_with_key(key)
+			}
+		}
+
+		impl<K, V, S> Clear for $im::HashMap<K, V, S> {
+			#[inline(always)]
+			fn clear(&mut self) {
+				self.clear()
+			}
+		}
+
+		impl<K, V, S> Iter for $im::HashMap<K, V, S> {
+			type Iter<'a>
+				= $im::hashmap::Values<'a, K, V>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				self.values()
+			}
+		}
+
+		impl<K, V, S> MapIter for $im::HashMap<K, V, S> {
+			type Iter<'a>
+				= $im::hashmap::Iter<'a, K, V>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				self.iter()
+			}
+		}
+
+		impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher> MapIterMut for $im::HashMap<K, V, S> {
+			type IterMut<'a>
+				= $im::hashmap::IterMut<'a, K, V>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter_mut(&mut self) -> Self::IterMut<'_> {
+				self.iter_mut()
+			}
+		}
+
+		impl<K, V> Collection for $im::OrdMap<K, V> {
+			type Item = V;
+		}
+
+		impl<K, V> CollectionRef for $im::OrdMap<K, V> {
+			type ItemRef<'a>
+				= &'a V
+			where
+				Self: 'a;
+
+			crate::covariant_item_ref!();
+		}
+
+		impl<K, V> CollectionMut for $im::OrdMap<K, V> {
+			type ItemMut<'a>
+				= &'a mut V
+			where
+				Self: 'a;
+
+			crate::covariant_item_mut!();
+		}
+
+		impl<K, V> Keyed for $im::OrdMap<K, V> {
+			type Key = K;
+		}
+
+		impl<K, V> KeyedRef for $im::OrdMap<K, V> {
+			type KeyRef<'a>
+				= &'a K
+			where
+				Self: 'a;
+
+			crate::covariant_key_ref!();
+		}
+
+		impl<K, V> Len for $im::OrdMap<K, V> {
+			#[inline(always)]
+			fn len(&self) -> usize {
+				self.len()
+			}
+
+			#[inline(always)]
+			fn is_empty(&self) -> bool {
+				self.is_empty()
+			}
+		}
+
+		impl<K, V> LenHint for $im::OrdMap<K, V> {
+			#[inline(always)]
+			fn len_hint(&self) -> (usize, Option<usize>) {
+				(self.len(), Some(self.len()))
+			}
+		}
+
+		impl<'a, Q, K: Ord, V> Get<&'a Q> for $im::OrdMap<K, V>
+		where
+			K: Borrow<Q>,
+			Q: Ord + ?Sized,
+		{
+			#[inline(always)]
+			fn get(&self, key: &'a Q) -> Option<&V> {
+				self.get(key)
+			}
+		}
+
+		impl<'a, Q, K: Ord + Clone, V: Clone> GetMut<&'a Q> for $im::OrdMap<K, V>
+		where
+			K: Borrow<Q>,
+			Q: Ord + ?Sized,
+		{
+			#[inline(always)]
+			fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+				self.get_mut(key)
+			}
+		}
+
+		impl<'a, Q, K: Ord, V> GetKeyValue<&'a Q> for $im::OrdMap<K, V>
+		where
+			K: Borrow<Q>,
+			Q: Ord + ?Sized,
+		{
+			#[inline(always)]
+			fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+				self.get_key_value(key)
+			}
+		}
+
+		impl<K: Ord + Clone, V: Clone> MapInsert<K> for $im::OrdMap<K, V> {
+			type Output = Option<V>;
+
+			#[inline(always)]
+			fn insert(&mut self, key: K, value: V) -> Option<V> {
+				self.insert(key, value)
+			}
+		}
+
+		impl<K: Ord + Clone, V: Clone> MergeFrom for $im::OrdMap<K, V> {
+			#[inline(always)]
+			fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+			where
+				O: IntoIterator<Item = (K, V)>,
+				F: FnMut(&mut V, V),
+			{
+				for (key, value) in other {
+					match self.entry(key) {
+						$im::ordmap::Entry::Occupied(entry) => resolve(entry.into_mut(), value),
+						$im::ordmap::Entry::Vacant(entry) => {
+							entry.insert(value);
+						}
+					}
+				}
+			}
+		}
+
+		impl<K: Ord + Clone, V: Clone> TryInsert<K> for $im::OrdMap<K, V> {
+			#[inline(always)]
+			fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, Self>> {
+				match self.entry(key) {
+					$im::ordmap::Entry::Occupied(entry) => Err(OccupiedError {
+						existing: entry.into_mut(),
+						value,
+					}),
+					$im::ordmap::Entry::Vacant(entry) => Ok(entry.insert(value)),
+				}
+			}
+		}
+
+		impl<K: Ord + Clone, V: Clone> Update for $im::OrdMap<K, V> {
+			#[inline(always)]
+			fn update<F>(&mut self, key: K, f: F)
+			where
+				F: FnOnce(Option<V>) -> Option<V>,
+			{
+				match self.entry(key) {
+					$im::ordmap::Entry::Occupied(entry) => {
+						let (key, value) = entry.remove_entry();
+						if let Some(value) = f(Some(value)) {
+							self.insert(key, value);
+						}
+					}
+					$im::ordmap::Entry::Vacant(entry) => {
+						if let Some(value) = f(None) {
+							entry.insert(value);
+						}
+					}
+				}
+			}
+		}
+
+		impl<'a, Q, K: Ord + Clone, V: Clone> Remove<&'a Q> for $im::OrdMap<K, V>
+		where
+			K: Borrow<Q>,
+			Q: Ord + ?Sized,
+		{
+			#[inline(always)]
+			fn remove(&mut self, key: &'a Q) -> Option<V> {
+				self.remove(key)
+			}
+		}
+
+		impl<'a, Q, K: Ord + Clone, V: Clone> RemoveEntry<&'a Q> for $im::OrdMap<K, V>
+		where
+			K: Borrow<Q>,
+			Q: Ord + ?Sized,
+		{
+			#[inline(always)]
+			fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+				self.remove_with_key(key)
+			}
+		}
+
+		impl<K, V> Clear for $im::OrdMap<K, V> {
+			#[inline(always)]
+			fn clear(&mut self) {
+				self.clear()
+			}
+		}
+
+		impl<K: Ord, V> Iter for $im::OrdMap<K, V> {
+			type Iter<'a>
+				= $im::ordmap::Values<'a, K, V>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				self.values()
+			}
+		}
+
+		impl<K: Ord, V> MapIter for $im::OrdMap<K, V> {
+			type Iter<'a>
+				= $im::ordmap::Iter<'a, K, V>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				self.iter()
+			}
+		}
+
+		impl<T: Clone> Collection for $im::Vector<T> {
+			type Item = T;
+		}
+
+		impl<T: Clone> CollectionRef for $im::Vector<T> {
+			type ItemRef<'a>
+				= &'a T
+			where
+				Self: 'a;
+
+			crate::covariant_item_ref!();
+		}
+
+		impl<T: Clone> CollectionMut for $im::Vector<T> {
+			type ItemMut<'a>
+				= &'a mut T
+			where
+				Self: 'a;
+
+			crate::covariant_item_mut!();
+		}
+
+		impl<T: Clone> Len for $im::Vector<T> {
+			#[inline(always)]
+			fn len(&self) -> usize {
+				self.len()
+			}
+
+			#[inline(always)]
+			fn is_empty(&self) -> bool {
+				self.is_empty()
+			}
+		}
+
+		impl<T: Clone> LenHint for $im::Vector<T> {
+			#[inline(always)]
+			fn len_hint(&self) -> (usize, Option<usize>) {
+				(self.len(), Some(self.len()))
+			}
+		}
+
+		impl<T: Clone> Get<usize> for $im::Vector<T> {
+			#[inline(always)]
+			fn get(&self, index: usize) -> Option<&T> {
+				self.get(index)
+			}
+		}
+
+		impl<T: Clone> GetMut<usize> for $im::Vector<T> {
+			#[inline(always)]
+			fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+				self.get_mut(index)
+			}
+		}
+
+		impl<'a, T: Clone + PartialEq> Contains<&'a T> for $im::Vector<T> {
+			#[inline(always)]
+			fn contains_value(&self, t: &'a T) -> bool {
+				self.contains(t)
+			}
+		}
+
+		impl<T: Clone> PushFront for $im::Vector<T> {
+			type Output = ();
+
+			#[inline(always)]
+			fn push_front(&mut self, t: T) {
+				self.push_front(t)
+			}
+		}
+
+		impl<T: Clone> PushBack for $im::Vector<T> {
+			type Output = ();
+
+			#[inline(always)]
+			fn push_back(&mut self, t: T) {
+				self.push_back(t)
+			}
+		}
+
+		impl<T: Clone> Append for $im::Vector<T> {
+			#[inline(always)]
+			fn append(&mut self, other: &mut Self) {
+				self.append(std::mem::take(other))
+			}
+		}
+
+		impl<T: Clone> PopFront for $im::Vector<T> {
+			#[inline(always)]
+			fn pop_front(&mut self) -> Option<T> {
+				self.pop_front()
+			}
+		}
+
+		impl<T: Clone> PopBack for $im::Vector<T> {
+			#[inline(always)]
+			fn pop_back(&mut self) -> Option<T> {
+				self.pop_back()
+			}
+		}
+
+		impl<T: Clone> InsertAt for $im::Vector<T> {
+			#[inline(always)]
+			fn insert_at(&mut self, index: usize, element: T) -> Result<(), T> {
+				if index <= self.len() {
+					self.insert(index, element);
+					Ok(())
+				} else {
+					Err(element)
+				}
+			}
+		}
+
+		impl<T: Clone> Remove<usize> for $im::Vector<T> {
+			#[inline(always)]
+			fn remove(&mut self, index: usize) -> Option<T> {
+				if index < self.len() {
+					Some(self.remove(index))
+				} else {
+					None
+				}
+			}
+		}
+
+		impl<T: Clone> Truncate for $im::Vector<T> {
+			#[inline(always)]
+			fn truncate(&mut self, len: usize) {
+				self.truncate(len)
+			}
+		}
+
+		impl<T: Clone> Swap for $im::Vector<T> {
+			#[inline(always)]
+			fn swap(&mut self, a: usize, b: usize) {
+				self.swap(a, b)
+			}
+		}
+
+		impl<T: Clone> BinarySearch for $im::Vector<T> {
+			#[inline(always)]
+			fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+			where
+				F: FnMut(&T) -> Ordering,
+			{
+				self.binary_search_by(f)
+			}
+		}
+
+		impl<T: Clone> Clear for $im::Vector<T> {
+			#[inline(always)]
+			fn clear(&mut self) {
+				self.clear()
+			}
+		}
+
+		impl<T: Clone> Iter for $im::Vector<T> {
+			type Iter<'a>
+				= $im::vector::Iter<'a, T>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				self.iter()
+			}
+		}
+
+		impl<T: Clone> IterMut for $im::Vector<T> {
+			type IterMut<'a>
+				= $im::vector::IterMut<'a, T>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter_mut(&mut self) -> Self::IterMut<'_> {
+				self.iter_mut()
+			}
+		}
+	};
+}
+
+#[cfg(feature = "im")]
+im_impls!(im);
+
+#[cfg(feature = "im-rc")]
+im_impls!(im_rc);
@@ -0,0 +1,554 @@
+//! Implementations for `indexmap` maps and sets.
+//!
+//! Removing an entry with [`Remove`], [`RemoveEntry`] or [`Take`]
+//! shifts the following entries, preserving the insertion order.
+//! Updating an entry with [`Update`] leaves it in place.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{MapInsert, MapIter, Remove, Update};
+//! use indexmap::IndexMap;
+//!
+//! let mut map = IndexMap::new();
+//! MapInsert::insert(&mut map, "a", 1);
+//! MapInsert::insert(&mut map, "b", 2);
+//! MapInsert::insert(&mut map, "c", 3);
+//! Remove::remove(&mut map, &"a");
+//! Update::update(&mut map, "b", |v| v.map(|v| v * 10));
+//!
+//! let entries: Vec<_> = MapIter::iter(&map).map(|(k, v)| (*k, *v)).collect();
+//! assert_eq!(entries, [("b", 20), ("c", 3)]);
+//! ```
+use crate::{
+	Capacity, Clear, Collection, CollectionMut, CollectionRef, Contains, Get, GetIndex,
+	GetIndexMut, GetIndexOf, GetKeyValue, GetMut, Insert, Iter, Keyed, KeyedRef, Len, Len64,
+	LenHint, MapInsert, MapIter, MapIterMut, MergeFrom, OccupiedError, OrderedMapOps, Remove,
+	RemoveEntry, Replace, Reserve, SetCompare, SetEntryApi, SetEntryApiWith, SetOps, SetPop, Take,
+	TryInsert, Update, WithCapacity,
+};
+use indexmap::{map::Entry, Equivalent, IndexMap, IndexSet};
+use std::{
+	cmp::Ordering,
+	hash::{BuildHasher, Hash},
+};
+
+impl<K, V, S> Collection for IndexMap<K, V, S> {
+	type Item = V;
+}
+
+impl<K, V, S> CollectionRef for IndexMap<K, V, S> {
+	type ItemRef<'a>
+		= &'a V
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<K, V, S> CollectionMut for IndexMap<K, V, S> {
+	type ItemMut<'a>
+		= &'a mut V
+	where
+		Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<K, V, S> Keyed for IndexMap<K, V, S> {
+	type Key = K;
+}
+
+impl<K, V, S> KeyedRef for IndexMap<K, V, S> {
+	type KeyRef<'a>
+		= &'a K
+	where
+		Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<K, V, S: Default> WithCapacity for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		IndexMap::with_capacity_and_hasher(capacity, S::default())
+	}
+}
+
+impl<K, V, S> Len for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<K, V, S> Len64 for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<K, V, S> LenHint for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<K, V, S> Capacity for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Reserve for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl<'a, Q, K, V, S: BuildHasher> Get<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&V> {
+		self.get(key)
+	}
+}
+
+impl<'a, Q, K, V, S: BuildHasher> GetMut<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+		self.get_mut(key)
+	}
+}
+
+impl<'a, Q, K, V, S: BuildHasher> GetKeyValue<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.get_key_value(key)
+	}
+}
+
+impl<K, V, S> GetIndex for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+		self.get_index(index)
+	}
+}
+
+impl<K, V, S> GetIndexMut for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+		self.get_index_mut(index)
+	}
+}
+
+impl<'a, Q, K, V, S: BuildHasher> GetIndexOf<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+{
+	#[inline(always)]
+	fn get_index_of(&self, key: &'a Q) -> Option<usize> {
+		self.get_index_of(key)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MapInsert<K> for IndexMap<K, V, S> {
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		self.insert(key, value)
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MergeFrom for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+	where
+		O: IntoIterator<Item = (K, V)>,
+		F: FnMut(&mut V, V),
+	{
+		let other = other.into_iter();
+		self.reserve(other.size_hint().0);
+		for (key, value) in other {
+			match self.entry(key) {
+				Entry::Occupied(entry) => resolve(entry.into_mut(), value),
+				Entry::Vacant(entry) => {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> TryInsert<K> for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, Self>> {
+		match self.entry(key) {
+			Entry::Occupied(entry) => Err(OccupiedError {
+				existing: entry.into_mut(),
+				value,
+			}),
+			Entry::Vacant(entry) => Ok(entry.insert(value)),
+		}
+	}
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Update for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn update<F>(&mut self, key: K, f: F)
+	where
+		F: FnOnce(Option<V>) -> Option<V>,
+	{
+		match self.entry(key) {
+			Entry::Occupied(entry) => {
+				let index = entry.index();
+				let (key, value) = entry.swap_remove_entry();
+				match f(Some(value)) {
+					Some(value) => {
+						// The entry is appended then swapped back in place.
+						let (last, _) = self.insert_full(key, value);
+						self.swap_indices(index, last)
+					}
+					None => {
+						// Moving the swapped entry back to the end shifts the following entries.
+						if index < self.len() {
+							self.move_index(index, self.len() - 1)
+						}
+					}
+				}
+			}
+			Entry::Vacant(entry) => {
+				if let Some(value) = f(None) {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
+impl<K, V, S> OrderedMapOps for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn move_index(&mut self, from: usize, to: usize) {
+		self.move_index(from, to)
+	}
+
+	#[inline(always)]
+	fn swap_indices(&mut self, a: usize, b: usize) {
+		self.swap_indices(a, b)
+	}
+
+	#[inline(always)]
+	fn sort_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&K, &V, &K, &V) -> Ordering,
+	{
+		self.sort_by(f)
+	}
+}
+
+impl<'a, Q, K, V, S: BuildHasher> Remove<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<V> {
+		self.shift_remove(key)
+	}
+}
+
+impl<'a, Q, K, V, S: BuildHasher> RemoveEntry<&'a Q> for IndexMap<K, V, S>
+where
+	Q: Hash + Equivalent<K> + ?Sized,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+		self.shift_remove_entry(key)
+	}
+}
+
+impl<K, V, S> Clear for IndexMap<K, V, S> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<K, V, S> Iter for IndexMap<K, V, S> {
+	type Iter<'a>
+		= indexmap::map::Values<'a, K, V>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl<K, V, S> MapIter for IndexMap<K, V, S> {
+	type Iter<'a>
+		= indexmap::map::Iter<'a, K, V>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<K, V, S> MapIterMut for IndexMap<K, V, S> {
+	type IterMut<'a>
+		= indexmap::map::IterMut<'a, K, V>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
+
+impl<T, S> Collection for IndexSet<T, S> {
+	type Item = T;
+}
+
+impl<T, S> CollectionRef for IndexSet<T, S> {
+	type ItemRef<'a>
+		= &'a T
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, S: Default> WithCapacity for IndexSet<T, S> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		IndexSet::with_capacity_and_hasher(capacity, S::default())
+	}
+}
+
+impl<T, S> Len for IndexSet<T, S> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T, S> Len64 for IndexSet<T, S> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<T, S> LenHint for IndexSet<T, S> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<T, S> Capacity for IndexSet<T, S> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Reserve for IndexSet<T, S> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl<'a, Q, T, S: BuildHasher> Get<&'a Q> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+{
+	#[inline(always)]
+	fn get(&self, t: &'a Q) -> Option<&T> {
+		self.get(t)
+	}
+}
+
+impl<'a, Q, T, S: BuildHasher> Contains<&'a Q> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+{
+	#[inline(always)]
+	fn contains_value(&self, t: &'a Q) -> bool {
+		self.contains(t)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Insert for IndexSet<T, S> {
+	type Output = bool;
+
+	#[inline(always)]
+	fn insert(&mut self, t: T) -> bool {
+		self.insert(t)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SetEntryApi for IndexSet<T, S> {
+	#[inline(always)]
+	fn get_or_insert(&mut self, t: T) -> &T {
+		let (index, _) = self.insert_full(t);
+		&self[index]
+	}
+}
+
+impl<'a, Q, T: Hash + Eq, S: BuildHasher> SetEntryApiWith<&'a Q> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+{
+	#[inline(always)]
+	fn get_or_insert_with<F>(&mut self, value: &'a Q, f: F) -> &T
+	where
+		F: FnOnce(&'a Q) -> T,
+	{
+		let index = match self.get_index_of(value) {
+			Some(index) => index,
+			None => self.insert_full(f(value)).0,
+		};
+		&self[index]
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Replace for IndexSet<T, S> {
+	#[inline(always)]
+	fn replace(&mut self, t: T) -> Option<T> {
+		self.replace(t)
+	}
+}
+
+impl<'a, Q, T, S: BuildHasher> Take<&'a Q> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+{
+	#[inline(always)]
+	fn take(&mut self, t: &'a Q) -> Option<T> {
+		self.shift_take(t)
+	}
+}
+
+impl<'a, Q, T, S: BuildHasher> Remove<&'a Q> for IndexSet<T, S>
+where
+	Q: Hash + Equivalent<T> + ?Sized,
+{
+	#[inline(always)]
+	fn remove(&mut self, t: &'a Q) -> Option<T> {
+		self.shift_take(t)
+	}
+}
+
+impl<T, S> SetPop for IndexSet<T, S> {
+	#[inline(always)]
+	fn pop(&mut self) -> Option<T> {
+		self.pop()
+	}
+}
+
+impl<T, S> Clear for IndexSet<T, S> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, S> Iter for IndexSet<T, S> {
+	type Iter<'a>
+		= indexmap::set::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SetOps for IndexSet<T, S> {
+	type Union<'a>
+		= indexmap::set::Union<'a, T, S>
+	where
+		Self: 'a;
+
+	type Intersection<'a>
+		= indexmap::set::Intersection<'a, T, S>
+	where
+		Self: 'a;
+
+	type Difference<'a>
+		= indexmap::set::Difference<'a, T, S>
+	where
+		Self: 'a;
+
+	type SymmetricDifference<'a>
+		= indexmap::set::SymmetricDifference<'a, T, S, S>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn union<'a>(&'a self, other: &'a Self) -> Self::Union<'a> {
+		self.union(other)
+	}
+
+	#[inline(always)]
+	fn intersection<'a>(&'a self, other: &'a Self) -> Self::Intersection<'a> {
+		self.intersection(other)
+	}
+
+	#[inline(always)]
+	fn difference<'a>(&'a self, other: &'a Self) -> Self::Difference<'a> {
+		self.difference(other)
+	}
+
+	#[inline(always)]
+	fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Self::SymmetricDifference<'a> {
+		self.symmetric_difference(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SetCompare for IndexSet<T, S> {
+	#[inline(always)]
+	fn is_subset(&self, other: &Self) -> bool {
+		self.is_subset(other)
+	}
+
+	#[inline(always)]
+	fn is_superset(&self, other: &Self) -> bool {
+		self.is_superset(other)
+	}
+
+	#[inline(always)]
+	fn is_disjoint(&self, other: &Self) -> bool {
+		self.is_disjoint(other)
+	}
+}
@@ -1,5 +1,9 @@
 #[cfg(not(feature = "nostd"))]
 mod std_collections;
+#[cfg(not(feature = "nostd"))]
+pub use self::std_collections::{CellRef, CharIter, CharRef, LockRef};
+
+mod pointers;
 
 #[cfg(feature = "slab")]
 mod slab;
@@ -12,3 +16,36 @@ mod serde_json;
 
 #[cfg(feature = "ijson")]
 mod ijson;
+
+#[cfg(feature = "bimap")]
+mod bimap;
+
+#[cfg(all(feature = "rayon", not(feature = "nostd")))]
+mod rayon;
+
+#[cfg(all(feature = "tokio", feature = "async"))]
+mod tokio;
+
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "either")]
+pub use self::either::EitherMapIter;
+
+#[cfg(feature = "indexmap")]
+mod indexmap;
+
+#[cfg(any(feature = "im", feature = "im-rc"))]
+mod im;
+
+#[cfg(feature = "rpds")]
+mod rpds;
+
+#[cfg(feature = "sled")]
+mod sled;
+#[cfg(feature = "sled")]
+pub use self::sled::{SledItemMut, SledIter, SledRef};
+
+#[cfg(feature = "heed")]
+mod heed;
+#[cfg(feature = "heed")]
+pub use self::heed::{HeedIter, HeedRead, HeedRef, HeedWrite};
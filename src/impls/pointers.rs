@@ -0,0 +1,535 @@
+//! Forwarding implementations for references and smart pointers.
+//!
+//! Shared references, `Rc` and `Arc` forward the read-only traits
+//! of the collection they point to,
+//! while mutable references and `Box` forward every trait.
+//! `Cow` forwards the read-only traits of the borrowed collection,
+//! and the mutable traits of the owned collection
+//! by cloning the borrowed collection on the first modification.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Get, MapInsert};
+//! use std::{borrow::Cow, collections::HashMap};
+//!
+//! let mut map = HashMap::new();
+//! map.insert("a", 1);
+//!
+//! let mut cow = Cow::Borrowed(&map);
+//! assert_eq!(cow.get(&"a").map(|v| *v), Some(1));
+//! cow.insert("b", 2); // clones the borrowed map.
+//! assert!(map.get(&"b").is_none());
+//! assert_eq!(cow.get(&"b").map(|v| *v), Some(2));
+//!
+//! let mut boxed = Box::new(map);
+//! (&mut boxed).insert("c", 3);
+//! assert_eq!(boxed.get(&"c").map(|v| *v), Some(3));
+//! ```
+use crate::{
+	AsMutSlice, AsSlice, Capacity, Clear, Collection, CollectionMut, CollectionRef, Contains, Get,
+	GetKeyValue, GetKeyValueMut, GetMut, Insert, Iter, IterMut, Keyed, KeyedRef, Len, Len64,
+	LenHint, MapInsert, MapIter, MapIterMut, PopBack, PopFront, PushBack, PushFront, PushStr,
+	Remove, Reserve, Update,
+};
+use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+macro_rules! forward_read {
+	($($ty:ty $(where C: $bound:path)?),*) => {
+		$(
+			impl<C: ?Sized + Collection> Collection for $ty
+			where
+				C: $($bound)?,
+			{
+				type Item = C::Item;
+			}
+
+			impl<C: ?Sized + CollectionRef> CollectionRef for $ty
+			where
+				C: $($bound)?,
+			{
+				type ItemRef<'a>
+					= C::ItemRef<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+				where
+					Self: 'long,
+				{
+					C::upcast_item_ref(r)
+				}
+			}
+
+			impl<C: ?Sized + Keyed> Keyed for $ty
+			where
+				C: $($bound)?,
+			{
+				type Key = C::Key;
+			}
+
+			impl<C: ?Sized + KeyedRef> KeyedRef for $ty
+			where
+				C: $($bound)?,
+			{
+				type KeyRef<'a>
+					= C::KeyRef<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+				where
+					Self: 'long,
+				{
+					C::upcast_key_ref(r)
+				}
+			}
+
+			impl<C: ?Sized + Len> Len for $ty
+			where
+				C: $($bound)?,
+			{
+				#[inline(always)]
+				fn len(&self) -> usize {
+					(**self).len()
+				}
+
+				#[inline(always)]
+				fn is_empty(&self) -> bool {
+					(**self).is_empty()
+				}
+			}
+
+			impl<C: ?Sized + Len64> Len64 for $ty
+			where
+				C: $($bound)?,
+			{
+				#[inline(always)]
+				fn len64(&self) -> u64 {
+					(**self).len64()
+				}
+			}
+
+			impl<C: ?Sized + LenHint> LenHint for $ty
+			where
+				C: $($bound)?,
+			{
+				#[inline(always)]
+				fn len_hint(&self) -> (usize, Option<usize>) {
+					(**self).len_hint()
+				}
+			}
+
+			impl<C: ?Sized + Capacity> Capacity for $ty
+			where
+				C: $($bound)?,
+			{
+				#[inline(always)]
+				fn capacity(&self) -> usize {
+					(**self).capacity()
+				}
+			}
+
+			impl<T, C: ?Sized + Get<T>> Get<T> for $ty
+			where
+				C: $($bound)?,
+			{
+				#[inline(always)]
+				fn get(&self, key: T) -> Option<Self::ItemRef<'_>> {
+					(**self).get(key)
+				}
+			}
+
+			impl<T, C: ?Sized + GetKeyValue<T>> GetKeyValue<T> for $ty
+			where
+				C: $($bound)?,
+			{
+				#[inline(always)]
+				fn get_key_value(&self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+					(**self).get_key_value(key)
+				}
+			}
+
+			impl<T, C: ?Sized + Contains<T>> Contains<T> for $ty
+			where
+				C: $($bound)?,
+			{
+				#[inline(always)]
+				fn contains_value(&self, value: T) -> bool {
+					(**self).contains_value(value)
+				}
+			}
+
+			impl<C: ?Sized + Iter> Iter for $ty
+			where
+				C: $($bound)?,
+			{
+				type Iter<'a>
+					= C::Iter<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn iter(&self) -> Self::Iter<'_> {
+					Iter::iter(&**self)
+				}
+			}
+
+			impl<C: ?Sized + MapIter> MapIter for $ty
+			where
+				C: $($bound)?,
+			{
+				type Iter<'a>
+					= C::Iter<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn iter(&self) -> Self::Iter<'_> {
+					MapIter::iter(&**self)
+				}
+			}
+
+			impl<C: ?Sized + AsSlice> AsSlice for $ty
+			where
+				C: $($bound)?,
+			{
+				#[inline(always)]
+				fn as_slice(&self) -> &[Self::Item] {
+					(**self).as_slice()
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! forward_mut {
+	($($ty:ty),*) => {
+		$(
+			impl<C: ?Sized + CollectionMut> CollectionMut for $ty {
+				type ItemMut<'a>
+					= C::ItemMut<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+				where
+					Self: 'long,
+				{
+					C::upcast_item_mut(r)
+				}
+			}
+
+			impl<T, C: ?Sized + GetMut<T>> GetMut<T> for $ty {
+				#[inline(always)]
+				fn get_mut(&mut self, key: T) -> Option<Self::ItemMut<'_>> {
+					(**self).get_mut(key)
+				}
+			}
+
+			impl<T, C: ?Sized + GetKeyValueMut<T>> GetKeyValueMut<T> for $ty {
+				#[inline(always)]
+				fn get_key_value_mut(&mut self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemMut<'_>)> {
+					(**self).get_key_value_mut(key)
+				}
+			}
+
+			impl<C: ?Sized + Insert> Insert for $ty {
+				type Output = C::Output;
+
+				#[inline(always)]
+				fn insert(&mut self, element: Self::Item) -> Self::Output {
+					(**self).insert(element)
+				}
+			}
+
+			impl<K, C: ?Sized + MapInsert<K>> MapInsert<K> for $ty {
+				type Output = C::Output;
+
+				#[inline(always)]
+				fn insert(&mut self, key: K, value: Self::Item) -> Self::Output {
+					(**self).insert(key, value)
+				}
+			}
+
+			impl<T, C: ?Sized + Remove<T>> Remove<T> for $ty {
+				#[inline(always)]
+				fn remove(&mut self, key: T) -> Option<Self::Item> {
+					(**self).remove(key)
+				}
+			}
+
+			impl<C: ?Sized + Update> Update for $ty {
+				#[inline(always)]
+				fn update<F>(&mut self, key: Self::Key, f: F)
+				where
+					F: FnOnce(Option<Self::Item>) -> Option<Self::Item>,
+				{
+					(**self).update(key, f)
+				}
+			}
+
+			impl<C: ?Sized + PushFront> PushFront for $ty {
+				type Output = C::Output;
+
+				#[inline(always)]
+				fn push_front(&mut self, element: Self::Item) -> Self::Output {
+					(**self).push_front(element)
+				}
+			}
+
+			impl<C: ?Sized + PushBack> PushBack for $ty {
+				type Output = C::Output;
+
+				#[inline(always)]
+				fn push_back(&mut self, element: Self::Item) -> Self::Output {
+					(**self).push_back(element)
+				}
+			}
+
+			impl<S: ?Sized, C: ?Sized + PushStr<S>> PushStr<S> for $ty {
+				#[inline(always)]
+				fn push_str(&mut self, s: &S) {
+					(**self).push_str(s)
+				}
+			}
+
+			impl<C: ?Sized + PopFront> PopFront for $ty {
+				#[inline(always)]
+				fn pop_front(&mut self) -> Option<Self::Item> {
+					(**self).pop_front()
+				}
+			}
+
+			impl<C: ?Sized + PopBack> PopBack for $ty {
+				#[inline(always)]
+				fn pop_back(&mut self) -> Option<Self::Item> {
+					(**self).pop_back()
+				}
+			}
+
+			impl<C: ?Sized + Clear> Clear for $ty {
+				#[inline(always)]
+				fn clear(&mut self) {
+					(**self).clear()
+				}
+			}
+
+			impl<C: ?Sized + Reserve> Reserve for $ty {
+				#[inline(always)]
+				fn reserve(&mut self, additional: usize) {
+					(**self).reserve(additional)
+				}
+			}
+
+			impl<C: ?Sized + IterMut> IterMut for $ty {
+				type IterMut<'a>
+					= C::IterMut<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn iter_mut(&mut self) -> Self::IterMut<'_> {
+					IterMut::iter_mut(&mut **self)
+				}
+			}
+
+			impl<C: ?Sized + MapIterMut> MapIterMut for $ty {
+				type IterMut<'a>
+					= C::IterMut<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn iter_mut(&mut self) -> Self::IterMut<'_> {
+					MapIterMut::iter_mut(&mut **self)
+				}
+			}
+
+			impl<C: ?Sized + AsMutSlice> AsMutSlice for $ty {
+				#[inline(always)]
+				fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+					(**self).as_mut_slice()
+				}
+			}
+		)*
+	};
+}
+
+forward_read!(&C, &mut C, Box<C>, Rc<C>, Arc<C>, Cow<'_, C> where C: ToOwned);
+forward_mut!(&mut C, Box<C>);
+
+impl<C> CollectionMut for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: CollectionMut<Item = C::Item>,
+{
+	type ItemMut<'a>
+		= <C::Owned as CollectionMut>::ItemMut<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		C::Owned::upcast_item_mut(r)
+	}
+}
+
+impl<T, C> GetMut<T> for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Get<T>,
+	C::Owned: GetMut<T, Item = C::Item>,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: T) -> Option<Self::ItemMut<'_>> {
+		self.to_mut().get_mut(key)
+	}
+}
+
+impl<C> Insert for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: Insert<Item = C::Item>,
+{
+	type Output = <C::Owned as Insert>::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, element: Self::Item) -> Self::Output {
+		self.to_mut().insert(element)
+	}
+}
+
+impl<K, C> MapInsert<K> for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: MapInsert<K, Item = C::Item>,
+{
+	type Output = <C::Owned as MapInsert<K>>::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: Self::Item) -> Self::Output {
+		self.to_mut().insert(key, value)
+	}
+}
+
+impl<T, C> Remove<T> for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: Remove<T, Item = C::Item>,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: T) -> Option<Self::Item> {
+		self.to_mut().remove(key)
+	}
+}
+
+impl<C> Update for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Keyed,
+	C::Owned: Update<Item = C::Item, Key = C::Key>,
+{
+	#[inline(always)]
+	fn update<F>(&mut self, key: Self::Key, f: F)
+	where
+		F: FnOnce(Option<Self::Item>) -> Option<Self::Item>,
+	{
+		self.to_mut().update(key, f)
+	}
+}
+
+impl<C> PushFront for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: PushFront<Item = C::Item>,
+{
+	type Output = <C::Owned as PushFront>::Output;
+
+	#[inline(always)]
+	fn push_front(&mut self, element: Self::Item) -> Self::Output {
+		self.to_mut().push_front(element)
+	}
+}
+
+impl<C> PushBack for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: PushBack<Item = C::Item>,
+{
+	type Output = <C::Owned as PushBack>::Output;
+
+	#[inline(always)]
+	fn push_back(&mut self, element: Self::Item) -> Self::Output {
+		self.to_mut().push_back(element)
+	}
+}
+
+impl<C> PopFront for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: PopFront<Item = C::Item>,
+{
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<Self::Item> {
+		self.to_mut().pop_front()
+	}
+}
+
+impl<C> PopBack for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: PopBack<Item = C::Item>,
+{
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<Self::Item> {
+		self.to_mut().pop_back()
+	}
+}
+
+/// A borrowed collection is replaced by an empty owned collection
+/// instead of being cloned then cleared.
+impl<C> Clear for Cow<'_, C>
+where
+	C: ?Sized + ToOwned,
+	C::Owned: Clear + Default,
+{
+	#[inline(always)]
+	fn clear(&mut self) {
+		match self {
+			Cow::Borrowed(_) => *self = Cow::Owned(Default::default()),
+			Cow::Owned(collection) => collection.clear(),
+		}
+	}
+}
+
+impl<C> Reserve for Cow<'_, C>
+where
+	C: ?Sized + ToOwned,
+	C::Owned: Reserve,
+{
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.to_mut().reserve(additional)
+	}
+}
+
+impl<C> IterMut for Cow<'_, C>
+where
+	C: ?Sized + ToOwned + Collection,
+	C::Owned: IterMut<Item = C::Item>,
+{
+	type IterMut<'a>
+		= <C::Owned as IterMut>::IterMut<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.to_mut().iter_mut()
+	}
+}
@@ -0,0 +1,323 @@
+//! Implementations for the `rayon` parallel iterators of the standard library collections.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{IntoParIter, ParExtend, ParIter, ParMapIter};
+//! use rayon::iter::ParallelIterator;
+//! use std::collections::{BTreeSet, HashMap};
+//!
+//! let mut vec = vec![1, 2, 3];
+//! ParExtend::par_extend(&mut vec, vec![4, 5]);
+//! assert_eq!(ParIter::par_iter(&vec).sum::<i32>(), 15);
+//!
+//! let map: HashMap<_, _> = vec.iter().map(|i| (*i, i * 2)).collect();
+//! assert_eq!(ParMapIter::par_iter(&map).map(|(_, v)| *v).sum::<i32>(), 30);
+//! assert_eq!(ParIter::par_iter(&vec[1..]).sum::<i32>(), 14);
+//!
+//! let set: BTreeSet<_> = vec.iter().copied().collect();
+//! assert_eq!(ParIter::par_iter(&set).max(), Some(&5));
+//! assert_eq!(IntoParIter::into_par_iter(vec).count(), 5);
+//! ```
+use crate::{IntoParIter, IntoParMapIter, ParExtend, ParIter, ParIterMut, ParMapIter};
+use rayon::iter::{
+	IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelExtend,
+};
+use std::{
+	collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+	hash::Hash,
+};
+
+impl<T: Send> ParExtend<T> for Vec<T> {
+	#[inline(always)]
+	fn par_extend<I>(&mut self, items: I)
+	where
+		I: IntoParallelIterator<Item = T>,
+	{
+		ParallelExtend::par_extend(self, items)
+	}
+}
+
+impl<T: Send> ParExtend<T> for VecDeque<T> {
+	#[inline(always)]
+	fn par_extend<I>(&mut self, items: I)
+	where
+		I: IntoParallelIterator<Item = T>,
+	{
+		ParallelExtend::par_extend(self, items)
+	}
+}
+
+impl<T: Hash + Eq + Send> ParExtend<T> for HashSet<T> {
+	#[inline(always)]
+	fn par_extend<I>(&mut self, items: I)
+	where
+		I: IntoParallelIterator<Item = T>,
+	{
+		ParallelExtend::par_extend(self, items)
+	}
+}
+
+impl<T: Ord + Send> ParExtend<T> for BTreeSet<T> {
+	#[inline(always)]
+	fn par_extend<I>(&mut self, items: I)
+	where
+		I: IntoParallelIterator<Item = T>,
+	{
+		ParallelExtend::par_extend(self, items)
+	}
+}
+
+impl<K: Hash + Eq + Send, V: Send> ParExtend<(K, V)> for HashMap<K, V> {
+	#[inline(always)]
+	fn par_extend<I>(&mut self, items: I)
+	where
+		I: IntoParallelIterator<Item = (K, V)>,
+	{
+		ParallelExtend::par_extend(self, items)
+	}
+}
+
+impl<K: Ord + Send, V: Send> ParExtend<(K, V)> for BTreeMap<K, V> {
+	#[inline(always)]
+	fn par_extend<I>(&mut self, items: I)
+	where
+		I: IntoParallelIterator<Item = (K, V)>,
+	{
+		ParallelExtend::par_extend(self, items)
+	}
+}
+
+impl<T: Send> IntoParIter for Vec<T> {
+	type IntoParIter = rayon::vec::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::IntoParIter {
+		IntoParallelIterator::into_par_iter(self)
+	}
+}
+
+impl<T: Send> IntoParIter for VecDeque<T> {
+	type IntoParIter = rayon::collections::vec_deque::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::IntoParIter {
+		IntoParallelIterator::into_par_iter(self)
+	}
+}
+
+impl<T: Hash + Eq + Send> IntoParIter for HashSet<T> {
+	type IntoParIter = rayon::collections::hash_set::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::IntoParIter {
+		IntoParallelIterator::into_par_iter(self)
+	}
+}
+
+impl<T: Ord + Send> IntoParIter for BTreeSet<T> {
+	type IntoParIter = rayon::collections::btree_set::IntoIter<T>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::IntoParIter {
+		IntoParallelIterator::into_par_iter(self)
+	}
+}
+
+impl<K: Hash + Eq + Send, V: Send> IntoParMapIter for HashMap<K, V> {
+	type IntoParIter = rayon::collections::hash_map::IntoIter<K, V>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::IntoParIter {
+		IntoParallelIterator::into_par_iter(self)
+	}
+}
+
+impl<K: Ord + Send, V: Send> IntoParMapIter for BTreeMap<K, V> {
+	type IntoParIter = rayon::collections::btree_map::IntoIter<K, V>;
+
+	#[inline(always)]
+	fn into_par_iter(self) -> Self::IntoParIter {
+		IntoParallelIterator::into_par_iter(self)
+	}
+}
+
+impl<T: Sync> ParIter for Vec<T> {
+	type ParIter<'a>
+		= rayon::slice::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter(&self) -> Self::ParIter<'_> {
+		IntoParallelRefIterator::par_iter(self.as_slice())
+	}
+}
+
+impl<T: Send> ParIterMut for Vec<T> {
+	type ParIterMut<'a>
+		= rayon::slice::IterMut<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter_mut(&mut self) -> Self::ParIterMut<'_> {
+		IntoParallelRefMutIterator::par_iter_mut(self.as_mut_slice())
+	}
+}
+
+impl<T: Sync> ParIter for VecDeque<T> {
+	type ParIter<'a>
+		= rayon::collections::vec_deque::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter(&self) -> Self::ParIter<'_> {
+		IntoParallelRefIterator::par_iter(self)
+	}
+}
+
+impl<T: Send> ParIterMut for VecDeque<T> {
+	type ParIterMut<'a>
+		= rayon::collections::vec_deque::IterMut<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter_mut(&mut self) -> Self::ParIterMut<'_> {
+		IntoParallelRefMutIterator::par_iter_mut(self)
+	}
+}
+
+impl<T: Hash + Eq + Sync> ParIter for HashSet<T> {
+	type ParIter<'a>
+		= rayon::collections::hash_set::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter(&self) -> Self::ParIter<'_> {
+		IntoParallelRefIterator::par_iter(self)
+	}
+}
+
+impl<T: Ord + Sync> ParIter for BTreeSet<T> {
+	type ParIter<'a>
+		= rayon::collections::btree_set::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter(&self) -> Self::ParIter<'_> {
+		IntoParallelRefIterator::par_iter(self)
+	}
+}
+
+impl<T: Sync> ParIter for [T] {
+	type ParIter<'a>
+		= rayon::slice::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter(&self) -> Self::ParIter<'_> {
+		IntoParallelRefIterator::par_iter(self)
+	}
+}
+
+impl<T: Send> ParIterMut for [T] {
+	type ParIterMut<'a>
+		= rayon::slice::IterMut<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter_mut(&mut self) -> Self::ParIterMut<'_> {
+		IntoParallelRefMutIterator::par_iter_mut(self)
+	}
+}
+
+impl<T: Sync, const N: usize> ParIter for [T; N] {
+	type ParIter<'a>
+		= rayon::slice::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter(&self) -> Self::ParIter<'_> {
+		IntoParallelRefIterator::par_iter(&self[..])
+	}
+}
+
+impl<T: Send, const N: usize> ParIterMut for [T; N] {
+	type ParIterMut<'a>
+		= rayon::slice::IterMut<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter_mut(&mut self) -> Self::ParIterMut<'_> {
+		IntoParallelRefMutIterator::par_iter_mut(&mut self[..])
+	}
+}
+
+impl<K: Hash + Eq + Sync, V: Sync> ParMapIter for HashMap<K, V> {
+	type ParIter<'a>
+		= rayon::collections::hash_map::Iter<'a, K, V>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter(&self) -> Self::ParIter<'_> {
+		IntoParallelRefIterator::par_iter(self)
+	}
+}
+
+impl<K: Hash + Eq + Sync, V: Send> ParIterMut for HashMap<K, V> {
+	type ParIterMut<'a>
+		= rayon::iter::Map<
+		rayon::collections::hash_map::IterMut<'a, K, V>,
+		fn((&'a K, &'a mut V)) -> &'a mut V,
+	>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter_mut(&mut self) -> Self::ParIterMut<'_> {
+		rayon::iter::ParallelIterator::map(
+			IntoParallelRefMutIterator::par_iter_mut(self),
+			|(_, v)| v,
+		)
+	}
+}
+
+impl<K: Ord + Sync, V: Sync> ParMapIter for BTreeMap<K, V> {
+	type ParIter<'a>
+		= rayon::collections::btree_map::Iter<'a, K, V>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter(&self) -> Self::ParIter<'_> {
+		IntoParallelRefIterator::par_iter(self)
+	}
+}
+
+impl<K: Ord + Sync, V: Send> ParIterMut for BTreeMap<K, V> {
+	type ParIterMut<'a>
+		= rayon::iter::Map<
+		rayon::collections::btree_map::IterMut<'a, K, V>,
+		fn((&'a K, &'a mut V)) -> &'a mut V,
+	>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn par_iter_mut(&mut self) -> Self::ParIterMut<'_> {
+		rayon::iter::ParallelIterator::map(
+			IntoParallelRefMutIterator::par_iter_mut(self),
+			|(_, v)| v,
+		)
+	}
+}
@@ -0,0 +1,557 @@
+//! Implementations for the `rpds` persistent collections.
+//!
+//! The traits are implemented for any pointer kind `P`
+//! (so for both the `Rc` based and the `Sync` variants).
+//! Mutations are done in place using the `*_mut` methods of `rpds`,
+//! and since those do not return the removed values,
+//! removing or updating requires the values to be `Clone`.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Get, Len, MapInsert, PopBack, PushBack, Remove};
+//!
+//! let mut map = rpds::HashTrieMap::new();
+//! MapInsert::insert(&mut map, "a", 1);
+//! let snapshot = map.clone();
+//! assert_eq!(Remove::remove(&mut map, &"a"), Some(1));
+//! assert!(Len::is_empty(&map));
+//! assert_eq!(Get::get(&snapshot, &"a").map(|v| *v), Some(1));
+//!
+//! let mut vec = rpds::Vector::new();
+//! PushBack::push_back(&mut vec, 1);
+//! PushBack::push_back(&mut vec, 2);
+//! assert_eq!(PopBack::pop_back(&mut vec), Some(2));
+//! assert_eq!(Len::len(&vec), 1);
+//! ```
+use crate::{
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, Keyed,
+	KeyedRef, Len, LenHint, MapInsert, MapIter, MergeFrom, OccupiedError, PopBack, PushBack,
+	Remove, RemoveEntry, TryInsert, Update,
+};
+use archery::SharedPointerKind;
+use rpds::{
+	map::{hash_trie_map, red_black_tree_map},
+	vector, HashTrieMap, RedBlackTreeMap, Vector,
+};
+use std::{
+	borrow::Borrow,
+	hash::{BuildHasher, Hash},
+};
+
+impl<K, V, P: SharedPointerKind, H: BuildHasher> Collection for HashTrieMap<K, V, P, H> {
+	type Item = V;
+}
+
+impl<K, V, P: SharedPointerKind, H: BuildHasher> CollectionRef for HashTrieMap<K, V, P, H> {
+	type ItemRef<'a>
+		= &'a V
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<K, V, P: SharedPointerKind, H: BuildHasher> CollectionMut for HashTrieMap<K, V, P, H> {
+	type ItemMut<'a>
+		= &'a mut V
+	where
+		Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<K, V, P: SharedPointerKind, H: BuildHasher> Keyed for HashTrieMap<K, V, P, H> {
+	type Key = K;
+}
+
+impl<K, V, P: SharedPointerKind, H: BuildHasher> KeyedRef for HashTrieMap<K, V, P, H> {
+	type KeyRef<'a>
+		= &'a K
+	where
+		Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<K: Hash + Eq, V, P: SharedPointerKind, H: BuildHasher + Clone> Len
+	for HashTrieMap<K, V, P, H>
+{
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.size()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<K: Hash + Eq, V, P: SharedPointerKind, H: BuildHasher + Clone> LenHint
+	for HashTrieMap<K, V, P, H>
+{
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.size(), Some(self.size()))
+	}
+}
+
+impl<'a, Q, K: Hash + Eq, V, P: SharedPointerKind, H: BuildHasher + Clone> Get<&'a Q>
+	for HashTrieMap<K, V, P, H>
+where
+	K: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&V> {
+		self.get(key)
+	}
+}
+
+impl<'a, Q, K: Hash + Eq + Clone, V: Clone, P: SharedPointerKind, H: BuildHasher + Clone>
+	GetMut<&'a Q> for HashTrieMap<K, V, P, H>
+where
+	K: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+		self.get_mut(key)
+	}
+}
+
+impl<'a, Q, K: Hash + Eq, V, P: SharedPointerKind, H: BuildHasher + Clone> GetKeyValue<&'a Q>
+	for HashTrieMap<K, V, P, H>
+where
+	K: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.get_key_value(key)
+	}
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, P: SharedPointerKind, H: BuildHasher + Clone> MapInsert<K>
+	for HashTrieMap<K, V, P, H>
+{
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		match self.get_mut(&key) {
+			Some(existing) => Some(std::mem::replace(existing, value)),
+			None => {
+				self.insert_mut(key, value);
+				None
+			}
+		}
+	}
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, P: SharedPointerKind, H: BuildHasher + Clone> MergeFrom
+	for HashTrieMap<K, V, P, H>
+{
+	#[inline(always)]
+	fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+	where
+		O: IntoIterator<Item = (K, V)>,
+		F: FnMut(&mut V, V),
+	{
+		for (key, value) in other {
+			match self.get_mut(&key) {
+				Some(existing) => resolve(existing, value),
+				None => self.insert_mut(key, value),
+			}
+		}
+	}
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, P: SharedPointerKind, H: BuildHasher + Clone> TryInsert<K>
+	for HashTrieMap<K, V, P, H>
+{
+	#[inline(always)]
+	fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, Self>> {
+		if self.contains_key(&key) {
+			Err(OccupiedError {
+				existing: self.get_mut(&key).unwrap(),
+				value,
+			})
+		} else {
+			self.insert_mut(key.clone(), value);
+			Ok(self.get_mut(&key).unwrap())
+		}
+	}
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, P: SharedPointerKind, H: BuildHasher + Clone> Update
+	for HashTrieMap<K, V, P, H>
+{
+	#[inline(always)]
+	fn update<F>(&mut self, key: K, f: F)
+	where
+		F: FnOnce(Option<V>) -> Option<V>,
+	{
+		match f(self.get(&key).cloned()) {
+			Some(value) => {
+				MapInsert::insert(self, key, value);
+			}
+			None => {
+				self.remove_mut(&key);
+			}
+		}
+	}
+}
+
+impl<'a, Q, K: Hash + Eq, V: Clone, P: SharedPointerKind, H: BuildHasher + Clone> Remove<&'a Q>
+	for HashTrieMap<K, V, P, H>
+where
+	K: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<V> {
+		let value = self.get(key).cloned()?;
+		self.remove_mut(key);
+		Some(value)
+	}
+}
+
+impl<'a, Q, K: Hash + Eq + Clone, V: Clone, P: SharedPointerKind, H: BuildHasher + Clone>
+	RemoveEntry<&'a Q> for HashTrieMap<K, V, P, H>
+where
+	K: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+		let (k, v) = self.get_key_value(key)?;
+		let entry = (k.clone(), v.clone());
+		self.remove_mut(key);
+		Some(entry)
+	}
+}
+
+impl<K: Hash + Eq, V, P: SharedPointerKind, H: BuildHasher + Clone> Iter
+	for HashTrieMap<K, V, P, H>
+{
+	type Iter<'a>
+		= hash_trie_map::IterValues<'a, K, V, P>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl<K: Hash + Eq, V, P: SharedPointerKind, H: BuildHasher + Clone> MapIter
+	for HashTrieMap<K, V, P, H>
+{
+	type Iter<'a>
+		= hash_trie_map::Iter<'a, K, V, P>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<K, V, P: SharedPointerKind> Collection for RedBlackTreeMap<K, V, P> {
+	type Item = V;
+}
+
+impl<K, V, P: SharedPointerKind> CollectionRef for RedBlackTreeMap<K, V, P> {
+	type ItemRef<'a>
+		= &'a V
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<K, V, P: SharedPointerKind> CollectionMut for RedBlackTreeMap<K, V, P> {
+	type ItemMut<'a>
+		= &'a mut V
+	where
+		Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<K, V, P: SharedPointerKind> Keyed for RedBlackTreeMap<K, V, P> {
+	type Key = K;
+}
+
+impl<K, V, P: SharedPointerKind> KeyedRef for RedBlackTreeMap<K, V, P> {
+	type KeyRef<'a>
+		= &'a K
+	where
+		Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<K: Ord, V, P: SharedPointerKind> Len for RedBlackTreeMap<K, V, P> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.size()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<K: Ord, V, P: SharedPointerKind> LenHint for RedBlackTreeMap<K, V, P> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.size(), Some(self.size()))
+	}
+}
+
+impl<'a, Q, K: Ord, V, P: SharedPointerKind> Get<&'a Q> for RedBlackTreeMap<K, V, P>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&V> {
+		self.get(key)
+	}
+}
+
+impl<'a, Q, K: Ord + Clone, V: Clone, P: SharedPointerKind> GetMut<&'a Q>
+	for RedBlackTreeMap<K, V, P>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+		self.get_mut(key)
+	}
+}
+
+impl<'a, Q, K: Ord, V, P: SharedPointerKind> GetKeyValue<&'a Q> for RedBlackTreeMap<K, V, P>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.get_key_value(key)
+	}
+}
+
+impl<K: Ord + Clone, V: Clone, P: SharedPointerKind> MapInsert<K> for RedBlackTreeMap<K, V, P> {
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		match self.get_mut(&key) {
+			Some(existing) => Some(std::mem::replace(existing, value)),
+			None => {
+				self.insert_mut(key, value);
+				None
+			}
+		}
+	}
+}
+
+impl<K: Ord + Clone, V: Clone, P: SharedPointerKind> MergeFrom for RedBlackTreeMap<K, V, P> {
+	#[inline(always)]
+	fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+	where
+		O: IntoIterator<Item = (K, V)>,
+		F: FnMut(&mut V, V),
+	{
+		for (key, value) in other {
+			match self.get_mut(&key) {
+				Some(existing) => resolve(existing, value),
+				None => self.insert_mut(key, value),
+			}
+		}
+	}
+}
+
+impl<K: Ord + Clone, V: Clone, P: SharedPointerKind> TryInsert<K> for RedBlackTreeMap<K, V, P> {
+	#[inline(always)]
+	fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, Self>> {
+		if self.contains_key(&key) {
+			Err(OccupiedError {
+				existing: self.get_mut(&key).unwrap(),
+				value,
+			})
+		} else {
+			self.insert_mut(key.clone(), value);
+			Ok(self.get_mut(&key).unwrap())
+		}
+	}
+}
+
+impl<K: Ord + Clone, V: Clone, P: SharedPointerKind> Update for RedBlackTreeMap<K, V, P> {
+	#[inline(always)]
+	fn update<F>(&mut self, key: K, f: F)
+	where
+		F: FnOnce(Option<V>) -> Option<V>,
+	{
+		match f(self.get(&key).cloned()) {
+			Some(value) => {
+				MapInsert::insert(self, key, value);
+			}
+			None => {
+				self.remove_mut(&key);
+			}
+		}
+	}
+}
+
+impl<'a, Q, K: Ord, V: Clone, P: SharedPointerKind> Remove<&'a Q> for RedBlackTreeMap<K, V, P>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<V> {
+		let value = self.get(key).cloned()?;
+		self.remove_mut(key);
+		Some(value)
+	}
+}
+
+impl<'a, Q, K: Ord + Clone, V: Clone, P: SharedPointerKind> RemoveEntry<&'a Q>
+	for RedBlackTreeMap<K, V, P>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+		let (k, v) = self.get_key_value(key)?;
+		let entry = (k.clone(), v.clone());
+		self.remove_mut(key);
+		Some(entry)
+	}
+}
+
+impl<K: Ord, V, P: SharedPointerKind> Clear for RedBlackTreeMap<K, V, P> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		*self = RedBlackTreeMap::new_with_ptr_kind()
+	}
+}
+
+impl<K: Ord, V, P: SharedPointerKind> Iter for RedBlackTreeMap<K, V, P> {
+	type Iter<'a>
+		= red_black_tree_map::IterValues<'a, K, V, P>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl<K: Ord, V, P: SharedPointerKind> MapIter for RedBlackTreeMap<K, V, P> {
+	type Iter<'a>
+		= red_black_tree_map::Iter<'a, K, V, P>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T, P: SharedPointerKind> Collection for Vector<T, P> {
+	type Item = T;
+}
+
+impl<T, P: SharedPointerKind> CollectionRef for Vector<T, P> {
+	type ItemRef<'a>
+		= &'a T
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, P: SharedPointerKind> CollectionMut for Vector<T, P> {
+	type ItemMut<'a>
+		= &'a mut T
+	where
+		Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, P: SharedPointerKind> Len for Vector<T, P> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T, P: SharedPointerKind> LenHint for Vector<T, P> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<T, P: SharedPointerKind> Get<usize> for Vector<T, P> {
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&T> {
+		self.get(index)
+	}
+}
+
+impl<T: Clone, P: SharedPointerKind> GetMut<usize> for Vector<T, P> {
+	#[inline(always)]
+	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.get_mut(index)
+	}
+}
+
+impl<T, P: SharedPointerKind> PushBack for Vector<T, P> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_back(&mut self, t: T) {
+		self.push_back_mut(t)
+	}
+}
+
+impl<T: Clone, P: SharedPointerKind> PopBack for Vector<T, P> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<T> {
+		let t = self.last().cloned()?;
+		self.drop_last_mut();
+		Some(t)
+	}
+}
+
+impl<T, P: SharedPointerKind> Iter for Vector<T, P> {
+	type Iter<'a>
+		= vector::Iter<'a, T, P>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
@@ -1,6 +1,7 @@
 use crate::{
 	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed, KeyedRef,
-	Len, MapInsert, MapIter, MapIterMut, Remove,
+	Len, MapInsert, MapIter, MapIterMut, MergeFrom, OccupiedError, Remove, RemoveEntry, TryInsert,
+	Update,
 };
 use std::{borrow::Borrow, cmp::Ord, hash::Hash};
 
@@ -103,6 +104,67 @@ impl MapInsert<String> for serde_json::Map<String, serde_json::Value> {
 	}
 }
 
+impl MergeFrom for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+	where
+		O: IntoIterator<Item = (String, serde_json::Value)>,
+		F: FnMut(&mut serde_json::Value, serde_json::Value),
+	{
+		for (key, value) in other {
+			match self.entry(key) {
+				serde_json::map::Entry::Occupied(entry) => resolve(entry.into_mut(), value),
+				serde_json::map::Entry::Vacant(entry) => {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
+impl TryInsert<String> for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn try_insert(
+		&mut self,
+		key: String,
+		value: serde_json::Value,
+	) -> Result<&mut serde_json::Value, OccupiedError<'_, Self>> {
+		match self.entry(key) {
+			serde_json::map::Entry::Occupied(entry) => Err(OccupiedError {
+				existing: entry.into_mut(),
+				value,
+			}),
+			serde_json::map::Entry::Vacant(entry) => Ok(entry.insert(value)),
+		}
+	}
+}
+
+impl Update for serde_json::Map<String, serde_json::Value> {
+	/// Updates the value behind the given `key` in place,
+	/// leaving `Null` behind it while `f` runs.
+	#[inline(always)]
+	fn update<F>(&mut self, key: String, f: F)
+	where
+		F: FnOnce(Option<serde_json::Value>) -> Option<serde_json::Value>,
+	{
+		match self.entry(key) {
+			serde_json::map::Entry::Occupied(mut entry) => {
+				match f(Some(std::mem::take(entry.get_mut()))) {
+					Some(value) => *entry.get_mut() = value,
+					None => {
+						entry.remove();
+					}
+				}
+			}
+			serde_json::map::Entry::Vacant(entry) => {
+				if let Some(value) = f(None) {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
 impl<'a, Q: ?Sized> Remove<&'a Q> for serde_json::Map<String, serde_json::Value>
 where
 	String: Borrow<Q>,
@@ -114,6 +176,17 @@ where
 	}
 }
 
+impl<'a, Q: ?Sized> RemoveEntry<&'a Q> for serde_json::Map<String, serde_json::Value>
+where
+	String: Borrow<Q>,
+	Q: Ord + Hash,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(String, serde_json::Value)> {
+		self.remove_entry(key)
+	}
+}
+
 impl Clear for serde_json::Map<String, serde_json::Value> {
 	#[inline(always)]
 	fn clear(&mut self) {
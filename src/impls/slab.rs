@@ -1,6 +1,6 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Insert, Len, Remove,
-	Reserve, WithCapacity,
+	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Insert, Keyed, Len,
+	Remove, Reserve, WithCapacity,
 };
 use slab::Slab;
 
@@ -10,22 +10,26 @@ impl<T> Collection for Slab<T> {
 
 impl<T> CollectionRef for Slab<T> {
 	type ItemRef<'a>
+		= &'a T
 	where
-		Self: 'a,
-	= &'a T;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
 impl<T> CollectionMut for Slab<T> {
 	type ItemMut<'a>
+		= &'a mut T
 	where
-		Self: 'a,
-	= &'a mut T;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
 
+impl<T> Keyed for Slab<T> {
+	type Key = usize;
+}
+
 impl<T> WithCapacity for Slab<T> {
 	fn with_capacity(capacity: usize) -> Self {
 		Slab::with_capacity(capacity)
@@ -0,0 +1,228 @@
+//! Implementations for `sled::Tree`.
+//!
+//! Sled operations are fallible, but the traits of this crate are not:
+//! sled errors (I/O errors or corrupted databases) cause a panic.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Get, Len, MapInsert, Remove};
+//!
+//! let db = sled::Config::new().temporary(true).open().unwrap();
+//! let mut tree = db.open_tree("example").unwrap();
+//! MapInsert::insert(&mut tree, "a", sled::IVec::from("1"));
+//! assert_eq!(Get::get(&tree, "a").as_deref().map(|v| &v[..]), Some(&b"1"[..]));
+//! assert_eq!(Len::len(&tree), 1);
+//! assert!(Remove::remove(&mut tree, "a").is_some());
+//! assert!(Len::is_empty(&tree));
+//! ```
+use crate::{
+	BoundQuery, Collection, CollectionMut, CollectionRef, Get, GetMut, Keyed, KeyedRef, Len, Len64,
+	LenHint, MapInsert, MapIter, Remove,
+};
+use sled::{IVec, Tree};
+use std::ops::{Bound, Deref, DerefMut};
+
+const SLED_ERROR: &str = "sled error";
+
+/// Owned reference to a key or value of a `sled::Tree`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SledRef(pub IVec);
+
+impl Deref for SledRef {
+	type Target = IVec;
+
+	#[inline(always)]
+	fn deref(&self) -> &IVec {
+		&self.0
+	}
+}
+
+/// Mutable reference to a value of a `sled::Tree`.
+///
+/// The value is written back to the tree when the reference is dropped,
+/// if it has been mutably accessed.
+pub struct SledItemMut<'a> {
+	tree: &'a Tree,
+	key: IVec,
+	value: IVec,
+	modified: bool,
+}
+
+impl Deref for SledItemMut<'_> {
+	type Target = IVec;
+
+	#[inline(always)]
+	fn deref(&self) -> &IVec {
+		&self.value
+	}
+}
+
+impl DerefMut for SledItemMut<'_> {
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut IVec {
+		self.modified = true;
+		&mut self.value
+	}
+}
+
+impl Drop for SledItemMut<'_> {
+	fn drop(&mut self) {
+		if self.modified {
+			let result = self.tree.insert(&self.key, self.value.clone());
+			if !std::thread::panicking() {
+				result.expect(SLED_ERROR);
+			}
+		}
+	}
+}
+
+/// Iterator over the key-value pairs of a `sled::Tree`.
+pub struct SledIter(sled::Iter);
+
+impl Iterator for SledIter {
+	type Item = (SledRef, SledRef);
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|entry| {
+			entry
+				.map(|(k, v)| (SledRef(k), SledRef(v)))
+				.expect(SLED_ERROR)
+		})
+	}
+}
+
+impl DoubleEndedIterator for SledIter {
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|entry| {
+			entry
+				.map(|(k, v)| (SledRef(k), SledRef(v)))
+				.expect(SLED_ERROR)
+		})
+	}
+}
+
+impl Collection for Tree {
+	type Item = IVec;
+}
+
+impl CollectionRef for Tree {
+	type ItemRef<'a> = SledRef;
+
+	crate::covariant_item_ref!();
+}
+
+impl CollectionMut for Tree {
+	type ItemMut<'a> = SledItemMut<'a>;
+
+	crate::covariant_item_mut!();
+}
+
+impl Keyed for Tree {
+	type Key = IVec;
+}
+
+impl KeyedRef for Tree {
+	type KeyRef<'a> = SledRef;
+
+	crate::covariant_key_ref!();
+}
+
+impl Len for Tree {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl Len64 for Tree {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+/// Computing the length of a tree requires iterating over it,
+/// so the hint only distinguishes empty trees.
+impl LenHint for Tree {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		if self.is_empty() {
+			(0, Some(0))
+		} else {
+			(1, None)
+		}
+	}
+}
+
+impl<K: AsRef<[u8]>> Get<K> for Tree {
+	#[inline(always)]
+	fn get(&self, key: K) -> Option<SledRef> {
+		self.get(key).expect(SLED_ERROR).map(SledRef)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: K) -> bool {
+		self.contains_key(key).expect(SLED_ERROR)
+	}
+}
+
+impl<K: AsRef<[u8]>> GetMut<K> for Tree {
+	#[inline(always)]
+	fn get_mut(&mut self, key: K) -> Option<SledItemMut<'_>> {
+		let tree: &Tree = self;
+		let key = key.as_ref();
+		tree.get(key).expect(SLED_ERROR).map(|value| SledItemMut {
+			tree,
+			key: key.into(),
+			value,
+			modified: false,
+		})
+	}
+}
+
+impl<K: AsRef<[u8]>> MapInsert<K> for Tree {
+	type Output = Option<IVec>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: IVec) -> Option<IVec> {
+		Tree::insert(self, key, value).expect(SLED_ERROR)
+	}
+}
+
+impl<K: AsRef<[u8]>> Remove<K> for Tree {
+	#[inline(always)]
+	fn remove(&mut self, key: K) -> Option<IVec> {
+		Tree::remove(self, key).expect(SLED_ERROR)
+	}
+}
+
+impl MapIter for Tree {
+	type Iter<'a> = SledIter;
+
+	#[inline(always)]
+	fn iter(&self) -> SledIter {
+		SledIter(self.iter())
+	}
+}
+
+impl<K: AsRef<[u8]>> BoundQuery<K> for Tree {
+	#[inline(always)]
+	fn lower_bound(&self, key: K) -> Option<(SledRef, SledRef)> {
+		SledIter(self.range::<K, _>((Bound::Included(key), Bound::Unbounded))).next()
+	}
+
+	#[inline(always)]
+	fn upper_bound(&self, key: K) -> Option<(SledRef, SledRef)> {
+		self.get_gt(key)
+			.expect(SLED_ERROR)
+			.map(|(k, v)| (SledRef(k), SledRef(v)))
+	}
+}
@@ -1,8 +1,11 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Iter, IterMut, Len,
-	PopBack, PushBack, Remove, Reserve, WithCapacity,
+	AsMutSlice, AsSlice, BinarySearch, Capacity, Clear, Collection, CollectionMut, CollectionRef,
+	Contains, Dedup, DrainRange, ExtendFromSlice, Fill, Get, GetManyMut, GetMut, InsertAt, Iter,
+	IterMut, Len, PopBack, PushBack, Remove, Reserve, Resize, Reverse, Rotate, Sort, SortUnstable,
+	SplitAtMut, Swap, SwapRemove, Truncate, WithCapacity,
 };
 use smallvec::{Array, SmallVec};
+use std::{cmp::Ordering, ops::RangeBounds};
 
 impl<A: Array> Collection for SmallVec<A> {
 	type Item = A::Item;
@@ -10,18 +13,18 @@ impl<A: Array> Collection for SmallVec<A> {
 
 impl<A: Array> CollectionRef for SmallVec<A> {
 	type ItemRef<'a>
+		= &'a A::Item
 	where
-		Self: 'a,
-	= &'a A::Item;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
 impl<A: Array> CollectionMut for SmallVec<A> {
 	type ItemMut<'a>
+		= &'a mut A::Item
 	where
-		Self: 'a,
-	= &'a mut A::Item;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
@@ -45,6 +48,19 @@ impl<A: Array> Len for SmallVec<A> {
 	}
 }
 
+impl<A: Array> GetManyMut<usize> for SmallVec<A> {
+	#[inline(always)]
+	fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut A::Item; N]> {
+		match self.as_mut_slice().get_disjoint_mut(indices) {
+			Ok(items) => Some(items),
+			Err(std::slice::GetDisjointMutError::IndexOutOfBounds) => None,
+			Err(std::slice::GetDisjointMutError::OverlappingIndices) => {
+				panic!("overlapping indices")
+			}
+		}
+	}
+}
+
 impl<A: Array> Capacity for SmallVec<A> {
 	#[inline(always)]
 	fn capacity(&self) -> usize {
@@ -66,6 +82,16 @@ impl<A: Array> Get<usize> for SmallVec<A> {
 	}
 }
 
+impl<'a, A: Array> Contains<&'a A::Item> for SmallVec<A>
+where
+	A::Item: PartialEq,
+{
+	#[inline(always)]
+	fn contains_value(&self, value: &'a A::Item) -> bool {
+		self.as_slice().contains(value)
+	}
+}
+
 impl<A: Array> GetMut<usize> for SmallVec<A> {
 	#[inline(always)]
 	fn get_mut(&mut self, index: usize) -> Option<&mut A::Item> {
@@ -89,6 +115,18 @@ impl<A: Array> PopBack for SmallVec<A> {
 	}
 }
 
+impl<A: Array> InsertAt for SmallVec<A> {
+	#[inline(always)]
+	fn insert_at(&mut self, index: usize, element: A::Item) -> Result<(), A::Item> {
+		if index <= self.len() {
+			self.insert(index, element);
+			Ok(())
+		} else {
+			Err(element)
+		}
+	}
+}
+
 impl<A: Array> Remove<usize> for SmallVec<A> {
 	#[inline(always)]
 	fn remove(&mut self, index: usize) -> Option<A::Item> {
@@ -100,6 +138,42 @@ impl<A: Array> Remove<usize> for SmallVec<A> {
 	}
 }
 
+impl<A: Array> SwapRemove<usize> for SmallVec<A> {
+	#[inline(always)]
+	fn swap_remove(&mut self, index: usize) -> Option<A::Item> {
+		if index < self.len() {
+			Some(self.swap_remove(index))
+		} else {
+			None
+		}
+	}
+}
+
+impl<A: Array> Truncate for SmallVec<A> {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl<A: Array> Resize for SmallVec<A> {
+	#[inline(always)]
+	fn resize(&mut self, new_len: usize, value: A::Item)
+	where
+		A::Item: Clone,
+	{
+		self.resize(new_len, value)
+	}
+
+	#[inline(always)]
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> A::Item,
+	{
+		self.resize_with(new_len, f)
+	}
+}
+
 impl<A: Array> Clear for SmallVec<A> {
 	#[inline(always)]
 	fn clear(&mut self) {
@@ -109,9 +183,9 @@ impl<A: Array> Clear for SmallVec<A> {
 
 impl<A: Array> Iter for SmallVec<A> {
 	type Iter<'a>
+		= std::slice::Iter<'a, A::Item>
 	where
-		A: 'a,
-	= std::slice::Iter<'a, A::Item>;
+		A: 'a;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
@@ -121,12 +195,179 @@ impl<A: Array> Iter for SmallVec<A> {
 
 impl<A: Array> IterMut for SmallVec<A> {
 	type IterMut<'a>
+		= std::slice::IterMut<'a, A::Item>
 	where
-		A: 'a,
-	= std::slice::IterMut<'a, A::Item>;
+		A: 'a;
 
 	#[inline(always)]
 	fn iter_mut(&mut self) -> Self::IterMut<'_> {
 		self.as_mut_slice().iter_mut()
 	}
 }
+
+impl<A: Array> BinarySearch for SmallVec<A> {
+	#[inline(always)]
+	fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(&A::Item) -> Ordering,
+	{
+		self.as_slice().binary_search_by(f)
+	}
+
+	#[inline(always)]
+	fn partition_point<P>(&self, pred: P) -> usize
+	where
+		P: FnMut(&A::Item) -> bool,
+	{
+		self.as_slice().partition_point(pred)
+	}
+}
+
+impl<A: Array> Sort for SmallVec<A> {
+	#[inline(always)]
+	fn sort_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&A::Item, &A::Item) -> Ordering,
+	{
+		self.as_mut_slice().sort_by(f)
+	}
+
+	#[inline(always)]
+	fn sort_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&A::Item) -> K,
+	{
+		self.as_mut_slice().sort_by_key(f)
+	}
+}
+
+impl<A: Array> SortUnstable for SmallVec<A> {
+	#[inline(always)]
+	fn sort_unstable_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&A::Item, &A::Item) -> Ordering,
+	{
+		self.as_mut_slice().sort_unstable_by(f)
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&A::Item) -> K,
+	{
+		self.as_mut_slice().sort_unstable_by_key(f)
+	}
+}
+
+impl<A: Array> Dedup for SmallVec<A> {
+	#[inline(always)]
+	fn dedup_by<F>(&mut self, same_bucket: F)
+	where
+		F: FnMut(&mut A::Item, &mut A::Item) -> bool,
+	{
+		self.dedup_by(same_bucket)
+	}
+
+	#[inline(always)]
+	fn dedup_by_key<K, F>(&mut self, key: F)
+	where
+		K: PartialEq,
+		F: FnMut(&mut A::Item) -> K,
+	{
+		self.dedup_by_key(key)
+	}
+}
+
+impl<A: Array> Reverse for SmallVec<A> {
+	#[inline(always)]
+	fn reverse(&mut self) {
+		self.as_mut_slice().reverse()
+	}
+}
+
+impl<A: Array> Rotate for SmallVec<A> {
+	#[inline(always)]
+	fn rotate_left(&mut self, mid: usize) {
+		self.as_mut_slice().rotate_left(mid)
+	}
+
+	#[inline(always)]
+	fn rotate_right(&mut self, k: usize) {
+		self.as_mut_slice().rotate_right(k)
+	}
+}
+
+impl<A: Array> AsSlice for SmallVec<A> {
+	#[inline(always)]
+	fn as_slice(&self) -> &[A::Item] {
+		self.as_slice()
+	}
+}
+
+impl<A: Array> AsMutSlice for SmallVec<A> {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [A::Item] {
+		self.as_mut_slice()
+	}
+}
+
+impl<A: Array> SplitAtMut for SmallVec<A> {
+	type SplitMut<'a>
+		= &'a mut [A::Item]
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn split_at_mut(&mut self, mid: usize) -> (&mut [A::Item], &mut [A::Item]) {
+		self.as_mut_slice().split_at_mut(mid)
+	}
+}
+
+impl<A: Array> Swap for SmallVec<A> {
+	#[inline(always)]
+	fn swap(&mut self, a: usize, b: usize) {
+		self.as_mut_slice().swap(a, b)
+	}
+}
+
+impl<A: Array> Fill for SmallVec<A> {
+	#[inline(always)]
+	fn fill(&mut self, value: A::Item)
+	where
+		A::Item: Clone,
+	{
+		self.as_mut_slice().fill(value)
+	}
+
+	#[inline(always)]
+	fn fill_with<F>(&mut self, f: F)
+	where
+		F: FnMut() -> A::Item,
+	{
+		self.as_mut_slice().fill_with(f)
+	}
+}
+
+impl<A: Array, R: RangeBounds<usize>> DrainRange<R> for SmallVec<A> {
+	type Drain<'a>
+		= smallvec::Drain<'a, A>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn drain_range(&mut self, range: R) -> Self::Drain<'_> {
+		self.drain(range)
+	}
+}
+
+impl<A: Array> ExtendFromSlice for SmallVec<A> {
+	#[inline(always)]
+	fn extend_from_slice(&mut self, other: &[A::Item])
+	where
+		A::Item: Clone,
+	{
+		self.extend(other.iter().cloned())
+	}
+}
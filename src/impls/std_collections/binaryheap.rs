@@ -0,0 +1,155 @@
+//! A `BinaryHeap` is a priority queue, popping its greatest item first.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Len, Peek, PriorityPop, PriorityPush};
+//! use std::collections::BinaryHeap;
+//!
+//! let mut heap = BinaryHeap::new();
+//! PriorityPush::push(&mut heap, 2);
+//! PriorityPush::push(&mut heap, 3);
+//! PriorityPush::push(&mut heap, 1);
+//! assert_eq!(Peek::peek(&heap).copied(), Some(3));
+//! assert_eq!(PriorityPop::pop(&mut heap), Some(3));
+//! assert_eq!(Len::len(&heap), 2);
+//! ```
+use crate::{
+	Append, Capacity, Clear, Collection, CollectionRef, Iter, Len, Len64, LenHint, MemSize, Peek,
+	PriorityPop, PriorityPush, PushPop, Recycle, Reserve, WithCapacity,
+};
+use std::collections::BinaryHeap;
+
+impl<T> Collection for BinaryHeap<T> {
+	type Item = T;
+}
+
+impl<T> CollectionRef for BinaryHeap<T> {
+	type ItemRef<'a>
+		= &'a T
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T: Ord> WithCapacity for BinaryHeap<T> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		BinaryHeap::with_capacity(capacity)
+	}
+}
+
+impl<T> Len for BinaryHeap<T> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T> Len64 for BinaryHeap<T> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<T> LenHint for BinaryHeap<T> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<T> Capacity for BinaryHeap<T> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T> Reserve for BinaryHeap<T> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl<T> Clear for BinaryHeap<T> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T> Iter for BinaryHeap<T> {
+	/// Iterator over the elements of the heap, in arbitrary order.
+	type Iter<'a>
+		= std::collections::binary_heap::Iter<'a, T>
+	where
+		T: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T: Ord> Peek for BinaryHeap<T> {
+	#[inline(always)]
+	fn peek(&self) -> Option<&T> {
+		self.peek()
+	}
+}
+
+impl<T: Ord> PriorityPush for BinaryHeap<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push(&mut self, t: T) {
+		self.push(t)
+	}
+}
+
+impl<T: Ord> Append for BinaryHeap<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<T: Ord> PriorityPop for BinaryHeap<T> {
+	#[inline(always)]
+	fn pop(&mut self) -> Option<T> {
+		self.pop()
+	}
+}
+
+impl<T: Ord> PushPop for BinaryHeap<T> {
+	#[inline(always)]
+	fn push_pop(&mut self, t: T) -> T {
+		match self.peek_mut() {
+			Some(mut top) if *top > t => std::mem::replace(&mut *top, t),
+			_ => t,
+		}
+	}
+}
+
+impl<T> MemSize for BinaryHeap<T> {
+	#[inline(always)]
+	fn mem_size(&self) -> usize {
+		self.capacity() * std::mem::size_of::<T>()
+	}
+}
+
+impl<T> Recycle for BinaryHeap<T> {
+	#[inline(always)]
+	fn recycle(&mut self) {
+		self.clear()
+	}
+}
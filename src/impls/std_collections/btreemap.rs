@@ -1,8 +1,14 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, Keyed,
-	KeyedRef, Len, MapInsert, MapIter, MapIterMut, Remove,
+	Append, Back, BackMut, BoundQuery, Clear, Collection, CollectionMut, CollectionRef, Front,
+	FrontMut, Get, GetIndex, GetIndexMut, GetIndexOf, GetKeyValue, GetMut, Iter, Keyed, KeyedRef,
+	Len, Len64, LenHint, MapDrainRange, MapInsert, MapIter, MapIterMut, MemSize, MergeFrom,
+	OccupiedError, Remove, RemoveEntry, TryInsert, Update,
+};
+use std::{
+	borrow::Borrow,
+	collections::BTreeMap,
+	ops::{Bound, RangeBounds},
 };
-use std::{borrow::Borrow, collections::BTreeMap};
 
 impl<K, V> Collection for BTreeMap<K, V> {
 	type Item = V;
@@ -10,18 +16,18 @@ impl<K, V> Collection for BTreeMap<K, V> {
 
 impl<K, V> CollectionRef for BTreeMap<K, V> {
 	type ItemRef<'a>
+		= &'a V
 	where
-		Self: 'a,
-	= &'a V;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
 impl<K, V> CollectionMut for BTreeMap<K, V> {
 	type ItemMut<'a>
+		= &'a mut V
 	where
-		Self: 'a,
-	= &'a mut V;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
@@ -32,13 +38,22 @@ impl<K, V> Keyed for BTreeMap<K, V> {
 
 impl<K, V> KeyedRef for BTreeMap<K, V> {
 	type KeyRef<'a>
+		= &'a K
 	where
-		Self: 'a,
-	= &'a K;
+		Self: 'a;
 
 	crate::covariant_key_ref!();
 }
 
+impl<K, V> MemSize for BTreeMap<K, V> {
+	/// Counts each entry plus roughly one pointer per entry for the nodes structure.
+	#[inline(always)]
+	fn mem_size(&self) -> usize {
+		self.len()
+			* (std::mem::size_of::<K>() + std::mem::size_of::<V>() + std::mem::size_of::<usize>())
+	}
+}
+
 impl<K, V> Len for BTreeMap<K, V> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -51,6 +66,20 @@ impl<K, V> Len for BTreeMap<K, V> {
 	}
 }
 
+impl<K, V> Len64 for BTreeMap<K, V> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<K, V> LenHint for BTreeMap<K, V> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
 impl<'a, Q, K: Ord, V> Get<&'a Q> for BTreeMap<K, V>
 where
 	K: Borrow<Q>,
@@ -73,6 +102,38 @@ where
 	}
 }
 
+impl<K, V> GetIndex for BTreeMap<K, V> {
+	#[inline(always)]
+	fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+		self.iter().nth(index)
+	}
+}
+
+impl<K, V> GetIndexMut for BTreeMap<K, V> {
+	#[inline(always)]
+	fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+		self.iter_mut().nth(index)
+	}
+}
+
+impl<'a, Q, K: Ord, V> GetIndexOf<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn get_index_of(&self, key: &'a Q) -> Option<usize> {
+		if self.contains_key(key) {
+			Some(
+				self.range::<Q, _>((Bound::Unbounded, Bound::Excluded(key)))
+					.count(),
+			)
+		} else {
+			None
+		}
+	}
+}
+
 impl<'a, Q, K: Ord, V> GetMut<&'a Q> for BTreeMap<K, V>
 where
 	K: Borrow<Q>,
@@ -84,6 +145,52 @@ where
 	}
 }
 
+impl<'a, Q, K: Ord, V> BoundQuery<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn lower_bound(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.range::<Q, _>((Bound::Included(key), Bound::Unbounded))
+			.next()
+	}
+
+	#[inline(always)]
+	fn upper_bound(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.range::<Q, _>((Bound::Excluded(key), Bound::Unbounded))
+			.next()
+	}
+}
+
+impl<K: Ord, V> Front for BTreeMap<K, V> {
+	#[inline(always)]
+	fn front(&self) -> Option<&V> {
+		self.first_key_value().map(|(_, v)| v)
+	}
+}
+
+impl<K: Ord, V> Back for BTreeMap<K, V> {
+	#[inline(always)]
+	fn back(&self) -> Option<&V> {
+		self.last_key_value().map(|(_, v)| v)
+	}
+}
+
+impl<K: Ord, V> FrontMut for BTreeMap<K, V> {
+	#[inline(always)]
+	fn front_mut(&mut self) -> Option<&mut V> {
+		self.values_mut().next()
+	}
+}
+
+impl<K: Ord, V> BackMut for BTreeMap<K, V> {
+	#[inline(always)]
+	fn back_mut(&mut self) -> Option<&mut V> {
+		self.values_mut().next_back()
+	}
+}
+
 impl<K: Ord, V> MapInsert<K> for BTreeMap<K, V> {
 	type Output = Option<V>;
 
@@ -93,6 +200,68 @@ impl<K: Ord, V> MapInsert<K> for BTreeMap<K, V> {
 	}
 }
 
+impl<K: Ord, V> MergeFrom for BTreeMap<K, V> {
+	#[inline(always)]
+	fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+	where
+		O: IntoIterator<Item = (K, V)>,
+		F: FnMut(&mut V, V),
+	{
+		for (key, value) in other {
+			match self.entry(key) {
+				std::collections::btree_map::Entry::Occupied(entry) => {
+					resolve(entry.into_mut(), value)
+				}
+				std::collections::btree_map::Entry::Vacant(entry) => {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
+impl<K: Ord, V> Append for BTreeMap<K, V> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<K: Ord, V> TryInsert<K> for BTreeMap<K, V> {
+	#[inline(always)]
+	fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, Self>> {
+		match self.entry(key) {
+			std::collections::btree_map::Entry::Occupied(entry) => Err(OccupiedError {
+				existing: entry.into_mut(),
+				value,
+			}),
+			std::collections::btree_map::Entry::Vacant(entry) => Ok(entry.insert(value)),
+		}
+	}
+}
+
+impl<K: Ord, V> Update for BTreeMap<K, V> {
+	#[inline(always)]
+	fn update<F>(&mut self, key: K, f: F)
+	where
+		F: FnOnce(Option<V>) -> Option<V>,
+	{
+		match self.entry(key) {
+			std::collections::btree_map::Entry::Occupied(entry) => {
+				let (key, value) = entry.remove_entry();
+				if let Some(value) = f(Some(value)) {
+					self.insert(key, value);
+				}
+			}
+			std::collections::btree_map::Entry::Vacant(entry) => {
+				if let Some(value) = f(None) {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
 impl<'a, Q, K: Ord, V> Remove<&'a Q> for BTreeMap<K, V>
 where
 	K: Borrow<Q>,
@@ -104,6 +273,17 @@ where
 	}
 }
 
+impl<'a, Q, K: Ord, V> RemoveEntry<&'a Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+		self.remove_entry(key)
+	}
+}
+
 impl<K: Ord, V> Clear for BTreeMap<K, V> {
 	#[inline(always)]
 	fn clear(&mut self) {
@@ -113,9 +293,9 @@ impl<K: Ord, V> Clear for BTreeMap<K, V> {
 
 impl<K, V> Iter for BTreeMap<K, V> {
 	type Iter<'a>
+		= std::collections::btree_map::Values<'a, K, V>
 	where
-		Self: 'a,
-	= std::collections::btree_map::Values<'a, K, V>;
+		Self: 'a;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
@@ -125,9 +305,9 @@ impl<K, V> Iter for BTreeMap<K, V> {
 
 impl<K, V> MapIter for BTreeMap<K, V> {
 	type Iter<'a>
+		= std::collections::btree_map::Iter<'a, K, V>
 	where
-		Self: 'a,
-	= std::collections::btree_map::Iter<'a, K, V>;
+		Self: 'a;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
@@ -137,12 +317,119 @@ impl<K, V> MapIter for BTreeMap<K, V> {
 
 impl<K, V> MapIterMut for BTreeMap<K, V> {
 	type IterMut<'a>
+		= std::collections::btree_map::IterMut<'a, K, V>
 	where
-		Self: 'a,
-	= std::collections::btree_map::IterMut<'a, K, V>;
+		Self: 'a;
 
 	#[inline(always)]
 	fn iter_mut(&mut self) -> Self::IterMut<'_> {
 		self.iter_mut()
 	}
 }
+
+#[cfg(feature = "nightly")]
+impl<'a, K: Ord, V> crate::MapCursorMut<'a, BTreeMap<K, V>>
+	for std::collections::btree_map::CursorMut<'a, K, V>
+{
+	#[inline(always)]
+	fn next(&mut self) -> Option<(&K, &mut V)> {
+		self.next()
+	}
+
+	#[inline(always)]
+	fn prev(&mut self) -> Option<(&K, &mut V)> {
+		self.prev()
+	}
+
+	#[inline(always)]
+	fn peek_next(&mut self) -> Option<(&K, &mut V)> {
+		self.peek_next()
+	}
+
+	#[inline(always)]
+	fn peek_prev(&mut self) -> Option<(&K, &mut V)> {
+		self.peek_prev()
+	}
+
+	#[inline(always)]
+	fn insert_after(&mut self, key: K, value: V) -> Result<(), crate::UnorderedKeyError> {
+		self.insert_after(key, value)
+			.map_err(|_| crate::UnorderedKeyError)
+	}
+
+	#[inline(always)]
+	fn insert_before(&mut self, key: K, value: V) -> Result<(), crate::UnorderedKeyError> {
+		self.insert_before(key, value)
+			.map_err(|_| crate::UnorderedKeyError)
+	}
+
+	#[inline(always)]
+	fn remove_next(&mut self) -> Option<(K, V)> {
+		self.remove_next()
+	}
+
+	#[inline(always)]
+	fn remove_prev(&mut self) -> Option<(K, V)> {
+		self.remove_prev()
+	}
+}
+
+#[cfg(feature = "nightly")]
+impl<'q, Q, K: Ord, V> crate::CursorApi<&'q Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	type CursorMut<'a>
+		= std::collections::btree_map::CursorMut<'a, K, V>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn lower_bound_mut(&mut self, bound: Bound<&'q Q>) -> Self::CursorMut<'_> {
+		self.lower_bound_mut(bound)
+	}
+
+	#[inline(always)]
+	fn upper_bound_mut(&mut self, bound: Bound<&'q Q>) -> Self::CursorMut<'_> {
+		self.upper_bound_mut(bound)
+	}
+}
+
+impl<K: Ord, V, R: RangeBounds<K>> MapDrainRange<R> for BTreeMap<K, V> {
+	type Drain<'a>
+		= std::collections::btree_map::IntoIter<K, V>
+	where
+		Self: 'a;
+
+	fn drain_range(&mut self, range: R) -> Self::Drain<'_> {
+		let mut drained = match range.start_bound() {
+			Bound::Included(start) => self.split_off(start),
+			Bound::Excluded(start) => {
+				let mut drained = self.split_off(start);
+				if let Some((key, value)) = drained.remove_entry(start) {
+					self.insert(key, value);
+				}
+
+				drained
+			}
+			Bound::Unbounded => std::mem::take(self),
+		};
+
+		let mut tail = match range.end_bound() {
+			Bound::Included(end) => {
+				let mut tail = drained.split_off(end);
+				if let Some((key, value)) = tail.remove_entry(end) {
+					drained.insert(key, value);
+				}
+
+				tail
+			}
+			Bound::Excluded(end) => drained.split_off(end),
+			Bound::Unbounded => BTreeMap::new(),
+		};
+
+		self.append(&mut tail);
+		drained.into_iter()
+	}
+}
@@ -1,5 +1,9 @@
-use crate::{Clear, Collection, CollectionMut, CollectionRef, Get, Insert, Iter, Len, Remove};
-use std::{borrow::Borrow, collections::BTreeSet};
+use crate::{
+	Append, Back, Clear, Collection, CollectionMut, CollectionRef, Contains, Front, Get, Insert,
+	Iter, Len, Len64, LenHint, MemSize, RangeIter, Remove, Replace, SetCompare, SetOps, SetPop,
+	Take,
+};
+use std::{borrow::Borrow, collections::BTreeSet, ops::RangeBounds};
 
 impl<T> Collection for BTreeSet<T> {
 	type Item = T;
@@ -7,22 +11,30 @@ impl<T> Collection for BTreeSet<T> {
 
 impl<T> CollectionRef for BTreeSet<T> {
 	type ItemRef<'a>
+		= &'a T
 	where
-		Self: 'a,
-	= &'a T;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
 impl<T> CollectionMut for BTreeSet<T> {
 	type ItemMut<'a>
+		= &'a mut T
 	where
-		Self: 'a,
-	= &'a mut T;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
 
+impl<T> MemSize for BTreeSet<T> {
+	/// Counts each element plus roughly one pointer per element for the nodes structure.
+	#[inline(always)]
+	fn mem_size(&self) -> usize {
+		self.len() * (std::mem::size_of::<T>() + std::mem::size_of::<usize>())
+	}
+}
+
 impl<T> Len for BTreeSet<T> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -35,6 +47,20 @@ impl<T> Len for BTreeSet<T> {
 	}
 }
 
+impl<T> Len64 for BTreeSet<T> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<T> LenHint for BTreeSet<T> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
 impl<'a, Q, T: Ord> Get<&'a Q> for BTreeSet<T>
 where
 	T: Borrow<Q>,
@@ -46,6 +72,31 @@ where
 	}
 }
 
+impl<'a, Q, T: Ord> Contains<&'a Q> for BTreeSet<T>
+where
+	T: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn contains_value(&self, value: &'a Q) -> bool {
+		self.contains(value)
+	}
+}
+
+impl<T: Ord> Front for BTreeSet<T> {
+	#[inline(always)]
+	fn front(&self) -> Option<&T> {
+		self.first()
+	}
+}
+
+impl<T: Ord> Back for BTreeSet<T> {
+	#[inline(always)]
+	fn back(&self) -> Option<&T> {
+		self.last()
+	}
+}
+
 impl<T: Ord> Insert for BTreeSet<T> {
 	type Output = bool;
 
@@ -55,6 +106,54 @@ impl<T: Ord> Insert for BTreeSet<T> {
 	}
 }
 
+impl<T: Ord> Append for BTreeSet<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Ord> crate::SetEntryApi for BTreeSet<T> {
+	#[inline(always)]
+	fn get_or_insert(&mut self, t: T) -> &T {
+		self.get_or_insert(t)
+	}
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, Q, T: Ord> crate::SetEntryApiWith<&'a Q> for BTreeSet<T>
+where
+	T: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn get_or_insert_with<F>(&mut self, value: &'a Q, f: F) -> &T
+	where
+		F: FnOnce(&'a Q) -> T,
+	{
+		self.get_or_insert_with(value, move |_| f(value))
+	}
+}
+
+impl<T: Ord> Replace for BTreeSet<T> {
+	#[inline(always)]
+	fn replace(&mut self, t: T) -> Option<T> {
+		self.replace(t)
+	}
+}
+
+impl<'a, Q, T: Ord> Take<&'a Q> for BTreeSet<T>
+where
+	T: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn take(&mut self, t: &'a Q) -> Option<T> {
+		self.take(t)
+	}
+}
+
 impl<'a, Q, T: Ord> Remove<&'a Q> for BTreeSet<T>
 where
 	T: Borrow<Q>,
@@ -66,6 +165,13 @@ where
 	}
 }
 
+impl<T: Ord> SetPop for BTreeSet<T> {
+	#[inline(always)]
+	fn pop(&mut self) -> Option<T> {
+		self.pop_first()
+	}
+}
+
 impl<T: Ord> Clear for BTreeSet<T> {
 	#[inline(always)]
 	fn clear(&mut self) {
@@ -75,12 +181,87 @@ impl<T: Ord> Clear for BTreeSet<T> {
 
 impl<T> Iter for BTreeSet<T> {
 	type Iter<'a>
+		= std::collections::btree_set::Iter<'a, T>
 	where
-		Self: 'a,
-	= std::collections::btree_set::Iter<'a, T>;
+		Self: 'a;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
 		self.iter()
 	}
 }
+
+impl<Q, T: Ord> RangeIter<Q> for BTreeSet<T>
+where
+	T: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	type RangeIter<'a>
+		= std::collections::btree_set::Range<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn range<R: RangeBounds<Q>>(&self, range: R) -> Self::RangeIter<'_> {
+		self.range(range)
+	}
+}
+
+impl<T: Ord> SetOps for BTreeSet<T> {
+	type Union<'a>
+		= std::collections::btree_set::Union<'a, T>
+	where
+		Self: 'a;
+
+	type Intersection<'a>
+		= std::collections::btree_set::Intersection<'a, T>
+	where
+		Self: 'a;
+
+	type Difference<'a>
+		= std::collections::btree_set::Difference<'a, T>
+	where
+		Self: 'a;
+
+	type SymmetricDifference<'a>
+		= std::collections::btree_set::SymmetricDifference<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn union<'a>(&'a self, other: &'a Self) -> Self::Union<'a> {
+		self.union(other)
+	}
+
+	#[inline(always)]
+	fn intersection<'a>(&'a self, other: &'a Self) -> Self::Intersection<'a> {
+		self.intersection(other)
+	}
+
+	#[inline(always)]
+	fn difference<'a>(&'a self, other: &'a Self) -> Self::Difference<'a> {
+		self.difference(other)
+	}
+
+	#[inline(always)]
+	fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Self::SymmetricDifference<'a> {
+		self.symmetric_difference(other)
+	}
+}
+
+impl<T: Ord> SetCompare for BTreeSet<T> {
+	#[inline(always)]
+	fn is_subset(&self, other: &Self) -> bool {
+		self.is_subset(other)
+	}
+
+	#[inline(always)]
+	fn is_superset(&self, other: &Self) -> bool {
+		self.is_superset(other)
+	}
+
+	#[inline(always)]
+	fn is_disjoint(&self, other: &Self) -> bool {
+		self.is_disjoint(other)
+	}
+}
@@ -0,0 +1,199 @@
+//! A `RefCell<C>` is queried by dynamically borrowing the inner collection.
+//!
+//! References returned by [`Get::get`] hold the borrow of the cell
+//! until they are dropped, so they are [`CellRef`] borrow guards.
+//! Mutable access goes through [`RefCell::get_mut`] and never borrows the cell dynamically.
+use crate::{
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Insert, IterMut, Keyed, Len,
+	MapInsert, PopBack, PopFront, PushBack, PushFront, Remove, Update,
+};
+use std::{
+	cell::{Ref, RefCell},
+	ops::Deref,
+};
+
+/// Reference to an item of a collection stored in a `RefCell`,
+/// holding the borrow of the cell.
+///
+/// ```
+/// use cc_traits::{Get, Len};
+/// use std::cell::RefCell;
+///
+/// let cell = RefCell::new(vec![1, 2, 3]);
+/// let second = cell.get(1).unwrap();
+/// assert_eq!(*second, 2);
+/// assert!(cell.try_borrow_mut().is_err());
+///
+/// drop(second);
+/// cell.borrow_mut().push(4);
+/// assert_eq!(cell.len(), 4);
+/// ```
+pub struct CellRef<'a, T: ?Sized>(pub Ref<'a, T>);
+
+impl<'a, T: ?Sized> Clone for CellRef<'a, T> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		CellRef(Ref::clone(&self.0))
+	}
+}
+
+impl<'a, T: ?Sized> Deref for CellRef<'a, T> {
+	type Target = T;
+
+	#[inline(always)]
+	fn deref(&self) -> &T {
+		&self.0
+	}
+}
+
+impl<C: Collection> Collection for RefCell<C> {
+	type Item = C::Item;
+}
+
+impl<C: Collection> CollectionRef for RefCell<C> {
+	type ItemRef<'a>
+		= CellRef<'a, C::Item>
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<C: CollectionMut> CollectionMut for RefCell<C> {
+	type ItemMut<'a>
+		= C::ItemMut<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_mut(r)
+	}
+}
+
+impl<C: Keyed> Keyed for RefCell<C> {
+	type Key = C::Key;
+}
+
+impl<C: Len> Len for RefCell<C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.borrow().len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.borrow().is_empty()
+	}
+}
+
+impl<T, C> Get<T> for RefCell<C>
+where
+	C: 'static + Get<T> + for<'a> CollectionRef<ItemRef<'a> = &'a <C as Collection>::Item>,
+{
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<CellRef<'_, C::Item>> {
+		Ref::filter_map(self.borrow(), |c| c.get(key))
+			.ok()
+			.map(CellRef)
+	}
+}
+
+impl<T, C> GetMut<T> for RefCell<C>
+where
+	C: 'static + GetMut<T> + for<'a> CollectionRef<ItemRef<'a> = &'a <C as Collection>::Item>,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: T) -> Option<C::ItemMut<'_>> {
+		self.get_mut().get_mut(key)
+	}
+}
+
+impl<C: Insert> Insert for RefCell<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, element: C::Item) -> C::Output {
+		self.get_mut().insert(element)
+	}
+}
+
+impl<K, C: MapInsert<K>> MapInsert<K> for RefCell<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: C::Item) -> C::Output {
+		self.get_mut().insert(key, value)
+	}
+}
+
+impl<T, C: Remove<T>> Remove<T> for RefCell<C> {
+	#[inline(always)]
+	fn remove(&mut self, key: T) -> Option<C::Item> {
+		self.get_mut().remove(key)
+	}
+}
+
+impl<C: Update> Update for RefCell<C> {
+	#[inline(always)]
+	fn update<F>(&mut self, key: C::Key, f: F)
+	where
+		F: FnOnce(Option<C::Item>) -> Option<C::Item>,
+	{
+		self.get_mut().update(key, f)
+	}
+}
+
+impl<C: PushFront> PushFront for RefCell<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn push_front(&mut self, element: C::Item) -> C::Output {
+		self.get_mut().push_front(element)
+	}
+}
+
+impl<C: PushBack> PushBack for RefCell<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn push_back(&mut self, element: C::Item) -> C::Output {
+		self.get_mut().push_back(element)
+	}
+}
+
+impl<C: PopFront> PopFront for RefCell<C> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<C::Item> {
+		self.get_mut().pop_front()
+	}
+}
+
+impl<C: PopBack> PopBack for RefCell<C> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<C::Item> {
+		self.get_mut().pop_back()
+	}
+}
+
+impl<C: Clear> Clear for RefCell<C> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.get_mut().clear()
+	}
+}
+
+impl<C: IterMut> IterMut for RefCell<C> {
+	type IterMut<'a>
+		= C::IterMut<'a>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.get_mut().iter_mut()
+	}
+}
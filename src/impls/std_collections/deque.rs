@@ -1,8 +1,10 @@
 use crate::{
-	Back, BackMut, Capacity, Clear, Collection, CollectionMut, CollectionRef, Front, FrontMut, Len,
-	PopBack, PushBack, Reserve, WithCapacity,
+	Append, Back, BackMut, BinarySearch, Capacity, Clear, Collection, CollectionMut, CollectionRef,
+	Contains, DrainRange, Fill, Front, FrontMut, InsertAt, Len, Len64, LenHint, MakeContiguous,
+	MemSize, PopBack, PushBack, Recycle, Reserve, Resize, Reverse, Rotate, Sort, SortUnstable,
+	SplitAtMut, Swap, SwapRemove, Truncate, WithCapacity,
 };
-use std::collections::VecDeque;
+use std::{cmp::Ordering, collections::VecDeque, ops::RangeBounds};
 
 impl<T> Collection for VecDeque<T> {
 	type Item = T;
@@ -10,18 +12,18 @@ impl<T> Collection for VecDeque<T> {
 
 impl<T> CollectionRef for VecDeque<T> {
 	type ItemRef<'a>
+		= &'a T
 	where
-		Self: 'a,
-	= &'a T;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
 impl<T> CollectionMut for VecDeque<T> {
 	type ItemMut<'a>
+		= &'a mut T
 	where
-		Self: 'a,
-	= &'a mut T;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
@@ -33,6 +35,13 @@ impl<T> WithCapacity for VecDeque<T> {
 	}
 }
 
+impl<T> MemSize for VecDeque<T> {
+	#[inline(always)]
+	fn mem_size(&self) -> usize {
+		self.capacity() * std::mem::size_of::<T>()
+	}
+}
+
 impl<T> Len for VecDeque<T> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -45,6 +54,30 @@ impl<T> Len for VecDeque<T> {
 	}
 }
 
+impl<T> Len64 for VecDeque<T> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<T> LenHint for VecDeque<T> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<'a, T> Contains<&'a T> for VecDeque<T>
+where
+	T: PartialEq,
+{
+	#[inline(always)]
+	fn contains_value(&self, value: &'a T) -> bool {
+		self.contains(value)
+	}
+}
+
 impl<T> Capacity for VecDeque<T> {
 	#[inline(always)]
 	fn capacity(&self) -> usize {
@@ -96,6 +129,13 @@ impl<T> PushBack for VecDeque<T> {
 	}
 }
 
+impl<T> Append for VecDeque<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
 impl<T> PopBack for VecDeque<T> {
 	#[inline(always)]
 	fn pop_back(&mut self) -> Option<T> {
@@ -103,9 +143,193 @@ impl<T> PopBack for VecDeque<T> {
 	}
 }
 
+impl<T> InsertAt for VecDeque<T> {
+	#[inline(always)]
+	fn insert_at(&mut self, index: usize, element: T) -> Result<(), T> {
+		if index <= self.len() {
+			self.insert(index, element);
+			Ok(())
+		} else {
+			Err(element)
+		}
+	}
+}
+
+impl<T> SwapRemove<usize> for VecDeque<T> {
+	#[inline(always)]
+	fn swap_remove(&mut self, index: usize) -> Option<T> {
+		self.swap_remove_back(index)
+	}
+}
+
+impl<T> Truncate for VecDeque<T> {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl<T> Resize for VecDeque<T> {
+	#[inline(always)]
+	fn resize(&mut self, new_len: usize, value: T)
+	where
+		T: Clone,
+	{
+		self.resize(new_len, value)
+	}
+
+	#[inline(always)]
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> T,
+	{
+		self.resize_with(new_len, f)
+	}
+}
+
 impl<T> Clear for VecDeque<T> {
 	#[inline(always)]
 	fn clear(&mut self) {
 		self.clear()
 	}
 }
+
+impl<T> Recycle for VecDeque<T> {
+	#[inline(always)]
+	fn recycle(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T> BinarySearch for VecDeque<T> {
+	#[inline(always)]
+	fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(&T) -> Ordering,
+	{
+		self.binary_search_by(f)
+	}
+
+	#[inline(always)]
+	fn partition_point<P>(&self, pred: P) -> usize
+	where
+		P: FnMut(&T) -> bool,
+	{
+		self.partition_point(pred)
+	}
+}
+
+impl<T> Sort for VecDeque<T> {
+	#[inline(always)]
+	fn sort_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&T, &T) -> Ordering,
+	{
+		self.make_contiguous().sort_by(f)
+	}
+
+	#[inline(always)]
+	fn sort_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.make_contiguous().sort_by_key(f)
+	}
+}
+
+impl<T> SortUnstable for VecDeque<T> {
+	#[inline(always)]
+	fn sort_unstable_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&T, &T) -> Ordering,
+	{
+		self.make_contiguous().sort_unstable_by(f)
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.make_contiguous().sort_unstable_by_key(f)
+	}
+}
+
+impl<T> Reverse for VecDeque<T> {
+	#[inline(always)]
+	fn reverse(&mut self) {
+		self.make_contiguous().reverse()
+	}
+}
+
+impl<T> Rotate for VecDeque<T> {
+	#[inline(always)]
+	fn rotate_left(&mut self, mid: usize) {
+		self.rotate_left(mid)
+	}
+
+	#[inline(always)]
+	fn rotate_right(&mut self, k: usize) {
+		self.rotate_right(k)
+	}
+}
+
+impl<T> MakeContiguous for VecDeque<T> {
+	#[inline(always)]
+	fn make_contiguous(&mut self) -> &mut [T] {
+		self.make_contiguous()
+	}
+}
+
+impl<T> SplitAtMut for VecDeque<T> {
+	type SplitMut<'a>
+		= std::iter::Chain<std::slice::IterMut<'a, T>, std::slice::IterMut<'a, T>>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn split_at_mut(&mut self, mid: usize) -> (Self::SplitMut<'_>, Self::SplitMut<'_>) {
+		assert!(mid <= self.len());
+		let (a, b) = self.as_mut_slices();
+		if mid <= a.len() {
+			let (a0, a1) = a.split_at_mut(mid);
+			let (b0, b1) = b.split_at_mut(0);
+			(a0.iter_mut().chain(b0), a1.iter_mut().chain(b1))
+		} else {
+			let (b0, b1) = b.split_at_mut(mid - a.len());
+			let (a0, a1) = a.split_at_mut(a.len());
+			(a0.iter_mut().chain(b0), a1.iter_mut().chain(b1))
+		}
+	}
+}
+
+impl<T> Swap for VecDeque<T> {
+	#[inline(always)]
+	fn swap(&mut self, a: usize, b: usize) {
+		self.swap(a, b)
+	}
+}
+
+impl<T> Fill for VecDeque<T> {
+	#[inline(always)]
+	fn fill_with<F>(&mut self, mut f: F)
+	where
+		F: FnMut() -> T,
+	{
+		self.iter_mut().for_each(|item| *item = f())
+	}
+}
+
+impl<T, R: RangeBounds<usize>> DrainRange<R> for VecDeque<T> {
+	type Drain<'a>
+		= std::collections::vec_deque::Drain<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn drain_range(&mut self, range: R) -> Self::Drain<'_> {
+		self.drain(range)
+	}
+}
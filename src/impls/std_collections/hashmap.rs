@@ -1,6 +1,7 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, Keyed,
-	KeyedRef, Len, MapInsert, MapIter, MapIterMut, Remove,
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetManyMut, GetMut, Iter,
+	Keyed, KeyedRef, Len, Len64, LenHint, MapInsert, MapIter, MapIterMut, MemSize, MergeFrom,
+	OccupiedError, Recycle, Remove, RemoveEntry, Reserve, TryInsert, Update,
 };
 use std::{borrow::Borrow, collections::HashMap, hash::Hash};
 
@@ -10,18 +11,18 @@ impl<K, V> Collection for HashMap<K, V> {
 
 impl<K, V> CollectionRef for HashMap<K, V> {
 	type ItemRef<'a>
+		= &'a V
 	where
-		Self: 'a,
-	= &'a V;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
 impl<K, V> CollectionMut for HashMap<K, V> {
 	type ItemMut<'a>
+		= &'a mut V
 	where
-		Self: 'a,
-	= &'a mut V;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
@@ -32,13 +33,21 @@ impl<K, V> Keyed for HashMap<K, V> {
 
 impl<K, V> KeyedRef for HashMap<K, V> {
 	type KeyRef<'a>
+		= &'a K
 	where
-		Self: 'a,
-	= &'a K;
+		Self: 'a;
 
 	crate::covariant_key_ref!();
 }
 
+impl<K, V> MemSize for HashMap<K, V> {
+	/// Each bucket stores a key-value pair and one control byte.
+	#[inline(always)]
+	fn mem_size(&self) -> usize {
+		self.capacity() * (std::mem::size_of::<(K, V)>() + 1)
+	}
+}
+
 impl<K, V> Len for HashMap<K, V> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -51,6 +60,27 @@ impl<K, V> Len for HashMap<K, V> {
 	}
 }
 
+impl<K, V> Len64 for HashMap<K, V> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<K, V> LenHint for HashMap<K, V> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<K: Hash + Eq, V> Reserve for HashMap<K, V> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
 impl<'a, Q, K: Hash + Eq, V> Get<&'a Q> for HashMap<K, V>
 where
 	K: Borrow<Q>,
@@ -73,6 +103,22 @@ where
 	}
 }
 
+impl<'a, Q, K: Hash + Eq, V> GetManyMut<&'a Q> for HashMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn get_many_mut<const N: usize>(&mut self, keys: [&'a Q; N]) -> Option<[&mut V; N]> {
+		let values = self.get_disjoint_mut(keys);
+		if values.iter().all(Option::is_some) {
+			Some(values.map(Option::unwrap))
+		} else {
+			None
+		}
+	}
+}
+
 impl<'a, Q, K: Hash + Eq, V> GetKeyValue<&'a Q> for HashMap<K, V>
 where
 	K: Borrow<Q>,
@@ -93,6 +139,63 @@ impl<K: Hash + Eq, V> MapInsert<K> for HashMap<K, V> {
 	}
 }
 
+impl<K: Hash + Eq, V> MergeFrom for HashMap<K, V> {
+	#[inline(always)]
+	fn merge_from<O, F>(&mut self, other: O, mut resolve: F)
+	where
+		O: IntoIterator<Item = (K, V)>,
+		F: FnMut(&mut V, V),
+	{
+		let other = other.into_iter();
+		self.reserve(other.size_hint().0);
+		for (key, value) in other {
+			match self.entry(key) {
+				std::collections::hash_map::Entry::Occupied(entry) => {
+					resolve(entry.into_mut(), value)
+				}
+				std::collections::hash_map::Entry::Vacant(entry) => {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
+impl<K: Hash + Eq, V> TryInsert<K> for HashMap<K, V> {
+	#[inline(always)]
+	fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, Self>> {
+		match self.entry(key) {
+			std::collections::hash_map::Entry::Occupied(entry) => Err(OccupiedError {
+				existing: entry.into_mut(),
+				value,
+			}),
+			std::collections::hash_map::Entry::Vacant(entry) => Ok(entry.insert(value)),
+		}
+	}
+}
+
+impl<K: Hash + Eq, V> Update for HashMap<K, V> {
+	#[inline(always)]
+	fn update<F>(&mut self, key: K, f: F)
+	where
+		F: FnOnce(Option<V>) -> Option<V>,
+	{
+		match self.entry(key) {
+			std::collections::hash_map::Entry::Occupied(entry) => {
+				let (key, value) = entry.remove_entry();
+				if let Some(value) = f(Some(value)) {
+					self.insert(key, value);
+				}
+			}
+			std::collections::hash_map::Entry::Vacant(entry) => {
+				if let Some(value) = f(None) {
+					entry.insert(value);
+				}
+			}
+		}
+	}
+}
+
 impl<'a, Q, K: Hash + Eq, V> Remove<&'a Q> for HashMap<K, V>
 where
 	K: Borrow<Q>,
@@ -104,6 +207,17 @@ where
 	}
 }
 
+impl<'a, Q, K: Hash + Eq, V> RemoveEntry<&'a Q> for HashMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn remove_entry(&mut self, key: &'a Q) -> Option<(K, V)> {
+		self.remove_entry(key)
+	}
+}
+
 impl<K, V> Clear for HashMap<K, V> {
 	#[inline(always)]
 	fn clear(&mut self) {
@@ -111,11 +225,18 @@ impl<K, V> Clear for HashMap<K, V> {
 	}
 }
 
+impl<K, V> Recycle for HashMap<K, V> {
+	#[inline(always)]
+	fn recycle(&mut self) {
+		self.clear()
+	}
+}
+
 impl<K, V> Iter for HashMap<K, V> {
 	type Iter<'a>
+		= std::collections::hash_map::Values<'a, K, V>
 	where
-		Self: 'a,
-	= std::collections::hash_map::Values<'a, K, V>;
+		Self: 'a;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
@@ -125,9 +246,9 @@ impl<K, V> Iter for HashMap<K, V> {
 
 impl<K, V> MapIter for HashMap<K, V> {
 	type Iter<'a>
+		= std::collections::hash_map::Iter<'a, K, V>
 	where
-		Self: 'a,
-	= std::collections::hash_map::Iter<'a, K, V>;
+		Self: 'a;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
@@ -137,9 +258,9 @@ impl<K, V> MapIter for HashMap<K, V> {
 
 impl<K, V> MapIterMut for HashMap<K, V> {
 	type IterMut<'a>
+		= std::collections::hash_map::IterMut<'a, K, V>
 	where
-		Self: 'a,
-	= std::collections::hash_map::IterMut<'a, K, V>;
+		Self: 'a;
 
 	#[inline(always)]
 	fn iter_mut(&mut self) -> Self::IterMut<'_> {
@@ -1,29 +1,52 @@
-use crate::{Clear, Collection, CollectionMut, CollectionRef, Get, Insert, Iter, Len, Remove};
-use std::{borrow::Borrow, collections::HashSet, hash::Hash};
+use crate::{
+	Capacity, Clear, Collection, CollectionMut, CollectionRef, Contains, Get, Insert, Iter, Len,
+	Len64, LenHint, MemSize, Recycle, Remove, Replace, Reserve, SetCompare, SetOps, SetPop, Take,
+	WithCapacity,
+};
+use std::{
+	borrow::Borrow,
+	collections::HashSet,
+	hash::{BuildHasher, Hash},
+};
 
-impl<T> Collection for HashSet<T> {
+impl<T, S> Collection for HashSet<T, S> {
 	type Item = T;
 }
 
-impl<T> CollectionRef for HashSet<T> {
+impl<T, S> CollectionRef for HashSet<T, S> {
 	type ItemRef<'a>
+		= &'a T
 	where
-		Self: 'a,
-	= &'a T;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
-impl<T> CollectionMut for HashSet<T> {
+impl<T, S> CollectionMut for HashSet<T, S> {
 	type ItemMut<'a>
+		= &'a mut T
 	where
-		Self: 'a,
-	= &'a mut T;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
 
-impl<T> Len for HashSet<T> {
+impl<T, S: BuildHasher + Default> WithCapacity for HashSet<T, S> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		HashSet::with_capacity_and_hasher(capacity, S::default())
+	}
+}
+
+impl<T, S> MemSize for HashSet<T, S> {
+	/// Each bucket stores an element and one control byte.
+	#[inline(always)]
+	fn mem_size(&self) -> usize {
+		self.capacity() * (std::mem::size_of::<T>() + 1)
+	}
+}
+
+impl<T, S> Len for HashSet<T, S> {
 	#[inline(always)]
 	fn len(&self) -> usize {
 		self.len()
@@ -35,7 +58,35 @@ impl<T> Len for HashSet<T> {
 	}
 }
 
-impl<'a, Q, T: Hash + Eq> Get<&'a Q> for HashSet<T>
+impl<T, S> Len64 for HashSet<T, S> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<T, S> LenHint for HashSet<T, S> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<T, S> Capacity for HashSet<T, S> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Reserve for HashSet<T, S> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl<'a, Q, T: Hash + Eq, S: BuildHasher> Get<&'a Q> for HashSet<T, S>
 where
 	T: Borrow<Q>,
 	Q: Hash + Eq + ?Sized,
@@ -45,7 +96,18 @@ where
 	}
 }
 
-impl<T: Hash + Eq> Insert for HashSet<T> {
+impl<'a, Q, T: Hash + Eq, S: BuildHasher> Contains<&'a Q> for HashSet<T, S>
+where
+	T: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn contains_value(&self, value: &'a Q) -> bool {
+		self.contains(value)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Insert for HashSet<T, S> {
 	type Output = bool;
 
 	#[inline(always)]
@@ -54,7 +116,48 @@ impl<T: Hash + Eq> Insert for HashSet<T> {
 	}
 }
 
-impl<'a, Q, T: Hash + Eq> Remove<&'a Q> for HashSet<T>
+#[cfg(feature = "nightly")]
+impl<T: Hash + Eq, S: BuildHasher> crate::SetEntryApi for HashSet<T, S> {
+	#[inline(always)]
+	fn get_or_insert(&mut self, t: T) -> &T {
+		self.get_or_insert(t)
+	}
+}
+
+#[cfg(feature = "nightly")]
+impl<'a, Q, T: Hash + Eq, S: BuildHasher> crate::SetEntryApiWith<&'a Q> for HashSet<T, S>
+where
+	T: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn get_or_insert_with<F>(&mut self, value: &'a Q, f: F) -> &T
+	where
+		F: FnOnce(&'a Q) -> T,
+	{
+		self.get_or_insert_with(value, move |_| f(value))
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Replace for HashSet<T, S> {
+	#[inline(always)]
+	fn replace(&mut self, t: T) -> Option<T> {
+		self.replace(t)
+	}
+}
+
+impl<'a, Q, T: Hash + Eq, S: BuildHasher> Take<&'a Q> for HashSet<T, S>
+where
+	T: Borrow<Q>,
+	Q: Hash + Eq + ?Sized,
+{
+	#[inline(always)]
+	fn take(&mut self, t: &'a Q) -> Option<T> {
+		self.take(t)
+	}
+}
+
+impl<'a, Q, T: Hash + Eq, S: BuildHasher> Remove<&'a Q> for HashSet<T, S>
 where
 	T: Borrow<Q>,
 	Q: Hash + Eq + ?Sized,
@@ -65,21 +168,94 @@ where
 	}
 }
 
-impl<T: Hash + Eq> Clear for HashSet<T> {
+impl<T: Hash + Eq, S: BuildHasher> SetPop for HashSet<T, S> {
+	#[inline(always)]
+	fn pop(&mut self) -> Option<T> {
+		self.extract_if(|_| true).next()
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Clear for HashSet<T, S> {
 	#[inline(always)]
 	fn clear(&mut self) {
 		self.clear()
 	}
 }
 
-impl<T> Iter for HashSet<T> {
+impl<T, S> Recycle for HashSet<T, S> {
+	#[inline(always)]
+	fn recycle(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T, S> Iter for HashSet<T, S> {
 	type Iter<'a>
+		= std::collections::hash_set::Iter<'a, T>
 	where
-		T: 'a,
-	= std::collections::hash_set::Iter<'a, T>;
+		Self: 'a;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
 		self.iter()
 	}
 }
+
+impl<T: Hash + Eq, S: BuildHasher> SetOps for HashSet<T, S> {
+	type Union<'a>
+		= std::collections::hash_set::Union<'a, T, S>
+	where
+		Self: 'a;
+
+	type Intersection<'a>
+		= std::collections::hash_set::Intersection<'a, T, S>
+	where
+		Self: 'a;
+
+	type Difference<'a>
+		= std::collections::hash_set::Difference<'a, T, S>
+	where
+		Self: 'a;
+
+	type SymmetricDifference<'a>
+		= std::collections::hash_set::SymmetricDifference<'a, T, S>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn union<'a>(&'a self, other: &'a Self) -> Self::Union<'a> {
+		self.union(other)
+	}
+
+	#[inline(always)]
+	fn intersection<'a>(&'a self, other: &'a Self) -> Self::Intersection<'a> {
+		self.intersection(other)
+	}
+
+	#[inline(always)]
+	fn difference<'a>(&'a self, other: &'a Self) -> Self::Difference<'a> {
+		self.difference(other)
+	}
+
+	#[inline(always)]
+	fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Self::SymmetricDifference<'a> {
+		self.symmetric_difference(other)
+	}
+}
+
+impl<T: Hash + Eq, S: BuildHasher> SetCompare for HashSet<T, S> {
+	#[inline(always)]
+	fn is_subset(&self, other: &Self) -> bool {
+		self.is_subset(other)
+	}
+
+	#[inline(always)]
+	fn is_superset(&self, other: &Self) -> bool {
+		self.is_superset(other)
+	}
+
+	#[inline(always)]
+	fn is_disjoint(&self, other: &Self) -> bool {
+		self.is_disjoint(other)
+	}
+}
@@ -0,0 +1,177 @@
+//! A `LinkedList` is a sequence without indexing,
+//! accessed through its front and back.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Back, Front, PopFront, PushBack, PushFront};
+//! use std::collections::LinkedList;
+//!
+//! let mut list = LinkedList::new();
+//! PushBack::push_back(&mut list, 2);
+//! PushFront::push_front(&mut list, 1);
+//! assert_eq!(Front::front(&list).copied(), Some(1));
+//! assert_eq!(Back::back(&list).copied(), Some(2));
+//! assert_eq!(PopFront::pop_front(&mut list), Some(1));
+//! ```
+use crate::{
+	Append, Back, BackMut, Clear, Collection, CollectionMut, CollectionRef, Contains, Front,
+	FrontMut, Iter, IterMut, Len, Len64, LenHint, PopBack, PopFront, PushBack, PushFront,
+};
+use std::collections::LinkedList;
+
+impl<T> Collection for LinkedList<T> {
+	type Item = T;
+}
+
+impl<T> CollectionRef for LinkedList<T> {
+	type ItemRef<'a>
+		= &'a T
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T> CollectionMut for LinkedList<T> {
+	type ItemMut<'a>
+		= &'a mut T
+	where
+		Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T> Len for LinkedList<T> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T> Len64 for LinkedList<T> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<T> LenHint for LinkedList<T> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<'a, T> Contains<&'a T> for LinkedList<T>
+where
+	T: PartialEq,
+{
+	#[inline(always)]
+	fn contains_value(&self, value: &'a T) -> bool {
+		self.contains(value)
+	}
+}
+
+impl<T> Front for LinkedList<T> {
+	#[inline(always)]
+	fn front(&self) -> Option<&T> {
+		self.front()
+	}
+}
+
+impl<T> FrontMut for LinkedList<T> {
+	#[inline(always)]
+	fn front_mut(&mut self) -> Option<&mut T> {
+		self.front_mut()
+	}
+}
+
+impl<T> Back for LinkedList<T> {
+	#[inline(always)]
+	fn back(&self) -> Option<&T> {
+		self.back()
+	}
+}
+
+impl<T> BackMut for LinkedList<T> {
+	#[inline(always)]
+	fn back_mut(&mut self) -> Option<&mut T> {
+		self.back_mut()
+	}
+}
+
+impl<T> PushFront for LinkedList<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_front(&mut self, t: T) {
+		self.push_front(t)
+	}
+}
+
+impl<T> PushBack for LinkedList<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_back(&mut self, t: T) {
+		self.push_back(t)
+	}
+}
+
+impl<T> PopFront for LinkedList<T> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<T> {
+		self.pop_front()
+	}
+}
+
+impl<T> PopBack for LinkedList<T> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<T> {
+		self.pop_back()
+	}
+}
+
+impl<T> Append for LinkedList<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<T> Clear for LinkedList<T> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl<T> Iter for LinkedList<T> {
+	type Iter<'a>
+		= std::collections::linked_list::Iter<'a, T>
+	where
+		T: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T> IterMut for LinkedList<T> {
+	type IterMut<'a>
+		= std::collections::linked_list::IterMut<'a, T>
+	where
+		T: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
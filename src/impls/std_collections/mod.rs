@@ -1,6 +1,18 @@
+mod binaryheap;
 mod btreemap;
 mod btreeset;
+mod cell;
 mod deque;
 mod hashmap;
 mod hashset;
+mod linked_list;
+mod os_string;
+mod path_buf;
+mod slice;
+mod string;
+mod sync;
 mod vec;
+
+pub use cell::CellRef;
+pub use string::{CharIter, CharRef};
+pub use sync::LockRef;
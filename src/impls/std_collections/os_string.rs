@@ -0,0 +1,73 @@
+//! An `OsString` is a growable buffer of platform strings.
+//!
+//! Its encoding is platform-specific, so it does not expose its elements:
+//! [`Len`] and [`Capacity`] are measured in bytes of the encoded string.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Clear, Len, PushStr};
+//! use std::ffi::{OsStr, OsString};
+//!
+//! let mut string = OsString::new();
+//! PushStr::<OsStr>::push_str(&mut string, OsStr::new("abc"));
+//! assert_eq!(Len::len(&string), 3);
+//! Clear::clear(&mut string);
+//! assert!(Len::is_empty(&string));
+//! ```
+use crate::{Capacity, Clear, Len, PushStr, Recycle, Reserve, WithCapacity};
+use std::ffi::{OsStr, OsString};
+
+impl WithCapacity for OsString {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		OsString::with_capacity(capacity)
+	}
+}
+
+impl Len for OsString {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.as_os_str().len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.as_os_str().is_empty()
+	}
+}
+
+impl Capacity for OsString {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl Reserve for OsString {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl PushStr<OsStr> for OsString {
+	#[inline(always)]
+	fn push_str(&mut self, s: &OsStr) {
+		self.push(s)
+	}
+}
+
+impl Clear for OsString {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl Recycle for OsString {
+	#[inline(always)]
+	fn recycle(&mut self) {
+		self.clear()
+	}
+}
@@ -0,0 +1,104 @@
+//! A `PathBuf` is a sequence of path components.
+//!
+//! Components are pushed as `OsString`s with [`PathBuf::push`],
+//! so pushing an absolute path replaces the whole path.
+//! [`Len::len`] counts the components in linear time,
+//! while [`Capacity`] and [`Reserve`] are measured in bytes.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Len, PopBack, PushBack};
+//! use std::path::PathBuf;
+//!
+//! let mut path = PathBuf::from("a/b");
+//! assert_eq!(Len::len(&path), 2);
+//!
+//! PushBack::push_back(&mut path, "c".into());
+//! assert_eq!(path, PathBuf::from("a/b/c"));
+//! assert_eq!(PopBack::pop_back(&mut path), Some("c".into()));
+//! assert_eq!(Len::len(&path), 2);
+//! ```
+use crate::{
+	Capacity, Clear, Collection, Len, PopBack, PushBack, PushStr, Recycle, Reserve, WithCapacity,
+};
+use std::{
+	ffi::OsString,
+	path::{Path, PathBuf},
+};
+
+impl Collection for PathBuf {
+	type Item = OsString;
+}
+
+impl WithCapacity for PathBuf {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		PathBuf::with_capacity(capacity)
+	}
+}
+
+impl Len for PathBuf {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.components().count()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.as_os_str().is_empty()
+	}
+}
+
+impl Capacity for PathBuf {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl Reserve for PathBuf {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl PushBack for PathBuf {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_back(&mut self, component: OsString) {
+		self.push(component)
+	}
+}
+
+impl PushStr<Path> for PathBuf {
+	#[inline(always)]
+	fn push_str(&mut self, path: &Path) {
+		self.push(path)
+	}
+}
+
+impl PopBack for PathBuf {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<OsString> {
+		let last = self.file_name()?.to_os_string();
+		self.pop();
+		Some(last)
+	}
+}
+
+impl Clear for PathBuf {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.as_mut_os_string().clear()
+	}
+}
+
+impl Recycle for PathBuf {
+	#[inline(always)]
+	fn recycle(&mut self) {
+		self.as_mut_os_string().clear()
+	}
+}
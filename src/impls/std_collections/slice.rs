@@ -0,0 +1,250 @@
+//! Slices and arrays are contiguous sequences.
+//!
+//! Slice references `&[T]` and `&mut [T]` get the traits of `[T]`
+//! through the forwarding implementations for references.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Get, GetMut, Len};
+//!
+//! fn sum<S: ?Sized + Len + Get<usize>>(seq: &S) -> i32
+//! where
+//!   for<'a> S::ItemRef<'a>: std::ops::Deref<Target = i32>,
+//! {
+//!   (0..seq.len()).map(|i| *seq.get(i).unwrap()).sum()
+//! }
+//!
+//! let mut array = [1, 2, 3];
+//! *array.get_mut(0).unwrap() = 10;
+//! assert_eq!(sum(&array), 15);
+//! assert_eq!(sum(&array[1..]), 5);
+//! ```
+use crate::{
+	AsMutSlice, AsSlice, Collection, CollectionMut, CollectionRef, Get, GetMut, Iter, IterMut, Len,
+	Len64, LenHint, SplitAtMut,
+};
+
+impl<T> Collection for [T] {
+	type Item = T;
+}
+
+impl<T> CollectionRef for [T] {
+	type ItemRef<'a>
+		= &'a T
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T> Len for [T] {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl<T> Len64 for [T] {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<T> LenHint for [T] {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
+impl<T> Get<usize> for [T] {
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&T> {
+		self.get(index)
+	}
+}
+
+impl<T> Iter for [T] {
+	type Iter<'a>
+		= std::slice::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T> AsSlice for [T] {
+	#[inline(always)]
+	fn as_slice(&self) -> &[T] {
+		self
+	}
+}
+
+impl<T> CollectionMut for [T] {
+	type ItemMut<'a>
+		= &'a mut T
+	where
+		Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T> GetMut<usize> for [T] {
+	#[inline(always)]
+	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.get_mut(index)
+	}
+}
+
+impl<T> IterMut for [T] {
+	type IterMut<'a>
+		= std::slice::IterMut<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
+
+impl<T> AsMutSlice for [T] {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		self
+	}
+}
+
+impl<T> SplitAtMut for [T] {
+	type SplitMut<'a>
+		= &'a mut [T]
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+		self.split_at_mut(mid)
+	}
+}
+
+impl<T, const N: usize> Collection for [T; N] {
+	type Item = T;
+}
+
+impl<T, const N: usize> CollectionRef for [T; N] {
+	type ItemRef<'a>
+		= &'a T
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<T, const N: usize> Len for [T; N] {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.as_slice().len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.as_slice().is_empty()
+	}
+}
+
+impl<T, const N: usize> Len64 for [T; N] {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.as_slice().len() as u64
+	}
+}
+
+impl<T, const N: usize> LenHint for [T; N] {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.as_slice().len(), Some(self.as_slice().len()))
+	}
+}
+
+impl<T, const N: usize> Get<usize> for [T; N] {
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&T> {
+		self.as_slice().get(index)
+	}
+}
+
+impl<T, const N: usize> Iter for [T; N] {
+	type Iter<'a>
+		= std::slice::Iter<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.as_slice().iter()
+	}
+}
+
+impl<T, const N: usize> AsSlice for [T; N] {
+	#[inline(always)]
+	fn as_slice(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T, const N: usize> CollectionMut for [T; N] {
+	type ItemMut<'a>
+		= &'a mut T
+	where
+		Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<T, const N: usize> GetMut<usize> for [T; N] {
+	#[inline(always)]
+	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.as_mut_slice().get_mut(index)
+	}
+}
+
+impl<T, const N: usize> IterMut for [T; N] {
+	type IterMut<'a>
+		= std::slice::IterMut<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+impl<T, const N: usize> AsMutSlice for [T; N] {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+impl<T, const N: usize> SplitAtMut for [T; N] {
+	type SplitMut<'a>
+		= &'a mut [T]
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+		self.as_mut_slice().split_at_mut(mid)
+	}
+}
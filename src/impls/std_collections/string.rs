@@ -0,0 +1,159 @@
+//! A `String` is a collection of `char`s.
+//!
+//! Characters are decoded on the fly, so they are referenced by value
+//! through [`CharRef`], and [`Len::len`] counts the characters
+//! in linear time (unlike [`String::len`] counting the bytes).
+//! [`LenHint`] provides constant-time bounds computed from the number of bytes.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{Iter, Len, PopBack, PushBack};
+//!
+//! let mut string = String::from("hé");
+//! assert_eq!(Len::len(&string), 2);
+//! assert_eq!(string.len(), 3);
+//!
+//! PushBack::push_back(&mut string, '!');
+//! assert_eq!(Iter::iter(&string).map(|c| *c).collect::<String>(), "hé!");
+//! assert_eq!(PopBack::pop_back(&mut string), Some('!'));
+//! ```
+use crate::{
+	Capacity, Clear, Collection, CollectionRef, Iter, Len, LenHint, PopBack, PushBack, PushStr,
+	Recycle, Reserve, WithCapacity,
+};
+use std::{ops::Deref, str::Chars};
+
+/// Reference to a character decoded from a `String`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CharRef(pub char);
+
+impl Deref for CharRef {
+	type Target = char;
+
+	#[inline(always)]
+	fn deref(&self) -> &char {
+		&self.0
+	}
+}
+
+/// Iterator over the characters of a `String`.
+pub struct CharIter<'a>(Chars<'a>);
+
+impl<'a> Iterator for CharIter<'a> {
+	type Item = CharRef;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<CharRef> {
+		self.0.next().map(CharRef)
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<'a> DoubleEndedIterator for CharIter<'a> {
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<CharRef> {
+		self.0.next_back().map(CharRef)
+	}
+}
+
+impl Collection for String {
+	type Item = char;
+}
+
+impl CollectionRef for String {
+	type ItemRef<'a> = CharRef;
+
+	crate::covariant_item_ref!();
+}
+
+impl WithCapacity for String {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		String::with_capacity(capacity)
+	}
+}
+
+impl Len for String {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.chars().count()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl LenHint for String {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		let bytes = self.len();
+		(bytes.div_ceil(4), Some(bytes))
+	}
+}
+
+impl Capacity for String {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl Reserve for String {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl PushBack for String {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_back(&mut self, c: char) {
+		self.push(c)
+	}
+}
+
+impl PushStr<str> for String {
+	#[inline(always)]
+	fn push_str(&mut self, s: &str) {
+		self.push_str(s)
+	}
+}
+
+impl PopBack for String {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<char> {
+		self.pop()
+	}
+}
+
+impl Clear for String {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
+
+impl Recycle for String {
+	#[inline(always)]
+	fn recycle(&mut self) {
+		self.clear()
+	}
+}
+
+impl Iter for String {
+	type Iter<'a> = CharIter<'a>;
+
+	#[inline(always)]
+	fn iter(&self) -> CharIter<'_> {
+		CharIter(self.chars())
+	}
+}
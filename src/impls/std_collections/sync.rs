@@ -0,0 +1,279 @@
+//! A `Mutex<C>` or `RwLock<C>` is queried by locking the inner collection.
+//!
+//! References returned by [`Get::get`] hold the lock until they are dropped,
+//! so they are [`LockRef`] lock guards.
+//! Mutable access goes through `get_mut` and never locks.
+//! Poisoned locks cause a panic.
+use crate::{
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Insert, IterMut, Keyed, Len,
+	MapInsert, PopBack, PopFront, PushBack, PushFront, Remove, Update,
+};
+use std::{
+	ops::Deref,
+	ptr::NonNull,
+	sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard},
+};
+
+const POISONED_LOCK: &str = "poisoned lock";
+
+/// Reference to an item of a collection stored behind a lock,
+/// holding the `G` lock guard.
+///
+/// The guard is shared between the clones of the reference,
+/// and released when all of them are dropped.
+///
+/// For a `Mutex`, this means the collection cannot be queried again
+/// until the reference is dropped:
+/// querying the same `Mutex` while holding a reference deadlocks.
+/// Read locks can be shared, so a `RwLock` can be queried multiple times.
+///
+/// ```
+/// use cc_traits::{Get, MapInsert};
+/// use std::{collections::HashMap, sync::RwLock};
+///
+/// let mut map: RwLock<HashMap<&str, i32>> = RwLock::default();
+/// map.insert("a", 1);
+///
+/// let a = map.get(&"a").unwrap();
+/// let b = map.get(&"a").unwrap(); // read locks can be shared.
+/// assert_eq!(*a + *b, 2);
+/// ```
+pub struct LockRef<G, T: ?Sized> {
+	guard: Arc<G>,
+	item: NonNull<T>,
+}
+
+impl<G, T: ?Sized> LockRef<G, T> {
+	/// Creates a reference to the `item` protected by the given `guard`.
+	///
+	/// The `item` must point inside the data protected by `guard`.
+	pub(crate) fn new(guard: G, item: NonNull<T>) -> Self {
+		LockRef {
+			guard: Arc::new(guard),
+			item,
+		}
+	}
+}
+
+// SAFETY: a `LockRef` only gives shared access to the item and guard.
+unsafe impl<G: Send + Sync, T: ?Sized + Sync> Send for LockRef<G, T> {}
+
+// SAFETY: a `LockRef` only gives shared access to the item and guard.
+unsafe impl<G: Send + Sync, T: ?Sized + Sync> Sync for LockRef<G, T> {}
+
+impl<G, T: ?Sized> Clone for LockRef<G, T> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		LockRef {
+			guard: self.guard.clone(),
+			item: self.item,
+		}
+	}
+}
+
+impl<G, T: ?Sized> Deref for LockRef<G, T> {
+	type Target = T;
+
+	#[inline(always)]
+	fn deref(&self) -> &T {
+		// SAFETY: `item` points inside the collection protected by `guard`,
+		// which cannot be moved nor modified as long as the guard is alive.
+		unsafe { self.item.as_ref() }
+	}
+}
+
+macro_rules! lock_impls {
+	($($lock:ident),*) => {
+		$(
+			impl<C: Collection> Collection for $lock<C> {
+				type Item = C::Item;
+			}
+
+			impl<C: CollectionMut> CollectionMut for $lock<C> {
+				type ItemMut<'a>
+					= C::ItemMut<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+				where
+					Self: 'long,
+				{
+					C::upcast_item_mut(r)
+				}
+			}
+
+			impl<C: Keyed> Keyed for $lock<C> {
+				type Key = C::Key;
+			}
+
+			impl<T, C> GetMut<T> for $lock<C>
+			where
+				C: 'static + GetMut<T> + for<'a> CollectionRef<ItemRef<'a> = &'a <C as Collection>::Item>,
+			{
+				#[inline(always)]
+				fn get_mut(&mut self, key: T) -> Option<C::ItemMut<'_>> {
+					self.get_mut().expect(POISONED_LOCK).get_mut(key)
+				}
+			}
+
+			impl<C: Insert> Insert for $lock<C> {
+				type Output = C::Output;
+
+				#[inline(always)]
+				fn insert(&mut self, element: C::Item) -> C::Output {
+					self.get_mut().expect(POISONED_LOCK).insert(element)
+				}
+			}
+
+			impl<K, C: MapInsert<K>> MapInsert<K> for $lock<C> {
+				type Output = C::Output;
+
+				#[inline(always)]
+				fn insert(&mut self, key: K, value: C::Item) -> C::Output {
+					self.get_mut().expect(POISONED_LOCK).insert(key, value)
+				}
+			}
+
+			impl<T, C: Remove<T>> Remove<T> for $lock<C> {
+				#[inline(always)]
+				fn remove(&mut self, key: T) -> Option<C::Item> {
+					self.get_mut().expect(POISONED_LOCK).remove(key)
+				}
+			}
+
+			impl<C: Update> Update for $lock<C> {
+				#[inline(always)]
+				fn update<F>(&mut self, key: C::Key, f: F)
+				where
+					F: FnOnce(Option<C::Item>) -> Option<C::Item>,
+				{
+					self.get_mut().expect(POISONED_LOCK).update(key, f)
+				}
+			}
+
+			impl<C: PushFront> PushFront for $lock<C> {
+				type Output = C::Output;
+
+				#[inline(always)]
+				fn push_front(&mut self, element: C::Item) -> C::Output {
+					self.get_mut().expect(POISONED_LOCK).push_front(element)
+				}
+			}
+
+			impl<C: PushBack> PushBack for $lock<C> {
+				type Output = C::Output;
+
+				#[inline(always)]
+				fn push_back(&mut self, element: C::Item) -> C::Output {
+					self.get_mut().expect(POISONED_LOCK).push_back(element)
+				}
+			}
+
+			impl<C: PopFront> PopFront for $lock<C> {
+				#[inline(always)]
+				fn pop_front(&mut self) -> Option<C::Item> {
+					self.get_mut().expect(POISONED_LOCK).pop_front()
+				}
+			}
+
+			impl<C: PopBack> PopBack for $lock<C> {
+				#[inline(always)]
+				fn pop_back(&mut self) -> Option<C::Item> {
+					self.get_mut().expect(POISONED_LOCK).pop_back()
+				}
+			}
+
+			impl<C: Clear> Clear for $lock<C> {
+				#[inline(always)]
+				fn clear(&mut self) {
+					self.get_mut().expect(POISONED_LOCK).clear()
+				}
+			}
+
+			impl<C: IterMut> IterMut for $lock<C> {
+				type IterMut<'a>
+					= C::IterMut<'a>
+				where
+					Self: 'a;
+
+				#[inline(always)]
+				fn iter_mut(&mut self) -> Self::IterMut<'_> {
+					self.get_mut().expect(POISONED_LOCK).iter_mut()
+				}
+			}
+		)*
+	};
+}
+
+lock_impls!(Mutex, RwLock);
+
+impl<C: Collection> CollectionRef for Mutex<C> {
+	type ItemRef<'a>
+		= LockRef<MutexGuard<'a, C>, C::Item>
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<C: Len> Len for Mutex<C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.lock().expect(POISONED_LOCK).len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.lock().expect(POISONED_LOCK).is_empty()
+	}
+}
+
+/// The returned reference keeps the mutex locked until it is dropped,
+/// so querying the same mutex again before dropping it deadlocks.
+impl<T, C> Get<T> for Mutex<C>
+where
+	C: 'static + Get<T> + for<'a> CollectionRef<ItemRef<'a> = &'a <C as Collection>::Item>,
+{
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<Self::ItemRef<'_>> {
+		let guard = self.lock().expect(POISONED_LOCK);
+		let item = NonNull::from(guard.get(key)?);
+		Some(LockRef::new(guard, item))
+	}
+}
+
+impl<C: Collection> CollectionRef for RwLock<C> {
+	type ItemRef<'a>
+		= LockRef<RwLockReadGuard<'a, C>, C::Item>
+	where
+		Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<C: Len> Len for RwLock<C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.read().expect(POISONED_LOCK).len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.read().expect(POISONED_LOCK).is_empty()
+	}
+}
+
+/// The returned reference holds a read lock until it is dropped.
+impl<T, C> Get<T> for RwLock<C>
+where
+	C: 'static + Get<T> + for<'a> CollectionRef<ItemRef<'a> = &'a <C as Collection>::Item>,
+{
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<Self::ItemRef<'_>> {
+		let guard = self.read().expect(POISONED_LOCK);
+		let item = NonNull::from(guard.get(key)?);
+		Some(LockRef::new(guard, item))
+	}
+}
@@ -1,7 +1,11 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Iter, IterMut, Len,
-	PopBack, PushBack, Remove, Reserve, WithCapacity,
+	Append, AsMutSlice, AsSlice, BinarySearch, Capacity, Clear, Collection, CollectionMut,
+	CollectionRef, Contains, Dedup, DrainRange, ExtendFromSlice, ExtendFromWithin, Fill, Get,
+	GetManyMut, GetMut, InsertAt, Iter, IterMut, Len, Len64, LenHint, MemSize, PopBack, PushBack,
+	Recycle, Remove, Reserve, Resize, Reverse, Rotate, Sort, SortUnstable, SplitAtMut, Swap,
+	SwapRemove, Truncate, WithCapacity,
 };
+use std::{cmp::Ordering, ops::RangeBounds};
 
 impl<T> Collection for Vec<T> {
 	type Item = T;
@@ -9,18 +13,18 @@ impl<T> Collection for Vec<T> {
 
 impl<T> CollectionRef for Vec<T> {
 	type ItemRef<'a>
+		= &'a T
 	where
-		Self: 'a,
-	= &'a T;
+		Self: 'a;
 
 	crate::covariant_item_ref!();
 }
 
 impl<T> CollectionMut for Vec<T> {
 	type ItemMut<'a>
+		= &'a mut T
 	where
-		Self: 'a,
-	= &'a mut T;
+		Self: 'a;
 
 	crate::covariant_item_mut!();
 }
@@ -32,6 +36,13 @@ impl<T> WithCapacity for Vec<T> {
 	}
 }
 
+impl<T> MemSize for Vec<T> {
+	#[inline(always)]
+	fn mem_size(&self) -> usize {
+		self.capacity() * std::mem::size_of::<T>()
+	}
+}
+
 impl<T> Len for Vec<T> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -44,6 +55,20 @@ impl<T> Len for Vec<T> {
 	}
 }
 
+impl<T> Len64 for Vec<T> {
+	#[inline(always)]
+	fn len64(&self) -> u64 {
+		self.len() as u64
+	}
+}
+
+impl<T> LenHint for Vec<T> {
+	#[inline(always)]
+	fn len_hint(&self) -> (usize, Option<usize>) {
+		(self.len(), Some(self.len()))
+	}
+}
+
 impl<T> Get<usize> for Vec<T> {
 	#[inline(always)]
 	fn get(&self, index: usize) -> Option<&T> {
@@ -51,6 +76,16 @@ impl<T> Get<usize> for Vec<T> {
 	}
 }
 
+impl<'a, T> Contains<&'a T> for Vec<T>
+where
+	T: PartialEq,
+{
+	#[inline(always)]
+	fn contains_value(&self, value: &'a T) -> bool {
+		self.as_slice().contains(value)
+	}
+}
+
 impl<T> GetMut<usize> for Vec<T> {
 	#[inline(always)]
 	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
@@ -58,6 +93,19 @@ impl<T> GetMut<usize> for Vec<T> {
 	}
 }
 
+impl<T> GetManyMut<usize> for Vec<T> {
+	#[inline(always)]
+	fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+		match self.as_mut_slice().get_disjoint_mut(indices) {
+			Ok(items) => Some(items),
+			Err(std::slice::GetDisjointMutError::IndexOutOfBounds) => None,
+			Err(std::slice::GetDisjointMutError::OverlappingIndices) => {
+				panic!("overlapping indices")
+			}
+		}
+	}
+}
+
 impl<T> Capacity for Vec<T> {
 	#[inline(always)]
 	fn capacity(&self) -> usize {
@@ -81,6 +129,13 @@ impl<T> PushBack for Vec<T> {
 	}
 }
 
+impl<T> Append for Vec<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
 impl<T> PopBack for Vec<T> {
 	#[inline(always)]
 	fn pop_back(&mut self) -> Option<T> {
@@ -88,6 +143,18 @@ impl<T> PopBack for Vec<T> {
 	}
 }
 
+impl<T> InsertAt for Vec<T> {
+	#[inline(always)]
+	fn insert_at(&mut self, index: usize, element: T) -> Result<(), T> {
+		if index <= self.len() {
+			self.insert(index, element);
+			Ok(())
+		} else {
+			Err(element)
+		}
+	}
+}
+
 impl<T> Remove<usize> for Vec<T> {
 	#[inline(always)]
 	fn remove(&mut self, index: usize) -> Option<T> {
@@ -99,6 +166,42 @@ impl<T> Remove<usize> for Vec<T> {
 	}
 }
 
+impl<T> SwapRemove<usize> for Vec<T> {
+	#[inline(always)]
+	fn swap_remove(&mut self, index: usize) -> Option<T> {
+		if index < self.len() {
+			Some(self.swap_remove(index))
+		} else {
+			None
+		}
+	}
+}
+
+impl<T> Truncate for Vec<T> {
+	#[inline(always)]
+	fn truncate(&mut self, len: usize) {
+		self.truncate(len)
+	}
+}
+
+impl<T> Resize for Vec<T> {
+	#[inline(always)]
+	fn resize(&mut self, new_len: usize, value: T)
+	where
+		T: Clone,
+	{
+		self.resize(new_len, value)
+	}
+
+	#[inline(always)]
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> T,
+	{
+		self.resize_with(new_len, f)
+	}
+}
+
 impl<T> Clear for Vec<T> {
 	#[inline(always)]
 	fn clear(&mut self) {
@@ -106,11 +209,18 @@ impl<T> Clear for Vec<T> {
 	}
 }
 
+impl<T> Recycle for Vec<T> {
+	#[inline(always)]
+	fn recycle(&mut self) {
+		self.clear()
+	}
+}
+
 impl<T> Iter for Vec<T> {
 	type Iter<'a>
+		= std::slice::Iter<'a, T>
 	where
-		T: 'a,
-	= std::slice::Iter<'a, T>;
+		T: 'a;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
@@ -120,12 +230,189 @@ impl<T> Iter for Vec<T> {
 
 impl<T> IterMut for Vec<T> {
 	type IterMut<'a>
+		= std::slice::IterMut<'a, T>
 	where
-		T: 'a,
-	= std::slice::IterMut<'a, T>;
+		T: 'a;
 
 	#[inline(always)]
 	fn iter_mut(&mut self) -> Self::IterMut<'_> {
 		self.as_mut_slice().iter_mut()
 	}
 }
+
+impl<T> BinarySearch for Vec<T> {
+	#[inline(always)]
+	fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(&T) -> Ordering,
+	{
+		self.as_slice().binary_search_by(f)
+	}
+
+	#[inline(always)]
+	fn partition_point<P>(&self, pred: P) -> usize
+	where
+		P: FnMut(&T) -> bool,
+	{
+		self.as_slice().partition_point(pred)
+	}
+}
+
+impl<T> Sort for Vec<T> {
+	#[inline(always)]
+	fn sort_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&T, &T) -> Ordering,
+	{
+		self.as_mut_slice().sort_by(f)
+	}
+
+	#[inline(always)]
+	fn sort_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.as_mut_slice().sort_by_key(f)
+	}
+}
+
+impl<T> SortUnstable for Vec<T> {
+	#[inline(always)]
+	fn sort_unstable_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&T, &T) -> Ordering,
+	{
+		self.as_mut_slice().sort_unstable_by(f)
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.as_mut_slice().sort_unstable_by_key(f)
+	}
+}
+
+impl<T> Dedup for Vec<T> {
+	#[inline(always)]
+	fn dedup_by<F>(&mut self, same_bucket: F)
+	where
+		F: FnMut(&mut T, &mut T) -> bool,
+	{
+		self.dedup_by(same_bucket)
+	}
+
+	#[inline(always)]
+	fn dedup_by_key<K, F>(&mut self, key: F)
+	where
+		K: PartialEq,
+		F: FnMut(&mut T) -> K,
+	{
+		self.dedup_by_key(key)
+	}
+}
+
+impl<T> Reverse for Vec<T> {
+	#[inline(always)]
+	fn reverse(&mut self) {
+		self.as_mut_slice().reverse()
+	}
+}
+
+impl<T> Rotate for Vec<T> {
+	#[inline(always)]
+	fn rotate_left(&mut self, mid: usize) {
+		self.as_mut_slice().rotate_left(mid)
+	}
+
+	#[inline(always)]
+	fn rotate_right(&mut self, k: usize) {
+		self.as_mut_slice().rotate_right(k)
+	}
+}
+
+impl<T> AsSlice for Vec<T> {
+	#[inline(always)]
+	fn as_slice(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<T> AsMutSlice for Vec<T> {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+impl<T> SplitAtMut for Vec<T> {
+	type SplitMut<'a>
+		= &'a mut [T]
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+		self.as_mut_slice().split_at_mut(mid)
+	}
+}
+
+impl<T> Swap for Vec<T> {
+	#[inline(always)]
+	fn swap(&mut self, a: usize, b: usize) {
+		self.as_mut_slice().swap(a, b)
+	}
+}
+
+impl<T> Fill for Vec<T> {
+	#[inline(always)]
+	fn fill(&mut self, value: T)
+	where
+		T: Clone,
+	{
+		self.as_mut_slice().fill(value)
+	}
+
+	#[inline(always)]
+	fn fill_with<F>(&mut self, f: F)
+	where
+		F: FnMut() -> T,
+	{
+		self.as_mut_slice().fill_with(f)
+	}
+}
+
+impl<T, R: RangeBounds<usize>> DrainRange<R> for Vec<T> {
+	type Drain<'a>
+		= std::vec::Drain<'a, T>
+	where
+		Self: 'a;
+
+	#[inline(always)]
+	fn drain_range(&mut self, range: R) -> Self::Drain<'_> {
+		self.drain(range)
+	}
+}
+
+impl<T> ExtendFromSlice for Vec<T> {
+	#[inline(always)]
+	fn extend_from_slice(&mut self, other: &[T])
+	where
+		T: Clone,
+	{
+		self.extend_from_slice(other)
+	}
+}
+
+impl<T, R: RangeBounds<usize>> ExtendFromWithin<R> for Vec<T> {
+	#[inline(always)]
+	fn extend_from_within(&mut self, src: R)
+	where
+		T: Clone,
+	{
+		self.extend_from_within(src)
+	}
+}
@@ -0,0 +1,144 @@
+//! The `tokio` locks implement the asynchronous traits by awaiting the lock.
+//!
+//! The synchronous traits are not implemented,
+//! since the lock cannot be acquired synchronously without panicking
+//! when it is held by another task.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::{AsyncGet, AsyncInsert, AsyncLen};
+//! use std::{
+//!   collections::HashMap,
+//!   future::Future,
+//!   pin::pin,
+//!   task::{Context, Poll, Waker},
+//! };
+//! use tokio::sync::RwLock;
+//!
+//! fn ready<F: Future>(f: F) -> F::Output {
+//!   match pin!(f).poll(&mut Context::from_waker(Waker::noop())) {
+//!     Poll::Ready(output) => output,
+//!     Poll::Pending => unreachable!(),
+//!   }
+//! }
+//!
+//! let map = RwLock::new(HashMap::new());
+//! ready(map.insert("a", 1)).unwrap();
+//! assert_eq!(ready(map.get(&"a")).unwrap().map(|v| *v), Some(1));
+//! assert_eq!(ready(map.len()).unwrap(), 1);
+//! ```
+use crate::{
+	AsyncCollection, AsyncGet, AsyncInsert, AsyncLen, AsyncRemove, Collection, CollectionRef, Get,
+	Len, LockRef, MapInsert, Remove,
+};
+use std::{convert::Infallible, ptr::NonNull};
+use tokio::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard};
+
+impl<C: Collection> Collection for RwLock<C> {
+	type Item = C::Item;
+}
+
+impl<C: Collection> AsyncCollection for RwLock<C> {
+	type Error = Infallible;
+}
+
+impl<C: Collection + Len> AsyncLen for RwLock<C>
+where
+	Self: Sync,
+{
+	async fn len(&self) -> Result<usize, Infallible> {
+		Ok(self.read().await.len())
+	}
+}
+
+impl<T: Send, C> AsyncGet<T> for RwLock<C>
+where
+	Self: Sync,
+	C: 'static + Get<T> + for<'a> CollectionRef<ItemRef<'a> = &'a <C as Collection>::Item>,
+{
+	type Ref<'a>
+		= RwLockReadGuard<'a, C::Item>
+	where
+		Self: 'a;
+
+	async fn get(&self, key: T) -> Result<Option<Self::Ref<'_>>, Infallible> {
+		Ok(RwLockReadGuard::try_map(self.read().await, |c| c.get(key)).ok())
+	}
+}
+
+impl<K: Send, C: MapInsert<K>> AsyncInsert<K> for RwLock<C>
+where
+	Self: Sync,
+	C::Item: Send,
+{
+	type Output = C::Output;
+
+	async fn insert(&self, key: K, value: C::Item) -> Result<C::Output, Infallible> {
+		Ok(self.write().await.insert(key, value))
+	}
+}
+
+impl<T: Send, C: Remove<T>> AsyncRemove<T> for RwLock<C>
+where
+	Self: Sync,
+{
+	async fn remove(&self, key: T) -> Result<Option<C::Item>, Infallible> {
+		Ok(self.write().await.remove(key))
+	}
+}
+
+impl<C: Collection> Collection for Mutex<C> {
+	type Item = C::Item;
+}
+
+impl<C: Collection> AsyncCollection for Mutex<C> {
+	type Error = Infallible;
+}
+
+impl<C: Collection + Len> AsyncLen for Mutex<C>
+where
+	Self: Sync,
+{
+	async fn len(&self) -> Result<usize, Infallible> {
+		Ok(self.lock().await.len())
+	}
+}
+
+impl<T: Send, C> AsyncGet<T> for Mutex<C>
+where
+	Self: Sync,
+	C: 'static + Get<T> + for<'a> CollectionRef<ItemRef<'a> = &'a <C as Collection>::Item>,
+{
+	type Ref<'a>
+		= LockRef<MutexGuard<'a, C>, C::Item>
+	where
+		Self: 'a;
+
+	async fn get(&self, key: T) -> Result<Option<Self::Ref<'_>>, Infallible> {
+		let guard = self.lock().await;
+		let item = guard.get(key).map(NonNull::from);
+		Ok(item.map(|item| LockRef::new(guard, item)))
+	}
+}
+
+impl<K: Send, C: MapInsert<K>> AsyncInsert<K> for Mutex<C>
+where
+	Self: Sync,
+	C::Item: Send,
+{
+	type Output = C::Output;
+
+	async fn insert(&self, key: K, value: C::Item) -> Result<C::Output, Infallible> {
+		Ok(self.lock().await.insert(key, value))
+	}
+}
+
+impl<T: Send, C: Remove<T>> AsyncRemove<T> for Mutex<C>
+where
+	Self: Sync,
+{
+	async fn remove(&self, key: T) -> Result<Option<C::Item>, Infallible> {
+		Ok(self.lock().await.remove(key))
+	}
+}
@@ -73,6 +73,41 @@
 //! assert!(deque.try_push(0).is_err());
 //! ```
 //!
+//! # Prelude
+//!
+//! The `prelude` module re-exports the most commonly used traits,
+//! so that they can all be imported at once with `use cc_traits::prelude::*`.
+//! The traits are also grouped by the kind of collection they describe
+//! in the `map`, `seq` and `set` modules.
+//!
+//! # Adapters
+//!
+//! This crate also provides adapters wrapping a collection to change its behavior
+//! while still implementing the traits of this crate:
+//!
+//!   - `MappedValues` is a read-only view of a map exposing its values through a projection function.
+//!   - `Filtered` is a read-only view of a map hiding the entries failing a predicate.
+//!   - `Layered` is a map layering a top map over a bottom map, writing to the top layer only.
+//!   - `Union` is a read-only view merging two maps, resolving conflicting keys with a `ConflictPolicy` (`LeftBiased`, `RightBiased` or `Combine`).
+//!   - `Defaulting` is a total map falling back to a default value for missing keys, inserting it on first mutable access.
+//!   - `Instrumented` counts the queries, hits, misses, insertions and removals performed on the wrapped collection.
+//!   - `Frozen` is a read-only wrapper implementing only the read-only traits of the wrapped collection.
+//!   - `Transactional` buffers the modifications of a map in an overlay until they are committed or rolled back.
+//!   - `Observed` notifies an observer of every modification of the wrapped map.
+//!   - `ArcMap` is a copy-on-write collection shared behind an `Arc`, cloned on its first modification when shared.
+//!   - `BoxedMap` and `BoxedSeq` are type-erased maps and sequences, boxing any `DynMap` or `DynSeq` implementor.
+//!
+//! # Algorithms
+//!
+//! Generic algorithms working on any implementor of the traits of this crate are also provided:
+//!
+//!   - `map_eq` and `seq_eq` compare two maps or sequences of possibly different types.
+//!   - `diff` iterates over the entries added, removed or changed between two maps.
+//!   - `inner_join`, `left_join` and `outer_join` join the entries of two maps by key, and `merge_join` does so without lookups for sorted maps.
+//!   - `group_by` groups key-value pairs into a map of collections,
+//!     and `group_by_reserved` reserves the map upfront.
+//!   - `invert` and `invert_multi` build the reverse of a map, where values become keys.
+//!
 //! # Trait aliases
 //!
 //! By enabling the `nightly` you can get access to
@@ -90,29 +125,145 @@
 //! for the standard library collections.
 //! You can disable it by using the `nostd` feature.
 //!
+//! The traits are also forwarded through references and smart pointers:
+//! `&C`, `Rc<C>` and `Arc<C>` implement the read-only traits of `C`,
+//! and `&mut C` and `Box<C>` implement all of them.
+//! `Cow<C>` implements the read-only traits of `C`
+//! and the mutable traits of its owned form, cloned on the first modification.
+//! `RefCell<C>`, `Mutex<C>` and `RwLock<C>` borrow or lock the collection on queries,
+//! returning references that hold the borrow or lock guard (`CellRef` and `LockRef`).
+//!
 //! # Foreign implementations
 //!
 //! In addition to the standard library,
 //! traits are implemented for
-//! some popular crates if you enable the feature of the same name.
+//! some popular crates if you enable the feature of the same name,
+//! or all of them at once with the `full` feature.
 //! Here are the supported crates:
 //!
 //!   - [`slab`](https://crates.io/crates/slab) providing the `Slab` collection.
 //!   - [`smallvec`](https://crates.io/crates/smallvec) providing the `SmallVec` collection.
 //!   - [`serde_json`](https://crates.io/crates/serde_json) providing the `Map<String, Value>` collection for JSON objects.
 //!   - [`ijson`](https://crates.io/crates/ijson) providing the `IObject` and `IArray` collections.
-#![feature(generic_associated_types)]
-#![cfg_attr(feature = "nightly", feature(trait_alias))]
+//!   - [`bimap`](https://crates.io/crates/bimap) providing the `BiHashMap` and `BiBTreeMap` bidirectional maps.
+//!   - [`rayon`](https://crates.io/crates/rayon) providing parallel iteration (`ParIter`, `ParMapIter`, `IntoParIter`...) and extension (`ParExtend`) of the standard library collections.
+//!   - [`either`](https://crates.io/crates/either) providing the `Either` type, dispatching each trait to the current side.
+//!   - [`indexmap`](https://crates.io/crates/indexmap) providing the `IndexMap` and `IndexSet` insertion-ordered collections.
+//!   - [`im`](https://crates.io/crates/im) and [`im-rc`](https://crates.io/crates/im-rc) providing the `HashMap`, `OrdMap` and `Vector` persistent collections.
+//!   - [`rpds`](https://crates.io/crates/rpds) providing the `HashTrieMap`, `RedBlackTreeMap` and `Vector` persistent collections.
+//!   - [`sled`](https://crates.io/crates/sled) providing the `Tree` byte-keyed ordered map (sled errors cause a panic).
+//!   - [`heed`](https://crates.io/crates/heed) providing the `HeedRead` and `HeedWrite` views of LMDB databases through transactions (heed errors cause a panic).
+//!
+//! # Asynchronous collections
+//!
+//! By enabling the `async` feature you get access to the `AsyncLen`, `AsyncGet`,
+//! `AsyncInsert` and `AsyncRemove` traits, describing disk-backed
+//! or remote stores with the same structure as the synchronous traits.
+//! Their futures are `Send`, and the `Blocking` adapter implements them
+//! for any synchronous collection.
+//! They are implemented for the `tokio::sync::RwLock` and `tokio::sync::Mutex` locks
+//! when the `tokio` feature is enabled.
+//! The `redis` feature also provides the `RedisHashMap` adapter,
+//! storing JSON-encoded values in a [Redis](https://redis.io) hash.
+//!
+//! # Serde
+//!
+//! By enabling the `serde` feature you get access to adapters
+//! bridging [`serde`](https://crates.io/crates/serde) and the traits of this crate,
+//! such as `SerializeCollection` which serializes any iterable collection or map
+//! without requiring it to implement `Serialize`,
+//! and `DeserializeInto` which deserializes into any insertable collection.
+//!
+//! # Property testing
+//!
+//! By enabling the `proptest` feature you get access to the `seq_strategy`
+//! and `map_strategy` [`proptest`](https://crates.io/crates/proptest) strategies,
+//! generating arbitrary contents for any `Default + PushBack` collection
+//! or `Default + MapInsert` map, so that generic algorithms can be
+//! property-tested against several backends with the same test body.
+//!
+//! # Derive
+//!
+//! By enabling the `derive` feature you get access to derive macros
+//! implementing the traits of this crate for newtype wrappers,
+//! by forwarding each trait to the wrapped field:
+//! ```ignore
+//! #[derive(Collection, CollectionRef, CollectionMut, Len, Get, GetMut, MapInsert, Remove)]
+//! struct Registry<V>(HashMap<String, V>);
+//! ```
+//! Structures with more than one field must mark the wrapped field with `#[delegate]`.
+//!
+//! They can also be derived for enums whose variants each wrap a different collection,
+//! dispatching each method to the current variant.
+#![cfg_attr(
+	feature = "nightly",
+	feature(trait_alias, btree_cursors, hash_set_entry, btree_set_entry)
+)]
 
 mod impls;
 mod macros;
 
+mod adapters;
+pub use adapters::*;
+
+mod algorithms;
+pub use algorithms::*;
+
+mod format;
+pub use format::*;
+
+mod pool;
+pub use pool::*;
+
+pub mod map;
+pub mod prelude;
+pub mod seq;
+pub mod set;
+
+#[cfg(feature = "testing")]
+pub mod testsuite;
+
+#[cfg(feature = "derive")]
+pub use cc_traits_derive::*;
+
+#[cfg(feature = "either")]
+pub use impls::EitherMapIter;
+
+#[cfg(feature = "sled")]
+pub use impls::{SledItemMut, SledIter, SledRef};
+
+#[cfg(not(feature = "nostd"))]
+pub use impls::{CellRef, CharIter, CharRef, LockRef};
+
+#[cfg(feature = "heed")]
+pub use impls::{HeedIter, HeedRead, HeedRef, HeedWrite};
+
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "serde")]
+pub use self::serde::*;
+
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "redis")]
+pub use self::redis::*;
+
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "proptest")]
+pub use self::proptest::*;
+
 #[cfg(feature = "nightly")]
 mod alias;
 #[cfg(feature = "nightly")]
 pub use alias::*;
 
-use std::ops::{Deref, DerefMut};
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::{
+	cmp::Ordering,
+	ops::{AddAssign, Deref, DerefMut, RangeBounds, SubAssign},
+};
 
 /// Abstract collection.
 pub trait Collection {
@@ -198,6 +349,26 @@ pub trait Len {
 	}
 }
 
+/// Sized collection whose length is reported as a `u64`.
+///
+/// This is useful for collections that may hold more than `usize::MAX` elements
+/// on 32-bit targets, such as database-backed maps.
+pub trait Len64 {
+	/// Returns the number of elements in the collection.
+	fn len64(&self) -> u64;
+}
+
+/// Collection able to cheaply estimate its length.
+///
+/// This is useful for backends where [`Len::len`] is not constant-time.
+pub trait LenHint {
+	/// Returns the bounds on the number of elements in the collection.
+	///
+	/// Just like [`Iterator::size_hint`], this returns a lower bound
+	/// and an optional upper bound (`None` when unknown).
+	fn len_hint(&self) -> (usize, Option<usize>);
+}
+
 /// Collection with known capacity.
 pub trait Capacity {
 	/// Returns the current capacity of the collection.
@@ -212,6 +383,19 @@ pub trait Reserve {
 	fn reserve(&mut self, additional: usize);
 }
 
+/// Collection able to report an estimation of its heap usage.
+///
+/// Custom backends should implement this trait to be usable
+/// by code reporting memory usage.
+pub trait MemSize {
+	/// Returns the approximate number of bytes allocated on the heap by the collection.
+	///
+	/// This only accounts for the storage of the elements themselves
+	/// (and the bookkeeping of the collection),
+	/// not for the heap memory owned by each element.
+	fn mem_size(&self) -> usize;
+}
+
 /// Queryable collection.
 pub trait Get<T>: CollectionRef {
 	/// Returns a reference to the item stored behind the given key (if any).
@@ -223,12 +407,45 @@ pub trait Get<T>: CollectionRef {
 	}
 }
 
+/// Collection that can be searched for a given value.
+///
+/// Contrarily to [`Get::contains`], the value is not a key but the item itself,
+/// which makes this trait suitable for unkeyed sequences.
+/// The method is named differently so that both traits can be imported together.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Contains, Get};
+///
+/// let vec = vec![1, 2, 3];
+/// assert!(Get::contains(&vec, 2));
+/// assert!(vec.contains_value(&3));
+/// assert!(!vec.contains_value(&4));
+/// ```
+pub trait Contains<T>: Collection {
+	/// Checks if the collection contains the given value.
+	fn contains_value(&self, value: T) -> bool;
+}
+
 /// Mutably queryable collection.
 pub trait GetMut<T>: Get<T> + CollectionMut {
 	/// Returns a mutable reference to the item stored behind the given key (if any).
 	fn get_mut(&mut self, key: T) -> Option<Self::ItemMut<'_>>;
 }
 
+/// Collection providing mutable access to multiple distinct items at once.
+pub trait GetManyMut<T>: CollectionMut {
+	/// Returns mutable references to the items stored behind each of the given keys.
+	///
+	/// Returns `None` if any of the keys is missing.
+	///
+	/// # Panics
+	///
+	/// Panics if any of the keys are overlapping.
+	fn get_many_mut<const N: usize>(&mut self, keys: [T; N]) -> Option<[Self::ItemMut<'_>; N]>;
+}
+
 /// Queryable map.
 pub trait GetKeyValue<T>: CollectionRef + KeyedRef {
 	/// Returns the key-value pair matching the given `key`.
@@ -241,6 +458,63 @@ pub trait GetKeyValueMut<T>: CollectionMut + KeyedRef {
 	fn get_key_value_mut(&mut self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemMut<'_>)>;
 }
 
+/// Map with a stable ordering, where entries can be accessed by index.
+pub trait GetIndex: CollectionRef + KeyedRef {
+	/// Returns the key-value pair stored at the given `index` (if any).
+	fn get_index(&self, index: usize) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)>;
+}
+
+/// Map with a stable ordering, where entries can be mutably accessed by index.
+pub trait GetIndexMut: CollectionMut + KeyedRef {
+	/// Returns the key-value pair stored at the given `index` (if any),
+	/// with a mutable reference to the value.
+	fn get_index_mut(&mut self, index: usize) -> Option<(Self::KeyRef<'_>, Self::ItemMut<'_>)>;
+}
+
+/// Map with a stable ordering, where the index of a given key can be queried.
+pub trait GetIndexOf<T>: Keyed {
+	/// Returns the index of the entry matching the given `key` (if any).
+	fn get_index_of(&self, key: T) -> Option<usize>;
+}
+
+/// Map with a stable ordering, whose entries can be reordered.
+pub trait OrderedMapOps: Collection + Keyed {
+	/// Moves the entry at index `from` to index `to`,
+	/// shifting all the entries in between.
+	///
+	/// Panics if `from` or `to` is out of bounds.
+	fn move_index(&mut self, from: usize, to: usize);
+
+	/// Swaps the entries at indices `a` and `b`.
+	///
+	/// Panics if `a` or `b` is out of bounds.
+	fn swap_indices(&mut self, a: usize, b: usize);
+
+	/// Sorts the entries of the map by key.
+	fn sort_keys(&mut self)
+	where
+		Self::Key: Ord,
+	{
+		self.sort_by(|a, _, b, _| a.cmp(b))
+	}
+
+	/// Sorts the entries of the map with the given comparator function.
+	///
+	/// The sort is stable.
+	fn sort_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&Self::Key, &Self::Item, &Self::Key, &Self::Item) -> Ordering;
+}
+
+/// Ordered map that can be queried for the entries following a given key.
+pub trait BoundQuery<T>: CollectionRef + KeyedRef {
+	/// Returns the first key-value pair whose key is greater or equal to the given `key`.
+	fn lower_bound(&self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)>;
+
+	/// Returns the first key-value pair whose key is strictly greater than the given `key`.
+	fn upper_bound(&self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)>;
+}
+
 /// Collection exposing a reference to its front element.
 pub trait Front: CollectionRef {
 	/// Get a reference to the front element of the collection.
@@ -310,6 +584,23 @@ pub trait Insert: Collection {
 	fn insert(&mut self, element: Self::Item) -> Self::Output;
 }
 
+/// Mutable sequence where new elements can be inserted at a given position.
+pub trait InsertAt: Collection {
+	/// Insert a new element at position `index`, shifting all the elements after it.
+	///
+	/// Returns the element back if `index` is greater than the length of the collection.
+	fn insert_at(&mut self, index: usize, element: Self::Item) -> Result<(), Self::Item>;
+}
+
+/// Mutable keyed collection generating the key of each inserted element,
+/// such as slabs or arenas.
+///
+/// This is automatically implemented for every [`Keyed`] collection implementing [`Insert`]
+/// with the key type as output.
+pub trait ArenaInsert: Keyed + Insert<Output = <Self as Keyed>::Key> {}
+
+impl<T: Keyed + Insert<Output = <T as Keyed>::Key>> ArenaInsert for T {}
+
 /// Mutable map where new new key-value pairs can be inserted.
 pub trait MapInsert<K>: Collection {
 	/// The output of the insertion function.
@@ -319,6 +610,343 @@ pub trait MapInsert<K>: Collection {
 	fn insert(&mut self, key: K, value: Self::Item) -> Self::Output;
 }
 
+/// Mutable set where new elements can be inserted.
+///
+/// This is automatically implemented for every collection implementing [`Insert`]
+/// with `bool` as output, indicating if the element was not already present in the set.
+pub trait SetInsert: Insert<Output = bool> {}
+
+impl<T: Insert<Output = bool>> SetInsert for T {}
+
+/// Mutable set where elements can be replaced.
+pub trait Replace: Collection {
+	/// Insert the given element in the set, replacing and returning the equal element
+	/// it may already contain.
+	fn replace(&mut self, element: Self::Item) -> Option<Self::Item>;
+}
+
+/// Mutable set where stored elements can be taken out.
+pub trait Take<T>: Collection {
+	/// Remove and return the element equal to the given `value` (if any).
+	fn take(&mut self, value: T) -> Option<Self::Item>;
+}
+
+/// Set providing an entry-like API to insert elements only when missing.
+pub trait SetEntryApi: CollectionRef {
+	/// Insert the given element in the set if it is not already present,
+	/// and returns a reference to the element stored in the set.
+	fn get_or_insert(&mut self, element: Self::Item) -> Self::ItemRef<'_>;
+}
+
+/// Set providing an entry-like API to build and insert elements only when missing.
+pub trait SetEntryApiWith<T>: SetEntryApi {
+	/// Insert the element built by `f` from the given `value` if no equal element is already present,
+	/// and returns a reference to the element stored in the set.
+	fn get_or_insert_with<F>(&mut self, value: T, f: F) -> Self::ItemRef<'_>
+	where
+		F: FnOnce(T) -> Self::Item;
+}
+
+/// Frequency map counting the occurrences of each key.
+///
+/// This is automatically implemented for every map with numeric values
+/// supporting lookup, insertion and removal.
+/// A key is absent from the map exactly when its count is zero.
+///
+/// ```
+/// use cc_traits::Counter;
+/// use std::collections::HashMap;
+///
+/// let mut words: HashMap<&str, u32> = HashMap::new();
+/// for word in "a b a".split(' ') {
+///   words.increment(word);
+/// }
+///
+/// assert_eq!(words.count(&"a"), 2);
+/// assert_eq!(words.decrement(&"b"), 0);
+/// assert_eq!(words.count(&"b"), 0);
+/// assert!(!words.contains_key("b"));
+/// ```
+pub trait Counter: Keyed {
+	/// Increments the count of the given key, and returns the new count.
+	fn increment(&mut self, key: Self::Key) -> Self::Item;
+
+	/// Decrements the count of the given key, and returns the new count.
+	///
+	/// The key is removed from the map when its count falls to zero.
+	/// Nothing happens if the key is not in the map.
+	/// A count of zero stored in the map (for instance by a direct insertion)
+	/// is not decremented: the key is removed and zero is returned.
+	fn decrement(&mut self, key: &Self::Key) -> Self::Item;
+
+	/// Returns the count of the given key.
+	fn count(&self, key: &Self::Key) -> Self::Item;
+}
+
+impl<C> Counter for C
+where
+	C: Keyed + for<'a> GetMut<&'a C::Key> + for<'a> Remove<&'a C::Key> + MapInsert<C::Key>,
+	C::Item: Copy + Default + PartialEq + AddAssign + SubAssign + From<u8>,
+{
+	fn increment(&mut self, key: Self::Key) -> Self::Item {
+		if let Some(mut count) = self.get_mut(&key) {
+			*count += 1.into();
+			return *count;
+		}
+
+		self.insert(key, 1.into());
+		1.into()
+	}
+
+	fn decrement(&mut self, key: &Self::Key) -> Self::Item {
+		let count = match self.get_mut(key) {
+			Some(mut count) => {
+				if *count != Self::Item::default() {
+					*count -= 1.into();
+				}
+
+				*count
+			}
+			None => return Self::Item::default(),
+		};
+
+		if count == Self::Item::default() {
+			self.remove(key);
+		}
+
+		count
+	}
+
+	fn count(&self, key: &Self::Key) -> Self::Item {
+		self.get(key).map(|count| *count).unwrap_or_default()
+	}
+}
+
+/// Error returned by [`TryInsert::try_insert`] when the key is already in the map.
+pub struct OccupiedError<'a, C: 'a + ?Sized + CollectionMut> {
+	/// Mutable reference to the value already stored behind the key.
+	pub existing: C::ItemMut<'a>,
+
+	/// The value that was not inserted.
+	pub value: C::Item,
+}
+
+/// Mutable map where new key-value pairs can be inserted only if the key is not already present.
+pub trait TryInsert<K>: CollectionMut {
+	/// Insert a new key-value pair in the collection if the key is vacant,
+	/// and returns a mutable reference to the inserted value.
+	///
+	/// If the key is occupied, nothing is inserted and an error containing
+	/// the existing value and the rejected value is returned.
+	fn try_insert(
+		&mut self,
+		key: K,
+		value: Self::Item,
+	) -> Result<Self::ItemMut<'_>, OccupiedError<'_, Self>>;
+}
+
+/// Mutable map where the value behind a key can be inserted, modified or removed
+/// in a single logical operation.
+///
+/// Implementations rely on the native entry API of the map when there is one,
+/// and preserve the position of the entry in insertion-ordered maps.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::Update;
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.update("a", |v| Some(v.unwrap_or(0) + 1));
+/// map.update("a", |v| Some(v.unwrap_or(0) + 1));
+/// assert_eq!(map["a"], 2);
+///
+/// map.update("a", |_| None);
+/// assert!(map.is_empty());
+/// ```
+pub trait Update: Keyed {
+	/// Updates the value behind the given `key` using `f`.
+	///
+	/// The function is given the current value (if any).
+	/// If it returns `Some(value)` then `value` is stored behind the key,
+	/// otherwise the key is removed from the map.
+	fn update<F>(&mut self, key: Self::Key, f: F)
+	where
+		F: FnOnce(Option<Self::Item>) -> Option<Self::Item>;
+}
+
+/// Mutable map where a value can be accessed, inserting it first if the key is vacant.
+///
+/// This is automatically implemented for every map supporting mutable access and insertion,
+/// with a lookup-then-insert default. The key is cloned when it is vacant.
+pub trait GetOrInsertExt: CollectionMut + Keyed {
+	/// Returns a mutable reference to the value behind the given `key`,
+	/// inserting `value` first if the key is vacant.
+	fn get_or_insert(&mut self, key: Self::Key, value: Self::Item) -> Self::ItemMut<'_> {
+		self.get_or_insert_with(key, || value)
+	}
+
+	/// Returns a mutable reference to the value behind the given `key`,
+	/// inserting the result of `f` first if the key is vacant.
+	fn get_or_insert_with<F>(&mut self, key: Self::Key, f: F) -> Self::ItemMut<'_>
+	where
+		F: FnOnce() -> Self::Item;
+
+	/// Returns a mutable reference to the value behind the given `key`,
+	/// inserting the default value first if the key is vacant.
+	fn get_or_insert_default(&mut self, key: Self::Key) -> Self::ItemMut<'_>
+	where
+		Self::Item: Default,
+	{
+		self.get_or_insert_with(key, Default::default)
+	}
+}
+
+impl<C> GetOrInsertExt for C
+where
+	C: Keyed + for<'a> GetMut<&'a C::Key> + MapInsert<C::Key>,
+	C::Key: Clone,
+{
+	fn get_or_insert_with<F>(&mut self, key: Self::Key, f: F) -> Self::ItemMut<'_>
+	where
+		F: FnOnce() -> Self::Item,
+	{
+		if !self.contains(&key) {
+			self.insert(key.clone(), f());
+		}
+
+		self.get_mut(&key).expect("key was just inserted")
+	}
+}
+
+/// Mutable map into which other key-value pairs can be merged.
+pub trait MergeFrom: Keyed {
+	/// Insert all the key-value pairs of `other` into the map.
+	///
+	/// When a key is already present in the map, `resolve` is called with a mutable reference
+	/// to the current value and the value coming from `other`.
+	fn merge_from<O, F>(&mut self, other: O, resolve: F)
+	where
+		O: IntoIterator<Item = (Self::Key, Self::Item)>,
+		F: FnMut(&mut Self::Item, Self::Item);
+}
+
+/// Sequence that can be built from the elements of another collection.
+///
+/// This is automatically implemented for every `Default + PushBack` sequence,
+/// from every collection iterating over its elements by value.
+pub trait FromCollection<C>: Sized {
+	/// Builds the sequence by pushing back every element of `collection`.
+	fn from_collection(collection: C) -> Self;
+}
+
+impl<C, T> FromCollection<C> for T
+where
+	C: IntoIterator<Item = T::Item>,
+	T: Default + PushBack,
+{
+	fn from_collection(collection: C) -> Self {
+		let mut result = Self::default();
+		for element in collection {
+			result.push_back(element);
+		}
+		result
+	}
+}
+
+/// Map that can be built from the key-value pairs of another map.
+///
+/// This is automatically implemented for every `Default + MapInsert` map,
+/// from every map iterating over its key-value pairs by value.
+pub trait FromMap<C>: Sized {
+	/// Builds the map by inserting every key-value pair of `map`.
+	fn from_map(map: C) -> Self;
+}
+
+impl<C, K, T> FromMap<C> for T
+where
+	C: IntoIterator<Item = (K, T::Item)>,
+	T: Default + MapInsert<K>,
+{
+	fn from_map(map: C) -> Self {
+		let mut result = Self::default();
+		for (key, value) in map {
+			result.insert(key, value);
+		}
+		result
+	}
+}
+
+/// Conversion of a collection into another collection type.
+///
+/// This is automatically implemented for every collection iterating over its items by value.
+///
+/// The `convert_into` and `convert_map_into` conversions do not reserve any capacity upfront:
+/// use `convert_into_reserved` and `convert_map_into_reserved`
+/// when the target collection supports it.
+///
+/// ```
+/// use cc_traits::ConvertInto;
+/// use std::collections::{BTreeMap, HashMap, VecDeque};
+///
+/// let map = HashMap::from([("a", 1), ("b", 2)]);
+/// let ordered: BTreeMap<&str, i32> = map.convert_map_into();
+/// assert_eq!(ordered.into_iter().collect::<std::vec::Vec<_>>(), [("a", 1), ("b", 2)]);
+///
+/// let map = BTreeMap::from([("a", 1), ("b", 2)]);
+/// let hashed: HashMap<&str, i32> = map.convert_map_into_reserved();
+/// assert!(hashed.capacity() >= 2);
+///
+/// let deque: VecDeque<i32> = vec![1, 2, 3].convert_into_reserved();
+/// assert_eq!(deque, [1, 2, 3]);
+///
+/// let items: std::vec::Vec<i32> = deque.convert_into();
+/// assert_eq!(items, [1, 2, 3]);
+/// ```
+pub trait ConvertInto: IntoIterator + Sized {
+	/// Converts this collection into a `T` sequence.
+	fn convert_into<T: FromCollection<Self>>(self) -> T {
+		T::from_collection(self)
+	}
+
+	/// Converts this map into a `T` map.
+	fn convert_map_into<T: FromMap<Self>>(self) -> T {
+		T::from_map(self)
+	}
+
+	/// Converts this collection into a `T` sequence,
+	/// allocated upfront with the minimum number of items announced by the iterator as capacity.
+	fn convert_into_reserved<T>(self) -> T
+	where
+		T: WithCapacity + PushBack<Item = Self::Item>,
+	{
+		let items = self.into_iter();
+		let mut result = T::with_capacity(items.size_hint().0);
+		for element in items {
+			result.push_back(element);
+		}
+		result
+	}
+
+	/// Converts this map into a `T` map,
+	/// reserved upfront for the length of this map.
+	fn convert_map_into_reserved<T, K>(self) -> T
+	where
+		Self: Len + IntoIterator<Item = (K, T::Item)>,
+		T: Default + Reserve + MapInsert<K>,
+	{
+		let mut result = T::default();
+		result.reserve(self.len());
+		for (key, value) in self {
+			result.insert(key, value);
+		}
+		result
+	}
+}
+
+impl<C: IntoIterator> ConvertInto for C {}
+
 /// Mutable collection where new elements can be pushed on the front.
 pub trait PushFront: Collection {
 	/// The output of the push function.
@@ -337,12 +965,74 @@ pub trait PushBack: Collection {
 	fn push_back(&mut self, element: Self::Item) -> Self::Output;
 }
 
+/// Text buffer that can be extended with a borrowed slice of text.
+///
+/// This is implemented by `String` (with `str`), `OsString` (with `OsStr`)
+/// and `PathBuf` (with `Path`, which appends a path component).
+///
+/// ```
+/// use cc_traits::{Clear, PushBack, PushStr};
+///
+/// fn greet<B: Clear + PushStr<str> + PushBack<Item = char>>(buffer: &mut B) {
+///   buffer.clear();
+///   buffer.push_str("hello");
+///   buffer.push_back('!');
+/// }
+///
+/// let mut s = String::from("bye");
+/// greet(&mut s);
+/// assert_eq!(s, "hello!");
+/// ```
+pub trait PushStr<S: ?Sized> {
+	/// Appends the given slice to the back of the buffer.
+	fn push_str(&mut self, s: &S);
+}
+
+/// Mutable sequence that can be extended by cloning the elements of a slice.
+pub trait ExtendFromSlice: Collection {
+	/// Clones and appends all the elements of `other` to the back of the sequence.
+	fn extend_from_slice(&mut self, other: &[Self::Item])
+	where
+		Self::Item: Clone;
+}
+
+/// Mutable collection into which all the elements of another collection of the same type can be moved.
+pub trait Append: Collection {
+	/// Moves all the elements of `other` into the collection, leaving `other` empty.
+	fn append(&mut self, other: &mut Self);
+}
+
+/// Mutable sequence that can be extended by cloning a range of its own elements.
+pub trait ExtendFromWithin<R>: Collection {
+	/// Clones and appends the elements in the given range to the back of the sequence.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is out of bounds.
+	fn extend_from_within(&mut self, src: R)
+	where
+		Self::Item: Clone;
+}
+
 /// Mutable collection where elements can be removed from.
 pub trait Remove<T>: Collection {
 	/// Remove the element identified by the given `key`.
 	fn remove(&mut self, key: T) -> Option<Self::Item>;
 }
 
+/// Mutable map where entries can be removed from, returning the owned key along with the value.
+pub trait RemoveEntry<T>: Keyed {
+	/// Remove the entry identified by the given `key` and return its key and value (if any).
+	fn remove_entry(&mut self, key: T) -> Option<(Self::Key, Self::Item)>;
+}
+
+/// Mutable collection where elements can be removed from in constant time,
+/// without preserving the ordering of the remaining elements.
+pub trait SwapRemove<T>: Collection {
+	/// Remove the element identified by the given `key`, replacing it with the last element of the collection.
+	fn swap_remove(&mut self, key: T) -> Option<Self::Item>;
+}
+
 /// Mutable collection where elements can be popped from the front.
 pub trait PopFront: Collection {
 	/// Remove the front element of the collection and return it (if any).
@@ -355,46 +1045,1022 @@ pub trait PopBack: Collection {
 	fn pop_back(&mut self) -> Option<Self::Item>;
 }
 
+/// Sequence that can be shortened.
+pub trait Truncate: Collection {
+	/// Shortens the collection, keeping the first `len` elements and dropping the rest.
+	///
+	/// Has no effect if `len` is greater than the current length of the collection.
+	fn truncate(&mut self, len: usize);
+}
+
+/// Sequence that can be resized in place.
+pub trait Resize: Collection {
+	/// Resizes the collection so that its length is equal to `new_len`.
+	///
+	/// If the collection grows, the new slots are filled with clones of `value`.
+	fn resize(&mut self, new_len: usize, value: Self::Item)
+	where
+		Self::Item: Clone;
+
+	/// Resizes the collection so that its length is equal to `new_len`.
+	///
+	/// If the collection grows, the new slots are filled with the values returned by `f`.
+	fn resize_with<F>(&mut self, new_len: usize, f: F)
+	where
+		F: FnMut() -> Self::Item;
+}
+
+/// Mutable set from which an arbitrary element can be removed.
+pub trait SetPop: Collection {
+	/// Remove some element of the set and return it (if any).
+	///
+	/// Which element is removed is left to the implementation.
+	fn pop(&mut self) -> Option<Self::Item>;
+}
+
+/// Priority queue exposing a reference to its greatest element.
+pub trait Peek: CollectionRef {
+	/// Get a reference to the greatest element of the queue.
+	fn peek(&self) -> Option<Self::ItemRef<'_>>;
+}
+
+/// Priority queue where new elements can be pushed.
+pub trait PriorityPush: Collection {
+	/// The output of the push function.
+	type Output;
+
+	/// Push a new element in the queue.
+	fn push(&mut self, element: Self::Item) -> Self::Output;
+}
+
+/// Priority queue where elements can be popped by order of priority.
+pub trait PriorityPop: Collection {
+	/// Remove the greatest element of the queue and return it (if any).
+	fn pop(&mut self) -> Option<Self::Item>;
+}
+
+/// Priority queue supporting a combined push and pop operation.
+pub trait PushPop: PriorityPush + PriorityPop {
+	/// Push a new element in the queue, then remove the greatest element of the queue and return it.
+	///
+	/// Implementations may provide a faster version than the consecutive push and pop operations.
+	fn push_pop(&mut self, element: Self::Item) -> Self::Item {
+		self.push(element);
+		self.pop().unwrap()
+	}
+}
+
+/// Sequence from which a range of elements can be removed.
+pub trait DrainRange<R>: Collection {
+	/// Draining iterator type.
+	type Drain<'a>: Iterator<Item = Self::Item>
+	where
+		Self: 'a;
+
+	/// Remove the elements in the given `range` from the sequence and return them as an iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the range is out of bounds.
+	fn drain_range(&mut self, range: R) -> Self::Drain<'_>;
+}
+
+/// Ordered map from which a range of keys can be removed.
+pub trait MapDrainRange<R>: Keyed {
+	/// Draining iterator type.
+	type Drain<'a>: Iterator<Item = (Self::Key, Self::Item)>
+	where
+		Self: 'a;
+
+	/// Remove the entries whose key is in the given `range` and return them as an iterator.
+	///
+	/// An empty or reversed range removes nothing.
+	fn drain_range(&mut self, range: R) -> Self::Drain<'_>;
+}
+
 /// Clearable collection.
 pub trait Clear {
 	/// Remove all the elements of the collection.
 	fn clear(&mut self);
 }
 
-/// Iterable collection.
-pub trait Iter: CollectionRef {
-	/// Iterator type.
-	type Iter<'a>: Iterator<Item = Self::ItemRef<'a>>
+/// Collection that can be cleared while keeping its allocated memory,
+/// so that it can be reused without allocating again.
+pub trait Recycle {
+	/// Remove all the elements of the collection, keeping its allocated memory.
+	fn recycle(&mut self);
+}
+
+/// Sorted sequence that can be binary searched.
+pub trait BinarySearch: Collection {
+	/// Binary searches the sequence for the given `value`.
+	///
+	/// Returns `Ok` with the index of a matching element if any,
+	/// or `Err` with the index where the value could be inserted while maintaining the sorted order.
+	fn binary_search(&self, value: &Self::Item) -> Result<usize, usize>
 	where
-		Self: 'a;
+		Self::Item: Ord,
+	{
+		self.binary_search_by(|item| item.cmp(value))
+	}
 
-	/// Create an iterator over the items of the collection.
-	fn iter(&self) -> Self::Iter<'_>;
-}
+	/// Binary searches the sequence with a comparator function.
+	///
+	/// See [`BinarySearch::binary_search`] for more information.
+	fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+	where
+		F: FnMut(&Self::Item) -> Ordering;
 
-/// Mutably iterable collection.
-pub trait IterMut: CollectionMut {
-	/// Iterator type.
-	type IterMut<'a>: Iterator<Item = Self::ItemMut<'a>>
+	/// Binary searches the sequence with a key extraction function.
+	///
+	/// See [`BinarySearch::binary_search`] for more information.
+	fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
 	where
-		Self: 'a;
+		B: Ord,
+		F: FnMut(&Self::Item) -> B,
+	{
+		self.binary_search_by(|item| f(item).cmp(b))
+	}
 
-	/// Create an iterator over the mutable items of the collection.
-	fn iter_mut(&mut self) -> Self::IterMut<'_>;
+	/// Returns the index of the partition point according to the given predicate
+	/// (the index of the first element of the second partition).
+	///
+	/// The sequence is assumed to be partitioned according to the predicate.
+	fn partition_point<P>(&self, mut pred: P) -> usize
+	where
+		P: FnMut(&Self::Item) -> bool,
+	{
+		self.binary_search_by(|item| {
+			if pred(item) {
+				Ordering::Less
+			} else {
+				Ordering::Greater
+			}
+		})
+		.unwrap_or_else(|i| i)
+	}
 }
 
-pub trait MapIter: KeyedRef + CollectionRef {
-	type Iter<'a>: Iterator<Item = (Self::KeyRef<'a>, Self::ItemRef<'a>)>
+/// Sorted sequence where new elements can be inserted while preserving the ordering.
+///
+/// This is automatically implemented for every sequence implementing [`BinarySearch`] and [`InsertAt`].
+pub trait InsertSorted: Collection {
+	/// Insert a new element in the sorted sequence, after any element equal to it.
+	///
+	/// Returns the index of the inserted element.
+	fn insert_sorted(&mut self, element: Self::Item) -> usize
 	where
-		Self: 'a;
+		Self::Item: Ord,
+	{
+		self.insert_sorted_by(element, Ord::cmp)
+	}
 
-	fn iter(&self) -> Self::Iter<'_>;
+	/// Insert a new element in a sequence sorted with the given comparator function,
+	/// after any element equal to it.
+	///
+	/// Returns the index of the inserted element.
+	fn insert_sorted_by<F>(&mut self, element: Self::Item, f: F) -> usize
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering;
 }
 
-pub trait MapIterMut: KeyedRef + CollectionMut {
-	type IterMut<'a>: Iterator<Item = (Self::KeyRef<'a>, Self::ItemMut<'a>)>
+impl<T: BinarySearch + InsertAt> InsertSorted for T {
+	fn insert_sorted_by<F>(&mut self, element: Self::Item, mut f: F) -> usize
 	where
-		Self: 'a;
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+	{
+		let index = self.partition_point(|item| f(item, &element) != Ordering::Greater);
+		if self.insert_at(index, element).is_err() {
+			unreachable!("partition point is out of bounds")
+		}
 
-	fn iter_mut(&mut self) -> Self::IterMut<'_>;
+		index
+	}
 }
+
+/// Sequence that can be sorted in place, preserving the order of equal elements.
+pub trait Sort: Collection {
+	/// Sorts the sequence.
+	fn sort(&mut self)
+	where
+		Self::Item: Ord,
+	{
+		self.sort_by(Ord::cmp)
+	}
+
+	/// Sorts the sequence with a comparator function.
+	fn sort_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+
+	/// Sorts the sequence with a key extraction function.
+	fn sort_by_key<K, F>(&mut self, mut f: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K,
+	{
+		self.sort_by(|a, b| f(a).cmp(&f(b)))
+	}
+}
+
+/// Sequence that can be sorted in place, without preserving the order of equal elements.
+pub trait SortUnstable: Collection {
+	/// Sorts the sequence.
+	fn sort_unstable(&mut self)
+	where
+		Self::Item: Ord,
+	{
+		self.sort_unstable_by(Ord::cmp)
+	}
+
+	/// Sorts the sequence with a comparator function.
+	fn sort_unstable_by<F>(&mut self, f: F)
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+
+	/// Sorts the sequence with a key extraction function.
+	fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K,
+	{
+		self.sort_unstable_by(|a, b| f(a).cmp(&f(b)))
+	}
+}
+
+/// Sequence from which consecutive repeated elements can be removed.
+pub trait Dedup: Collection {
+	/// Removes consecutive repeated elements.
+	fn dedup(&mut self)
+	where
+		Self::Item: PartialEq,
+	{
+		self.dedup_by(|a, b| a == b)
+	}
+
+	/// Removes consecutive elements satisfying the given equality relation.
+	///
+	/// The relation is given the current element first and the preceding retained element second.
+	fn dedup_by<F>(&mut self, same_bucket: F)
+	where
+		F: FnMut(&mut Self::Item, &mut Self::Item) -> bool;
+
+	/// Removes consecutive elements resolving to the same key.
+	fn dedup_by_key<K, F>(&mut self, mut key: F)
+	where
+		K: PartialEq,
+		F: FnMut(&mut Self::Item) -> K,
+	{
+		self.dedup_by(|a, b| key(a) == key(b))
+	}
+}
+
+/// Sequence that can be reversed in place.
+pub trait Reverse: Collection {
+	/// Reverses the order of the elements in the sequence.
+	fn reverse(&mut self);
+}
+
+/// Sequence that can be rotated in place.
+pub trait Rotate: Collection {
+	/// Rotates the sequence `mid` places to the left.
+	///
+	/// # Panics
+	///
+	/// Panics if `mid` is greater than the length of the sequence.
+	fn rotate_left(&mut self, mid: usize);
+
+	/// Rotates the sequence `k` places to the right.
+	///
+	/// # Panics
+	///
+	/// Panics if `k` is greater than the length of the sequence.
+	fn rotate_right(&mut self, k: usize);
+}
+
+/// Collection whose elements are stored contiguously in memory.
+pub trait AsSlice: Collection {
+	/// Returns a slice containing all the elements of the collection.
+	fn as_slice(&self) -> &[Self::Item];
+}
+
+/// Mutable collection whose elements are stored contiguously in memory.
+pub trait AsMutSlice: AsSlice {
+	/// Returns a mutable slice containing all the elements of the collection.
+	fn as_mut_slice(&mut self) -> &mut [Self::Item];
+}
+
+/// Collection whose elements can be rearranged to be stored contiguously in memory.
+pub trait MakeContiguous: Collection {
+	/// Rearranges the internal storage of the collection so that its elements are contiguous,
+	/// and returns a mutable slice to them.
+	fn make_contiguous(&mut self) -> &mut [Self::Item];
+}
+
+impl<T: AsMutSlice> MakeContiguous for T {
+	fn make_contiguous(&mut self) -> &mut [Self::Item] {
+		self.as_mut_slice()
+	}
+}
+
+/// Sequence that can be iterated over by chunks.
+pub trait Chunks: Collection {
+	/// Chunks iterator type.
+	type Chunks<'a>: Iterator<Item = &'a [Self::Item]>
+	where
+		Self: 'a;
+
+	/// Create an iterator over `chunk_size` elements of the sequence at a time.
+	///
+	/// The last chunk may be shorter if `chunk_size` does not divide the length of the sequence.
+	///
+	/// # Panics
+	///
+	/// Panics if `chunk_size` is 0.
+	fn chunks(&self, chunk_size: usize) -> Self::Chunks<'_>;
+}
+
+impl<T: AsSlice> Chunks for T {
+	type Chunks<'a>
+		= std::slice::Chunks<'a, T::Item>
+	where
+		Self: 'a;
+
+	fn chunks(&self, chunk_size: usize) -> Self::Chunks<'_> {
+		self.as_slice().chunks(chunk_size)
+	}
+}
+
+/// Sequence that can be mutably iterated over by chunks.
+pub trait ChunksMut: Collection {
+	/// Mutable chunks iterator type.
+	type ChunksMut<'a>: Iterator<Item = &'a mut [Self::Item]>
+	where
+		Self: 'a;
+
+	/// Create an iterator over `chunk_size` mutable elements of the sequence at a time.
+	///
+	/// See [`Chunks::chunks`] for more information.
+	fn chunks_mut(&mut self, chunk_size: usize) -> Self::ChunksMut<'_>;
+}
+
+impl<T: AsMutSlice> ChunksMut for T {
+	type ChunksMut<'a>
+		= std::slice::ChunksMut<'a, T::Item>
+	where
+		Self: 'a;
+
+	fn chunks_mut(&mut self, chunk_size: usize) -> Self::ChunksMut<'_> {
+		self.as_mut_slice().chunks_mut(chunk_size)
+	}
+}
+
+/// Sequence that can be iterated over by overlapping windows.
+pub trait Windows: Collection {
+	/// Windows iterator type.
+	type Windows<'a>: Iterator<Item = &'a [Self::Item]>
+	where
+		Self: 'a;
+
+	/// Create an iterator over all contiguous windows of length `size` of the sequence.
+	///
+	/// # Panics
+	///
+	/// Panics if `size` is 0.
+	fn windows(&self, size: usize) -> Self::Windows<'_>;
+}
+
+impl<T: AsSlice> Windows for T {
+	type Windows<'a>
+		= std::slice::Windows<'a, T::Item>
+	where
+		Self: 'a;
+
+	fn windows(&self, size: usize) -> Self::Windows<'_> {
+		self.as_slice().windows(size)
+	}
+}
+
+/// Sequence that can be split into two disjoint mutable views.
+pub trait SplitAtMut: CollectionMut {
+	/// Type of mutable views over a part of the sequence.
+	type SplitMut<'a>: IntoIterator<Item = Self::ItemMut<'a>>
+	where
+		Self: 'a;
+
+	/// Splits the sequence into two mutable views at the given index.
+	///
+	/// The first view contains the elements `[0, mid)` and the second view the elements `[mid, len)`.
+	///
+	/// # Panics
+	///
+	/// Panics if `mid` is greater than the length of the sequence.
+	fn split_at_mut(&mut self, mid: usize) -> (Self::SplitMut<'_>, Self::SplitMut<'_>);
+}
+
+/// Sequence whose elements can be swapped.
+pub trait Swap: Collection {
+	/// Swaps the elements at indexes `a` and `b`.
+	///
+	/// # Panics
+	///
+	/// Panics if `a` or `b` are out of bounds.
+	fn swap(&mut self, a: usize, b: usize);
+}
+
+/// Sequence whose elements can all be overwritten at once.
+pub trait Fill: Collection {
+	/// Fills the sequence with clones of `value`.
+	fn fill(&mut self, value: Self::Item)
+	where
+		Self::Item: Clone,
+	{
+		self.fill_with(|| value.clone())
+	}
+
+	/// Fills the sequence with the values returned by `f`.
+	fn fill_with<F>(&mut self, f: F)
+	where
+		F: FnMut() -> Self::Item;
+}
+
+/// Set supporting the usual set algebra operations.
+pub trait SetOps: CollectionRef {
+	/// Union iterator type.
+	type Union<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Intersection iterator type.
+	type Intersection<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Difference iterator type.
+	type Difference<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Symmetric difference iterator type.
+	type SymmetricDifference<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the items that are in `self` or `other`, without duplicates.
+	fn union<'a>(&'a self, other: &'a Self) -> Self::Union<'a>;
+
+	/// Create an iterator over the items that are both in `self` and `other`.
+	fn intersection<'a>(&'a self, other: &'a Self) -> Self::Intersection<'a>;
+
+	/// Create an iterator over the items that are in `self` but not in `other`.
+	fn difference<'a>(&'a self, other: &'a Self) -> Self::Difference<'a>;
+
+	/// Create an iterator over the items that are in `self` or in `other`, but not in both.
+	fn symmetric_difference<'a>(&'a self, other: &'a Self) -> Self::SymmetricDifference<'a>;
+}
+
+/// Set that can be compared with other sets.
+pub trait SetCompare: Collection {
+	/// Checks if every item of `self` is also in `other`.
+	fn is_subset(&self, other: &Self) -> bool;
+
+	/// Checks if every item of `other` is also in `self`.
+	fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Checks if `self` and `other` have no item in common.
+	fn is_disjoint(&self, other: &Self) -> bool;
+}
+
+/// Ordered collection whose items within a range can be iterated over.
+pub trait RangeIter<Q: ?Sized>: CollectionRef {
+	/// Range iterator type.
+	type RangeIter<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the items of the collection within the given `range`, in order.
+	///
+	/// ```
+	/// use cc_traits::RangeIter;
+	/// use std::collections::BTreeSet;
+	///
+	/// fn between<S: RangeIter<i32, Item = i32>>(set: &S) -> std::vec::Vec<i32> {
+	///     RangeIter::range(set, 2..5).map(|i| *i).collect()
+	/// }
+	///
+	/// assert_eq!(between(&BTreeSet::from([1, 2, 3, 5, 8])), [2, 3]);
+	/// ```
+	fn range<R: RangeBounds<Q>>(&self, range: R) -> Self::RangeIter<'_>;
+}
+
+/// Iterable collection.
+pub trait Iter: CollectionRef {
+	/// Iterator type.
+	type Iter<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the items of the collection.
+	fn iter(&self) -> Self::Iter<'_>;
+}
+
+/// Mutably iterable collection.
+pub trait IterMut: CollectionMut {
+	/// Iterator type.
+	type IterMut<'a>: Iterator<Item = Self::ItemMut<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the mutable items of the collection.
+	fn iter_mut(&mut self) -> Self::IterMut<'_>;
+}
+
+pub trait MapIter: KeyedRef + CollectionRef {
+	type Iter<'a>: Iterator<Item = (Self::KeyRef<'a>, Self::ItemRef<'a>)>
+	where
+		Self: 'a;
+
+	fn iter(&self) -> Self::Iter<'_>;
+}
+
+pub trait MapIterMut: KeyedRef + CollectionMut {
+	type IterMut<'a>: Iterator<Item = (Self::KeyRef<'a>, Self::ItemMut<'a>)>
+	where
+		Self: 'a;
+
+	fn iter_mut(&mut self) -> Self::IterMut<'_>;
+}
+
+/// Collection that can be iterated in parallel.
+#[cfg(feature = "rayon")]
+pub trait ParIter: CollectionRef {
+	/// Parallel iterator type.
+	type ParIter<'a>: rayon::iter::ParallelIterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create a parallel iterator over the items of the collection.
+	fn par_iter(&self) -> Self::ParIter<'_>;
+}
+
+/// Collection that can be mutably iterated in parallel.
+#[cfg(feature = "rayon")]
+pub trait ParIterMut: CollectionMut {
+	/// Parallel iterator type.
+	type ParIterMut<'a>: rayon::iter::ParallelIterator<Item = Self::ItemMut<'a>>
+	where
+		Self: 'a;
+
+	/// Create a parallel iterator over the mutable items of the collection.
+	fn par_iter_mut(&mut self) -> Self::ParIterMut<'_>;
+}
+
+/// Map that can be iterated in parallel.
+#[cfg(feature = "rayon")]
+pub trait ParMapIter: KeyedRef + CollectionRef {
+	/// Parallel iterator type.
+	type ParIter<'a>: rayon::iter::ParallelIterator<Item = (Self::KeyRef<'a>, Self::ItemRef<'a>)>
+	where
+		Self: 'a;
+
+	/// Create a parallel iterator over the key-value pairs of the map.
+	fn par_iter(&self) -> Self::ParIter<'_>;
+}
+
+/// Collection that can be extended in parallel.
+#[cfg(feature = "rayon")]
+pub trait ParExtend<T: Send> {
+	/// Extend the collection with the items of the given parallel iterator.
+	fn par_extend<I>(&mut self, items: I)
+	where
+		I: rayon::iter::IntoParallelIterator<Item = T>;
+}
+
+/// Collection that can be consumed by a parallel iterator.
+#[cfg(feature = "rayon")]
+pub trait IntoParIter: Collection {
+	/// Owning parallel iterator type.
+	type IntoParIter: rayon::iter::ParallelIterator<Item = Self::Item>;
+
+	/// Create a parallel iterator consuming the items of the collection.
+	fn into_par_iter(self) -> Self::IntoParIter;
+}
+
+/// Map that can be consumed by a parallel iterator.
+#[cfg(feature = "rayon")]
+pub trait IntoParMapIter: Keyed {
+	/// Owning parallel iterator type.
+	type IntoParIter: rayon::iter::ParallelIterator<Item = (Self::Key, Self::Item)>;
+
+	/// Create a parallel iterator consuming the key-value pairs of the map.
+	fn into_par_iter(self) -> Self::IntoParIter;
+}
+
+/// Asynchronous collection.
+///
+/// The asynchronous traits mirror [`Len`], [`Get`], [`MapInsert`] and [`Remove`],
+/// and the [`Blocking`] adapter implements them for any synchronous collection.
+/// Their futures are `Send`, so that generic code can spawn them on a multi-threaded executor.
+///
+/// Asynchronous traits take `&self`, even for mutating operations.
+/// Asynchronous backends (remote stores, locks, connection pools) are shared between tasks
+/// and synchronize their accesses themselves,
+/// so requiring `&mut self` would only force callers to wrap them in yet another lock.
+/// Every operation may fail with the collection's [`AsyncCollection::Error`].
+#[cfg(feature = "async")]
+pub trait AsyncCollection: Collection {
+	/// Error type of the asynchronous operations.
+	///
+	/// In-memory collections use [`std::convert::Infallible`].
+	type Error;
+}
+
+/// Asynchronous sized collection.
+#[cfg(feature = "async")]
+pub trait AsyncLen: AsyncCollection {
+	/// Returns the number of elements in the collection.
+	fn len(&self) -> impl Future<Output = Result<usize, Self::Error>> + Send;
+
+	/// Checks if the collection is empty.
+	fn is_empty(&self) -> impl Future<Output = Result<bool, Self::Error>> + Send {
+		let len = self.len();
+		async move { Ok(len.await? == 0) }
+	}
+}
+
+/// Asynchronously queryable collection.
+#[cfg(feature = "async")]
+pub trait AsyncGet<T>: AsyncCollection {
+	/// Type of references to the items returned by [`AsyncGet::get`].
+	///
+	/// This can be a plain reference, a lock guard, or an owned value wrapper.
+	type Ref<'a>: Deref<Target = Self::Item>
+	where
+		Self: 'a;
+
+	/// Returns a reference to the item stored behind the given key (if any).
+	fn get(
+		&self,
+		key: T,
+	) -> impl Future<Output = Result<Option<Self::Ref<'_>>, Self::Error>> + Send;
+
+	/// Checks if the collection contains an item behind the given key.
+	fn contains(&self, key: T) -> impl Future<Output = Result<bool, Self::Error>> + Send {
+		let get = self.get(key);
+		async move { Ok(get.await?.is_some()) }
+	}
+}
+
+/// Asynchronous map where new key-value pairs can be inserted.
+#[cfg(feature = "async")]
+pub trait AsyncInsert<K>: AsyncCollection {
+	/// The output of the insertion function.
+	type Output;
+
+	/// Insert a new key-value pair in the collection.
+	///
+	/// Contrarily to [`MapInsert::insert`], this takes `&self`
+	/// (see [`AsyncCollection`]).
+	fn insert(
+		&self,
+		key: K,
+		value: Self::Item,
+	) -> impl Future<Output = Result<Self::Output, Self::Error>> + Send;
+}
+
+/// Asynchronous collection where elements can be removed from.
+#[cfg(feature = "async")]
+pub trait AsyncRemove<T>: AsyncCollection {
+	/// Remove the element identified by the given `key`.
+	///
+	/// Contrarily to [`Remove::remove`], this takes `&self`
+	/// (see [`AsyncCollection`]).
+	fn remove(
+		&self,
+		key: T,
+	) -> impl Future<Output = Result<Option<Self::Item>, Self::Error>> + Send;
+}
+
+/// Immutable set data structure.
+///
+/// A set is an unordered collection storing at most one single copy of each element.
+///
+/// This is automatically implemented for every collection implementing the required traits.
+pub trait Set<T>: Collection<Item = T> + Len + for<'a> Get<&'a T> + Iter {}
+
+impl<T, C> Set<T> for C where C: Collection<Item = T> + Len + for<'a> Get<&'a T> + Iter {}
+
+/// Mutable set data structure.
+///
+/// This is automatically implemented for every collection implementing the required traits.
+pub trait SetMut<T>: Set<T> + Insert<Output = bool> + for<'a> Remove<&'a T> {}
+
+impl<T, C> SetMut<T> for C where C: Set<T> + Insert<Output = bool> + for<'a> Remove<&'a T> {}
+
+/// Immutable map data structure.
+///
+/// A map is an unordered collection storing key-value pairs, indexed by the key.
+///
+/// This is automatically implemented for every collection implementing the required traits.
+pub trait Map<K, V>:
+	Keyed<Key = K, Item = V> + Len + for<'a> Get<&'a K> + for<'a> GetKeyValue<&'a K> + MapIter
+{
+}
+
+impl<K, V, C> Map<K, V> for C where
+	C: Keyed<Key = K, Item = V> + Len + for<'a> Get<&'a K> + for<'a> GetKeyValue<&'a K> + MapIter
+{
+}
+
+/// Mutable map data structure.
+///
+/// This is automatically implemented for every collection implementing the required traits.
+pub trait MapMut<K, V>:
+	Map<K, V> + for<'a> GetMut<&'a K> + MapInsert<K, Output = Option<V>> + for<'a> Remove<&'a K>
+{
+}
+
+impl<K, V, C> MapMut<K, V> for C where
+	C: Map<K, V> + for<'a> GetMut<&'a K> + MapInsert<K, Output = Option<V>> + for<'a> Remove<&'a K>
+{
+}
+
+/// Error returned by mutable cursors when inserting a key that would break the ordering of the map.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UnorderedKeyError;
+
+impl std::fmt::Display for UnorderedKeyError {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		write!(f, "key is not properly ordered relative to neighbors")
+	}
+}
+
+impl std::error::Error for UnorderedKeyError {}
+
+/// Mutable cursor over an ordered map.
+///
+/// A cursor points to a gap between two entries of the map (or before the first/after the last entry).
+pub trait MapCursorMut<'a, C: 'a + ?Sized + KeyedRef + CollectionMut> {
+	/// Advances the cursor to the next gap, returning the entry it moved over (if any).
+	fn next(&mut self) -> Option<(C::KeyRef<'_>, C::ItemMut<'_>)>;
+
+	/// Advances the cursor to the previous gap, returning the entry it moved over (if any).
+	fn prev(&mut self) -> Option<(C::KeyRef<'_>, C::ItemMut<'_>)>;
+
+	/// Returns the entry directly after the cursor, without moving it.
+	fn peek_next(&mut self) -> Option<(C::KeyRef<'_>, C::ItemMut<'_>)>;
+
+	/// Returns the entry directly before the cursor, without moving it.
+	fn peek_prev(&mut self) -> Option<(C::KeyRef<'_>, C::ItemMut<'_>)>;
+
+	/// Inserts a new key-value pair into the map in the gap that the cursor is currently pointing to,
+	/// leaving the cursor before the new entry.
+	///
+	/// Fails if `key` is not strictly between the keys of the surrounding entries.
+	fn insert_after(&mut self, key: C::Key, value: C::Item) -> Result<(), UnorderedKeyError>;
+
+	/// Inserts a new key-value pair into the map in the gap that the cursor is currently pointing to,
+	/// leaving the cursor after the new entry.
+	///
+	/// Fails if `key` is not strictly between the keys of the surrounding entries.
+	fn insert_before(&mut self, key: C::Key, value: C::Item) -> Result<(), UnorderedKeyError>;
+
+	/// Removes the entry directly after the cursor and returns it (if any).
+	fn remove_next(&mut self) -> Option<(C::Key, C::Item)>;
+
+	/// Removes the entry directly before the cursor and returns it (if any).
+	fn remove_prev(&mut self) -> Option<(C::Key, C::Item)>;
+}
+
+/// Ordered map providing mutable cursors.
+pub trait CursorApi<T>: KeyedRef + CollectionMut {
+	/// Mutable cursor type.
+	type CursorMut<'a>: MapCursorMut<'a, Self>
+	where
+		Self: 'a;
+
+	/// Returns a cursor pointing at the gap before the smallest key above the given bound.
+	fn lower_bound_mut(&mut self, bound: std::ops::Bound<T>) -> Self::CursorMut<'_>;
+
+	/// Returns a cursor pointing at the gap after the greatest key below the given bound.
+	fn upper_bound_mut(&mut self, bound: std::ops::Bound<T>) -> Self::CursorMut<'_>;
+}
+
+/// Bidirectional map, associating left values to right values and vice versa.
+pub trait BiMap {
+	/// Type of the left values.
+	type Left;
+
+	/// Type of the right values.
+	type Right;
+
+	/// Returns a reference to the right value associated to the given left value (if any).
+	fn get_by_left(&self, left: &Self::Left) -> Option<&Self::Right>;
+
+	/// Returns a reference to the left value associated to the given right value (if any).
+	fn get_by_right(&self, right: &Self::Right) -> Option<&Self::Left>;
+
+	/// Checks if the map contains the given left value.
+	fn contains_left(&self, left: &Self::Left) -> bool {
+		self.get_by_left(left).is_some()
+	}
+
+	/// Checks if the map contains the given right value.
+	fn contains_right(&self, right: &Self::Right) -> bool {
+		self.get_by_right(right).is_some()
+	}
+}
+
+/// Mutable bidirectional map.
+pub trait BiMapMut: BiMap {
+	/// The output of the insertion function, describing the pairs displaced by the insertion.
+	type Output;
+
+	/// Insert a new left-right pair in the map,
+	/// removing any existing pair sharing its left or right value.
+	fn insert(&mut self, left: Self::Left, right: Self::Right) -> Self::Output;
+
+	/// Remove the pair identified by the given left value, and return it (if any).
+	fn remove_by_left(&mut self, left: &Self::Left) -> Option<(Self::Left, Self::Right)>;
+
+	/// Remove the pair identified by the given right value, and return it (if any).
+	fn remove_by_right(&mut self, right: &Self::Right) -> Option<(Self::Left, Self::Right)>;
+}
+
+/// Object-safe counterpart of [`Len`].
+///
+/// This is automatically implemented for every collection implementing [`Len`].
+pub trait DynLen {
+	/// Returns the number of elements in the collection.
+	fn dyn_len(&self) -> usize;
+
+	/// Checks if the collection is empty.
+	fn dyn_is_empty(&self) -> bool {
+		self.dyn_len() == 0
+	}
+}
+
+impl<C: Len> DynLen for C {
+	#[inline(always)]
+	fn dyn_len(&self) -> usize {
+		self.len()
+	}
+}
+
+/// Object-safe counterpart of [`Get`] for maps whose item references are plain references.
+///
+/// This is automatically implemented for every `'static` map implementing [`Get`]
+/// with `&'a V` as item reference type.
+pub trait DynGet<K, V> {
+	/// Returns a reference to the value stored behind the given key (if any).
+	fn dyn_get(&self, key: &K) -> Option<&V>;
+
+	/// Checks if the map contains a value behind the given key.
+	fn dyn_contains(&self, key: &K) -> bool {
+		self.dyn_get(key).is_some()
+	}
+}
+
+impl<C, K, V> DynGet<K, V> for C
+where
+	C: 'static + Keyed<Key = K> + for<'a> CollectionRef<ItemRef<'a> = &'a V> + for<'a> Get<&'a K>,
+{
+	#[inline(always)]
+	fn dyn_get(&self, key: &K) -> Option<&V> {
+		self.get(key)
+	}
+}
+
+/// Object-safe counterpart of [`MapInsert`].
+///
+/// This is automatically implemented for every map implementing [`MapInsert`]
+/// that returns the previous value behind the key.
+pub trait DynInsert<K, V> {
+	/// Insert a new key-value pair in the map, returning the previous value behind the key (if any).
+	fn dyn_insert(&mut self, key: K, value: V) -> Option<V>;
+}
+
+impl<C, K, V> DynInsert<K, V> for C
+where
+	C: Collection<Item = V> + MapInsert<K, Output = Option<V>>,
+{
+	#[inline(always)]
+	fn dyn_insert(&mut self, key: K, value: V) -> Option<V> {
+		self.insert(key, value)
+	}
+}
+
+/// Object-safe counterpart of [`Remove`].
+///
+/// This is automatically implemented for every map implementing [`Remove`].
+pub trait DynRemove<K, V> {
+	/// Remove the value behind the given `key`.
+	fn dyn_remove(&mut self, key: &K) -> Option<V>;
+}
+
+impl<C, K, V> DynRemove<K, V> for C
+where
+	C: Collection<Item = V> + for<'a> Remove<&'a K>,
+{
+	#[inline(always)]
+	fn dyn_remove(&mut self, key: &K) -> Option<V> {
+		self.remove(key)
+	}
+}
+
+/// Object-safe map.
+///
+/// This is automatically implemented for every map implementing
+/// [`DynLen`], [`DynGet`], [`DynInsert`] and [`DynRemove`],
+/// so that heterogeneous maps can be stored as `Box<dyn DynMap<K, V>>`.
+///
+/// ```
+/// use cc_traits::DynMap;
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let mut maps: Vec<Box<dyn DynMap<String, i32>>> =
+///     vec![Box::new(HashMap::new()), Box::new(BTreeMap::new())];
+///
+/// for map in &mut maps {
+///     map.dyn_insert("answer".to_string(), 42);
+///     assert_eq!(map.dyn_get(&"answer".to_string()), Some(&42));
+///     assert_eq!(map.dyn_len(), 1);
+/// }
+/// ```
+pub trait DynMap<K, V>: DynLen + DynGet<K, V> + DynInsert<K, V> + DynRemove<K, V> {}
+
+impl<C, K, V> DynMap<K, V> for C where C: DynLen + DynGet<K, V> + DynInsert<K, V> + DynRemove<K, V> {}
+
+/// Object-safe counterpart of [`Get`] for sequences whose item references are plain references.
+///
+/// This is automatically implemented for every `'static` sequence implementing `Get<usize>`
+/// with `&'a T` as item reference type.
+pub trait DynIndex<T> {
+	/// Returns a reference to the element at the given `index` (if any).
+	fn dyn_index(&self, index: usize) -> Option<&T>;
+}
+
+impl<C, T> DynIndex<T> for C
+where
+	C: 'static + for<'a> CollectionRef<ItemRef<'a> = &'a T> + Get<usize>,
+{
+	#[inline(always)]
+	fn dyn_index(&self, index: usize) -> Option<&T> {
+		self.get(index)
+	}
+}
+
+/// Object-safe counterpart of [`PushBack`].
+///
+/// This is automatically implemented for every collection implementing [`PushBack`].
+pub trait DynPushBack<T> {
+	/// Push a new element on the back of the collection.
+	fn dyn_push_back(&mut self, element: T);
+}
+
+impl<C: PushBack> DynPushBack<C::Item> for C {
+	#[inline(always)]
+	fn dyn_push_back(&mut self, element: C::Item) {
+		self.push_back(element);
+	}
+}
+
+/// Object-safe counterpart of [`PopBack`].
+///
+/// This is automatically implemented for every collection implementing [`PopBack`].
+pub trait DynPopBack<T> {
+	/// Remove the back element of the collection and return it (if any).
+	fn dyn_pop_back(&mut self) -> Option<T>;
+}
+
+impl<C: PopBack> DynPopBack<C::Item> for C {
+	#[inline(always)]
+	fn dyn_pop_back(&mut self) -> Option<C::Item> {
+		self.pop_back()
+	}
+}
+
+/// Object-safe sequence.
+///
+/// This is automatically implemented for every sequence implementing
+/// [`DynLen`], [`DynIndex`], [`DynPushBack`] and [`DynPopBack`],
+/// so that heterogeneous sequences can be stored as `Box<dyn DynSeq<T>>`.
+///
+/// ```
+/// use cc_traits::DynSeq;
+///
+/// let mut seqs: Vec<Box<dyn DynSeq<i32>>> =
+///     vec![Box::new(Vec::new()), Box::new(Vec::with_capacity(16))];
+///
+/// for seq in &mut seqs {
+///     seq.dyn_push_back(42);
+///     assert_eq!(seq.dyn_index(0), Some(&42));
+///     assert_eq!(seq.dyn_pop_back(), Some(42));
+///     assert!(seq.dyn_is_empty());
+/// }
+/// ```
+pub trait DynSeq<T>: DynLen + DynIndex<T> + DynPushBack<T> + DynPopBack<T> {}
+
+impl<C, T> DynSeq<T> for C where C: DynLen + DynIndex<T> + DynPushBack<T> + DynPopBack<T> {}
@@ -4,7 +4,6 @@
 /// ## Example
 ///
 /// ```
-/// #![feature(generic_associated_types)]
 /// use cc_traits::{Collection, CollectionRef, covariant_item_ref};
 ///
 /// pub struct MyVec<T>(Vec<T>);
@@ -14,10 +13,9 @@
 /// }
 ///
 /// impl<T> CollectionRef for MyVec<T> {
-///   type ItemRef<'a>
+///   type ItemRef<'a> = &'a T
 ///   where
-///     Self: 'a,
-///   = &'a T;
+///     Self: 'a;
 ///
 ///   covariant_item_ref!();
 /// }
@@ -40,7 +38,6 @@ macro_rules! covariant_item_ref {
 /// ## Example
 ///
 /// ```
-/// #![feature(generic_associated_types)]
 /// use cc_traits::{Collection, CollectionMut, covariant_item_mut};
 ///
 /// pub struct MyVec<T>(Vec<T>);
@@ -50,10 +47,9 @@ macro_rules! covariant_item_ref {
 /// }
 ///
 /// impl<T> CollectionMut for MyVec<T> {
-///   type ItemMut<'a>
+///   type ItemMut<'a> = &'a mut T
 ///   where
-///     Self: 'a,
-///   = &'a mut T;
+///     Self: 'a;
 ///
 ///   covariant_item_mut!();
 /// }
@@ -76,7 +72,6 @@ macro_rules! covariant_item_mut {
 /// ## Example
 ///
 /// ```
-/// #![feature(generic_associated_types)]
 /// use cc_traits::{Collection, Keyed, KeyedRef, covariant_key_ref};
 ///
 /// pub struct MyMap<K, V>(std::collections::HashMap<K, V>);
@@ -90,10 +85,9 @@ macro_rules! covariant_item_mut {
 /// }
 ///
 /// impl<K, V> KeyedRef for MyMap<K, V> {
-///   type KeyRef<'a>
+///   type KeyRef<'a> = &'a K
 ///   where
-///     Self: 'a,
-///   = &'a K;
+///     Self: 'a;
 ///
 ///   covariant_key_ref!();
 /// }
@@ -109,3 +103,410 @@ macro_rules! covariant_key_ref {
 		}
 	};
 }
+
+/// Implements the given traits for a wrapper type
+/// by forwarding them to one of its fields.
+///
+/// The wrapper type is followed by `=>`, the field to delegate to and its type.
+/// Generic parameters are introduced with a leading `impl<...>`.
+/// Each implementation is bounded by the field type implementing the trait,
+/// and traits with a type parameter (such as `Get<T>`)
+/// are implemented for every parameter supported by the field.
+///
+/// The supported traits are `Collection`, `CollectionRef`, `CollectionMut`,
+/// `Keyed`, `KeyedRef`, `WithCapacity`, `Len`, `Capacity`, `Reserve`, `Clear`,
+/// `Get`, `GetMut`, `GetKeyValue`, `Insert`, `MapInsert`, `Remove`, `Update`, `PushFront`, `PushBack`,
+/// `PopFront`, `PopBack`, `Iter`, `IterMut`, `MapIter` and `MapIterMut`.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{delegate_collection, Get, GetMut, Len, MapInsert, Remove};
+/// use std::collections::HashMap;
+///
+/// pub struct MyMap<K, V>(HashMap<K, V>);
+///
+/// delegate_collection!(impl<K, V> MyMap<K, V> => .0: HashMap<K, V>; [
+///   Collection, CollectionRef, CollectionMut, Get, GetMut, MapInsert, Remove, Len
+/// ]);
+///
+/// let mut map = MyMap(HashMap::new());
+/// map.insert("a", 1);
+/// *map.get_mut(&"a").unwrap() += 1;
+/// assert_eq!(map.get(&"a").map(|v| *v), Some(2));
+/// assert_eq!(map.remove(&"a"), Some(2));
+/// assert!(map.is_empty());
+/// ```
+///
+/// The wrapper type may have other fields.
+/// Implementing `WithCapacity` requires the wrapper type to implement `Default`,
+/// used to initialize the other fields:
+///
+/// ```
+/// use cc_traits::{delegate_collection, Iter, PushBack, WithCapacity};
+///
+/// #[derive(Default)]
+/// pub struct Log {
+///   name: &'static str,
+///   lines: Vec<String>,
+/// }
+///
+/// delegate_collection!(Log => .lines: Vec<String>; [
+///   Collection, CollectionRef, WithCapacity, PushBack, Iter
+/// ]);
+///
+/// let mut log = Log::with_capacity(2);
+/// log.push_back("started".to_string());
+/// assert_eq!(log.name, "");
+/// assert_eq!(log.iter().count(), 1);
+/// ```
+#[macro_export]
+macro_rules! delegate_collection {
+	(
+		impl<$($g:ident $(: $bound:path)?),* $(,)?>
+		$ty:ty => .$field:tt : $inner:ty ; [$($trait:ident),* $(,)?]
+	) => {
+		$crate::delegate_collection!(
+			@all [$($g $(: $bound)?,)*] $ty, $field, $inner, [$($trait),*]
+		);
+	};
+	($ty:ty => .$field:tt : $inner:ty ; [$($trait:ident),* $(,)?]) => {
+		$crate::delegate_collection!(@all [] $ty, $field, $inner, [$($trait),*]);
+	};
+	(@all $g:tt $ty:ty, $f:tt, $inner:ty, [$($trait:ident),*]) => {
+		$(
+			$crate::delegate_collection!(@impl $trait $g $ty, $f, $inner);
+		)*
+	};
+	(@impl Collection [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Collection for $ty
+		where
+			$inner: $crate::Collection,
+		{
+			type Item = <$inner as $crate::Collection>::Item;
+		}
+	};
+	(@impl CollectionRef [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::CollectionRef for $ty
+		where
+			$inner: $crate::CollectionRef,
+		{
+			type ItemRef<'a> = <$inner as $crate::CollectionRef>::ItemRef<'a>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+			where
+				Self: 'long,
+			{
+				<$inner as $crate::CollectionRef>::upcast_item_ref(r)
+			}
+		}
+	};
+	(@impl CollectionMut [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::CollectionMut for $ty
+		where
+			$inner: $crate::CollectionMut,
+		{
+			type ItemMut<'a> = <$inner as $crate::CollectionMut>::ItemMut<'a>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+			where
+				Self: 'long,
+			{
+				<$inner as $crate::CollectionMut>::upcast_item_mut(r)
+			}
+		}
+	};
+	(@impl Keyed [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Keyed for $ty
+		where
+			$inner: $crate::Keyed,
+		{
+			type Key = <$inner as $crate::Keyed>::Key;
+		}
+	};
+	(@impl KeyedRef [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::KeyedRef for $ty
+		where
+			$inner: $crate::KeyedRef,
+		{
+			type KeyRef<'a> = <$inner as $crate::KeyedRef>::KeyRef<'a>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+			where
+				Self: 'long,
+			{
+				<$inner as $crate::KeyedRef>::upcast_key_ref(r)
+			}
+		}
+	};
+	(@impl WithCapacity [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::WithCapacity for $ty
+		where
+			$inner: $crate::WithCapacity,
+		{
+			#[inline(always)]
+			#[allow(clippy::needless_update)]
+			fn with_capacity(capacity: usize) -> Self {
+				Self {
+					$f: <$inner as $crate::WithCapacity>::with_capacity(capacity),
+					..::core::default::Default::default()
+				}
+			}
+		}
+	};
+	(@impl Len [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Len for $ty
+		where
+			$inner: $crate::Len,
+		{
+			#[inline(always)]
+			fn len(&self) -> usize {
+				<$inner as $crate::Len>::len(&self.$f)
+			}
+
+			#[inline(always)]
+			fn is_empty(&self) -> bool {
+				<$inner as $crate::Len>::is_empty(&self.$f)
+			}
+		}
+	};
+	(@impl Capacity [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Capacity for $ty
+		where
+			$inner: $crate::Capacity,
+		{
+			#[inline(always)]
+			fn capacity(&self) -> usize {
+				<$inner as $crate::Capacity>::capacity(&self.$f)
+			}
+		}
+	};
+	(@impl Reserve [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Reserve for $ty
+		where
+			$inner: $crate::Reserve,
+		{
+			#[inline(always)]
+			fn reserve(&mut self, additional: usize) {
+				<$inner as $crate::Reserve>::reserve(&mut self.$f, additional)
+			}
+		}
+	};
+	(@impl Clear [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Clear for $ty
+		where
+			$inner: $crate::Clear,
+		{
+			#[inline(always)]
+			fn clear(&mut self) {
+				<$inner as $crate::Clear>::clear(&mut self.$f)
+			}
+		}
+	};
+	(@impl Get [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)* __Q> $crate::Get<__Q> for $ty
+		where
+			$inner: $crate::Get<__Q>,
+		{
+			#[inline(always)]
+			fn get(&self, key: __Q) -> Option<Self::ItemRef<'_>> {
+				<$inner as $crate::Get<__Q>>::get(&self.$f, key)
+			}
+
+			#[inline(always)]
+			fn contains(&self, key: __Q) -> bool {
+				<$inner as $crate::Get<__Q>>::contains(&self.$f, key)
+			}
+		}
+	};
+	(@impl GetKeyValue [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)* __Q> $crate::GetKeyValue<__Q> for $ty
+		where
+			$inner: $crate::GetKeyValue<__Q>,
+		{
+			#[inline(always)]
+			fn get_key_value(&self, key: __Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				<$inner as $crate::GetKeyValue<__Q>>::get_key_value(&self.$f, key)
+			}
+		}
+	};
+	(@impl GetMut [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)* __Q> $crate::GetMut<__Q> for $ty
+		where
+			$inner: $crate::GetMut<__Q>,
+		{
+			#[inline(always)]
+			fn get_mut(&mut self, key: __Q) -> Option<Self::ItemMut<'_>> {
+				<$inner as $crate::GetMut<__Q>>::get_mut(&mut self.$f, key)
+			}
+		}
+	};
+	(@impl Insert [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Insert for $ty
+		where
+			$inner: $crate::Insert,
+		{
+			type Output = <$inner as $crate::Insert>::Output;
+
+			#[inline(always)]
+			fn insert(&mut self, element: Self::Item) -> Self::Output {
+				<$inner as $crate::Insert>::insert(&mut self.$f, element)
+			}
+		}
+	};
+	(@impl MapInsert [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)* __Q> $crate::MapInsert<__Q> for $ty
+		where
+			$inner: $crate::MapInsert<__Q>,
+		{
+			type Output = <$inner as $crate::MapInsert<__Q>>::Output;
+
+			#[inline(always)]
+			fn insert(&mut self, key: __Q, value: Self::Item) -> Self::Output {
+				<$inner as $crate::MapInsert<__Q>>::insert(&mut self.$f, key, value)
+			}
+		}
+	};
+	(@impl Remove [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)* __Q> $crate::Remove<__Q> for $ty
+		where
+			$inner: $crate::Remove<__Q>,
+		{
+			#[inline(always)]
+			fn remove(&mut self, key: __Q) -> Option<Self::Item> {
+				<$inner as $crate::Remove<__Q>>::remove(&mut self.$f, key)
+			}
+		}
+	};
+	(@impl Update [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Update for $ty
+		where
+			$inner: $crate::Update,
+		{
+			#[inline(always)]
+			fn update<__F>(&mut self, key: Self::Key, f: __F)
+			where
+				__F: FnOnce(Option<Self::Item>) -> Option<Self::Item>,
+			{
+				<$inner as $crate::Update>::update(&mut self.$f, key, f)
+			}
+		}
+	};
+	(@impl PushFront [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::PushFront for $ty
+		where
+			$inner: $crate::PushFront,
+		{
+			type Output = <$inner as $crate::PushFront>::Output;
+
+			#[inline(always)]
+			fn push_front(&mut self, element: Self::Item) -> Self::Output {
+				<$inner as $crate::PushFront>::push_front(&mut self.$f, element)
+			}
+		}
+	};
+	(@impl PushBack [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::PushBack for $ty
+		where
+			$inner: $crate::PushBack,
+		{
+			type Output = <$inner as $crate::PushBack>::Output;
+
+			#[inline(always)]
+			fn push_back(&mut self, element: Self::Item) -> Self::Output {
+				<$inner as $crate::PushBack>::push_back(&mut self.$f, element)
+			}
+		}
+	};
+	(@impl PopFront [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::PopFront for $ty
+		where
+			$inner: $crate::PopFront,
+		{
+			#[inline(always)]
+			fn pop_front(&mut self) -> Option<Self::Item> {
+				<$inner as $crate::PopFront>::pop_front(&mut self.$f)
+			}
+		}
+	};
+	(@impl PopBack [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::PopBack for $ty
+		where
+			$inner: $crate::PopBack,
+		{
+			#[inline(always)]
+			fn pop_back(&mut self) -> Option<Self::Item> {
+				<$inner as $crate::PopBack>::pop_back(&mut self.$f)
+			}
+		}
+	};
+	(@impl Iter [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::Iter for $ty
+		where
+			$inner: $crate::Iter,
+		{
+			type Iter<'a> = <$inner as $crate::Iter>::Iter<'a>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				<$inner as $crate::Iter>::iter(&self.$f)
+			}
+		}
+	};
+	(@impl IterMut [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::IterMut for $ty
+		where
+			$inner: $crate::IterMut,
+		{
+			type IterMut<'a> = <$inner as $crate::IterMut>::IterMut<'a>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter_mut(&mut self) -> Self::IterMut<'_> {
+				<$inner as $crate::IterMut>::iter_mut(&mut self.$f)
+			}
+		}
+	};
+	(@impl MapIter [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::MapIter for $ty
+		where
+			$inner: $crate::MapIter,
+		{
+			type Iter<'a> = <$inner as $crate::MapIter>::Iter<'a>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				<$inner as $crate::MapIter>::iter(&self.$f)
+			}
+		}
+	};
+	(@impl MapIterMut [$($g:tt)*] $ty:ty, $f:tt, $inner:ty) => {
+		impl<$($g)*> $crate::MapIterMut for $ty
+		where
+			$inner: $crate::MapIterMut,
+		{
+			type IterMut<'a> = <$inner as $crate::MapIterMut>::IterMut<'a>
+			where
+				Self: 'a;
+
+			#[inline(always)]
+			fn iter_mut(&mut self) -> Self::IterMut<'_> {
+				<$inner as $crate::MapIterMut>::iter_mut(&mut self.$f)
+			}
+		}
+	};
+}
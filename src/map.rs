@@ -0,0 +1,14 @@
+//! Traits describing maps.
+//!
+//! This module re-exports the traits of this crate
+//! that are relevant to keyed collections.
+pub use crate::{
+	BiMap, BiMapMut, BoundQuery, Collection, CollectionMut, CollectionRef, ConvertInto, Counter,
+	CursorApi, FromMap, Get, GetIndex, GetIndexMut, GetIndexOf, GetKeyValue, GetKeyValueMut,
+	GetMut, GetOrInsertExt, Keyed, KeyedRef, Len, Map, MapCursorMut, MapDrainRange, MapInsert,
+	MapIter, MapIterMut, MapMut, MergeFrom, OccupiedError, OrderedMapOps, Remove, RemoveEntry,
+	TryInsert, UnorderedKeyError, Update,
+};
+
+#[cfg(feature = "rayon")]
+pub use crate::{IntoParMapIter, ParMapIter};
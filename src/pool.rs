@@ -0,0 +1,67 @@
+use crate::Recycle;
+
+/// Pool of recycled collections.
+///
+/// Collections given back to the pool are recycled (see [`Recycle`]),
+/// so that taking a collection from the pool reuses their allocated memory.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::Pool;
+///
+/// let mut pool: Pool<String> = Pool::new();
+/// for line in ["a", "b", "c"] {
+///   let mut buffer = pool.take();
+///   buffer.push_str(line);
+///   assert_eq!(buffer.len(), 1);
+///   pool.give(buffer);
+/// }
+///
+/// assert_eq!(pool.len(), 1);
+/// assert!(pool.take().capacity() > 0);
+/// ```
+pub struct Pool<C> {
+	free: Vec<C>,
+}
+
+impl<C> Pool<C> {
+	/// Creates a new empty pool.
+	pub fn new() -> Self {
+		Self { free: Vec::new() }
+	}
+
+	/// Returns the number of collections available in the pool.
+	pub fn len(&self) -> usize {
+		self.free.len()
+	}
+
+	/// Checks if no collection is available in the pool.
+	pub fn is_empty(&self) -> bool {
+		self.free.is_empty()
+	}
+
+	/// Takes an empty collection from the pool,
+	/// or creates a new one if the pool is empty.
+	pub fn take(&mut self) -> C
+	where
+		C: Default,
+	{
+		self.free.pop().unwrap_or_default()
+	}
+
+	/// Recycles the given collection and gives it back to the pool.
+	pub fn give(&mut self, mut collection: C)
+	where
+		C: Recycle,
+	{
+		collection.recycle();
+		self.free.push(collection)
+	}
+}
+
+impl<C> Default for Pool<C> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,31 @@
+//! Commonly used traits.
+//!
+//! Importing this module brings the traits most used in generic code into scope,
+//! so that their methods can be called without naming each trait:
+//!
+//! ```
+//! use cc_traits::prelude::*;
+//! use std::collections::HashMap;
+//!
+//! fn sum<C: Iter<Item = u32>>(collection: &C) -> u32 {
+//!     collection.iter().map(|v| *v).sum()
+//! }
+//!
+//! fn replace<M: MapInsert<&'static str, Item = u32> + Remove<&'static str, Item = u32>>(map: &mut M) {
+//!     if let Some(value) = map.remove("old") {
+//!         map.insert("new", value);
+//!     }
+//! }
+//!
+//! let mut map = HashMap::new();
+//! map.insert("old", 1);
+//! replace(&mut map);
+//! assert_eq!(map.get("new").map(|v| *v), Some(1));
+//! assert_eq!(map.len(), 1);
+//! assert_eq!(sum(&vec![1, 2, 3]), 6);
+//! ```
+pub use crate::{
+	Back, BackMut, Clear, Collection, CollectionMut, CollectionRef, Front, FrontMut, Get, GetMut,
+	GetOrInsertExt, Insert, Iter, IterMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapIterMut,
+	PopBack, PushBack, Remove, SetEntryApi, SetEntryApiWith,
+};
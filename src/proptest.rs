@@ -0,0 +1,253 @@
+use crate::{MapInsert, PushBack};
+use ::proptest::{
+	collection::SizeRange,
+	strategy::{NewTree, Strategy, ValueTree},
+	test_runner::TestRunner,
+};
+use std::{fmt, marker::PhantomData};
+
+/// Strategy generating sequences of any `Default + PushBack` collection.
+///
+/// Created by the [`seq_strategy`] function.
+pub struct SeqStrategy<C, S> {
+	element: S,
+	size: SizeRange,
+	collection: PhantomData<fn() -> C>,
+}
+
+/// Creates a strategy generating a `C` collection by pushing
+/// a number of elements in `size`, generated by `element`.
+///
+/// Generated collections shrink by removing elements, starting from the back,
+/// then by shrinking the remaining elements.
+///
+/// ```
+/// use cc_traits::seq_strategy;
+/// use proptest::prelude::*;
+/// use std::collections::VecDeque;
+///
+/// proptest! {
+///     fn push_pop(deque in seq_strategy::<VecDeque<u8>, _>(any::<u8>(), 0..8)) {
+///         prop_assert!(deque.len() < 8);
+///     }
+/// }
+///
+/// push_pop();
+/// ```
+pub fn seq_strategy<C, S>(element: S, size: impl Into<SizeRange>) -> SeqStrategy<C, S>
+where
+	S: Strategy,
+	C: Default + PushBack<Item = S::Value> + fmt::Debug,
+{
+	SeqStrategy {
+		element,
+		size: size.into(),
+		collection: PhantomData,
+	}
+}
+
+impl<C, S: fmt::Debug> fmt::Debug for SeqStrategy<C, S> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("SeqStrategy")
+			.field("element", &self.element)
+			.field("size", &self.size)
+			.finish()
+	}
+}
+
+impl<C, S> Strategy for SeqStrategy<C, S>
+where
+	S: Strategy,
+	C: Default + PushBack<Item = S::Value> + fmt::Debug,
+{
+	type Tree = CollectionValueTree<C, S::Tree>;
+	type Value = C;
+
+	fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+		CollectionValueTree::new(&self.element, &self.size, runner, |elements| {
+			let mut collection = C::default();
+			for element in elements {
+				collection.push_back(element.current());
+			}
+			collection
+		})
+	}
+}
+
+/// Strategy generating maps of any `Default + MapInsert` collection.
+///
+/// Created by the [`map_strategy`] function.
+pub struct MapStrategy<C, K, V> {
+	entry: (K, V),
+	size: SizeRange,
+	collection: PhantomData<fn() -> C>,
+}
+
+/// Creates a strategy generating a `C` map by inserting
+/// a number of entries in `size`, generated by `key` and `value`.
+///
+/// Entries with the same key overwrite each other,
+/// so the generated map may be smaller than `size`.
+/// Generated maps shrink by removing entries,
+/// then by shrinking the remaining keys and values.
+///
+/// ```
+/// use cc_traits::map_strategy;
+/// use proptest::prelude::*;
+/// use std::collections::BTreeMap;
+///
+/// proptest! {
+///     fn bounded(map in map_strategy::<BTreeMap<u8, bool>, _, _>(any::<u8>(), any::<bool>(), 0..8)) {
+///         prop_assert!(map.len() < 8);
+///     }
+/// }
+///
+/// bounded();
+/// ```
+pub fn map_strategy<C, K, V>(key: K, value: V, size: impl Into<SizeRange>) -> MapStrategy<C, K, V>
+where
+	K: Strategy,
+	V: Strategy,
+	C: Default + MapInsert<K::Value, Item = V::Value> + fmt::Debug,
+{
+	MapStrategy {
+		entry: (key, value),
+		size: size.into(),
+		collection: PhantomData,
+	}
+}
+
+impl<C, K: fmt::Debug, V: fmt::Debug> fmt::Debug for MapStrategy<C, K, V> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("MapStrategy")
+			.field("key", &self.entry.0)
+			.field("value", &self.entry.1)
+			.field("size", &self.size)
+			.finish()
+	}
+}
+
+impl<C, K, V> Strategy for MapStrategy<C, K, V>
+where
+	K: Strategy,
+	V: Strategy,
+	C: Default + MapInsert<K::Value, Item = V::Value> + fmt::Debug,
+{
+	type Tree = CollectionValueTree<C, <(K, V) as Strategy>::Tree>;
+	type Value = C;
+
+	fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+		CollectionValueTree::new(&self.entry, &self.size, runner, |entries| {
+			let mut collection = C::default();
+			for entry in entries {
+				let (key, value) = entry.current();
+				collection.insert(key, value);
+			}
+			collection
+		})
+	}
+}
+
+/// Last shrinking step, undone by `complicate`.
+#[derive(Debug, Clone, Copy)]
+enum Shrink {
+	Remove(usize),
+	Element(usize),
+}
+
+/// Value tree of the [`SeqStrategy`] and [`MapStrategy`] strategies.
+pub struct CollectionValueTree<C, T> {
+	elements: Vec<T>,
+	included: Vec<bool>,
+	len: usize,
+	min_len: usize,
+	removing: usize,
+	shrinking: usize,
+	prev: Option<Shrink>,
+	build: fn(&mut dyn Iterator<Item = &T>) -> C,
+}
+
+impl<C, T: ValueTree> CollectionValueTree<C, T> {
+	fn new<S: Strategy<Tree = T>>(
+		element: &S,
+		size: &SizeRange,
+		runner: &mut TestRunner,
+		build: fn(&mut dyn Iterator<Item = &T>) -> C,
+	) -> Result<Self, ::proptest::test_runner::Reason> {
+		let len = (size.start()..=size.end_incl()).new_tree(runner)?.current();
+		let elements = (0..len)
+			.map(|_| element.new_tree(runner))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self {
+			elements,
+			included: vec![true; len],
+			len,
+			min_len: size.start(),
+			removing: len,
+			shrinking: 0,
+			prev: None,
+			build,
+		})
+	}
+}
+
+impl<C, T: fmt::Debug> fmt::Debug for CollectionValueTree<C, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_list().entries(self.current_elements()).finish()
+	}
+}
+
+impl<C, T> CollectionValueTree<C, T> {
+	fn current_elements(&self) -> impl Iterator<Item = &T> {
+		self.elements
+			.iter()
+			.zip(&self.included)
+			.filter_map(|(element, included)| included.then_some(element))
+	}
+}
+
+impl<C: fmt::Debug, T: ValueTree> ValueTree for CollectionValueTree<C, T> {
+	type Value = C;
+
+	fn current(&self) -> C {
+		(self.build)(&mut self.current_elements())
+	}
+
+	fn simplify(&mut self) -> bool {
+		if self.removing > 0 && self.len > self.min_len {
+			self.removing -= 1;
+			self.included[self.removing] = false;
+			self.len -= 1;
+			self.prev = Some(Shrink::Remove(self.removing));
+			return true;
+		}
+
+		while self.shrinking < self.elements.len() {
+			if self.included[self.shrinking] && self.elements[self.shrinking].simplify() {
+				self.prev = Some(Shrink::Element(self.shrinking));
+				return true;
+			}
+
+			self.shrinking += 1;
+		}
+
+		self.prev = None;
+		false
+	}
+
+	fn complicate(&mut self) -> bool {
+		match self.prev.take() {
+			Some(Shrink::Remove(i)) => {
+				self.included[i] = true;
+				self.len += 1;
+				true
+			}
+			Some(Shrink::Element(i)) if self.elements[i].complicate() => {
+				self.prev = Some(Shrink::Element(i));
+				true
+			}
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,162 @@
+//! Redis-backed collections.
+use crate::{AsyncCollection, AsyncGet, AsyncInsert, AsyncLen, AsyncRemove, Collection};
+use ::redis::{aio::ConnectionLike, RedisError, ToSingleRedisArg};
+use ::serde::{de::DeserializeOwned, Serialize};
+use std::{fmt, marker::PhantomData};
+
+/// Error returned by [`RedisHashMap`] operations.
+#[derive(Debug)]
+pub enum RedisHashMapError {
+	/// Error returned by the Redis server or connection.
+	Redis(RedisError),
+
+	/// Error while encoding or decoding a value.
+	Encoding(serde_json::Error),
+}
+
+impl fmt::Display for RedisHashMapError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Redis(e) => write!(f, "redis error: {}", e),
+			Self::Encoding(e) => write!(f, "value encoding error: {}", e),
+		}
+	}
+}
+
+impl std::error::Error for RedisHashMapError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::Redis(e) => Some(e),
+			Self::Encoding(e) => Some(e),
+		}
+	}
+}
+
+impl From<RedisError> for RedisHashMapError {
+	fn from(e: RedisError) -> Self {
+		Self::Redis(e)
+	}
+}
+
+impl From<serde_json::Error> for RedisHashMapError {
+	fn from(e: serde_json::Error) -> Self {
+		Self::Encoding(e)
+	}
+}
+
+/// Map stored in a Redis hash.
+///
+/// Values are encoded in JSON.
+/// The connection is cloned for each operation,
+/// so it should be cheap to clone, like `MultiplexedConnection` or `ConnectionManager`.
+pub struct RedisHashMap<V, C> {
+	connection: C,
+	key: String,
+	value: PhantomData<fn() -> V>,
+}
+
+impl<V, C> RedisHashMap<V, C> {
+	/// Creates a new map stored in the Redis hash at the given `key`.
+	pub fn new(connection: C, key: impl Into<String>) -> Self {
+		RedisHashMap {
+			connection,
+			key: key.into(),
+			value: PhantomData,
+		}
+	}
+
+	/// Returns the key of the underlying Redis hash.
+	pub fn key(&self) -> &str {
+		&self.key
+	}
+}
+
+impl<V, C> Collection for RedisHashMap<V, C> {
+	type Item = V;
+}
+
+impl<V, C> AsyncCollection for RedisHashMap<V, C> {
+	type Error = RedisHashMapError;
+}
+
+/// Decodes a JSON-encoded value, if any.
+fn decode<V: DeserializeOwned>(value: Option<String>) -> Result<Option<V>, RedisHashMapError> {
+	Ok(value.map(|v| serde_json::from_str(&v)).transpose()?)
+}
+
+impl<V, C: ConnectionLike + Clone + Send + Sync> AsyncLen for RedisHashMap<V, C> {
+	async fn len(&self) -> Result<usize, RedisHashMapError> {
+		Ok(::redis::cmd("HLEN")
+			.arg(&self.key)
+			.query_async(&mut self.connection.clone())
+			.await?)
+	}
+}
+
+impl<F, V, C> AsyncGet<F> for RedisHashMap<V, C>
+where
+	F: ToSingleRedisArg + Send,
+	V: DeserializeOwned,
+	C: ConnectionLike + Clone + Send + Sync,
+{
+	type Ref<'a>
+		= Box<V>
+	where
+		Self: 'a;
+
+	async fn get(&self, field: F) -> Result<Option<Box<V>>, RedisHashMapError> {
+		let value = ::redis::cmd("HGET")
+			.arg(&self.key)
+			.arg(field)
+			.query_async(&mut self.connection.clone())
+			.await?;
+		Ok(decode(value)?.map(Box::new))
+	}
+
+	async fn contains(&self, field: F) -> Result<bool, RedisHashMapError> {
+		Ok(::redis::cmd("HEXISTS")
+			.arg(&self.key)
+			.arg(field)
+			.query_async(&mut self.connection.clone())
+			.await?)
+	}
+}
+
+impl<F, V, C> AsyncInsert<F> for RedisHashMap<V, C>
+where
+	F: ToSingleRedisArg + Send,
+	V: Serialize + DeserializeOwned + Send,
+	C: ConnectionLike + Clone + Send + Sync,
+{
+	type Output = Option<V>;
+
+	async fn insert(&self, field: F, value: V) -> Result<Option<V>, RedisHashMapError> {
+		let value = serde_json::to_string(&value)?;
+		let (previous,) = ::redis::pipe()
+			.atomic()
+			.hget(&self.key, &field)
+			.hset(&self.key, &field, value)
+			.ignore()
+			.query_async(&mut self.connection.clone())
+			.await?;
+		decode(previous)
+	}
+}
+
+impl<F, V, C> AsyncRemove<F> for RedisHashMap<V, C>
+where
+	F: ToSingleRedisArg + Send,
+	V: DeserializeOwned,
+	C: ConnectionLike + Clone + Send + Sync,
+{
+	async fn remove(&self, field: F) -> Result<Option<V>, RedisHashMapError> {
+		let (previous,) = ::redis::pipe()
+			.atomic()
+			.hget(&self.key, &field)
+			.hdel(&self.key, &field)
+			.ignore()
+			.query_async(&mut self.connection.clone())
+			.await?;
+		decode(previous)
+	}
+}
@@ -0,0 +1,28 @@
+//! Traits describing sequences.
+//!
+//! This module re-exports the traits of this crate
+//! that are relevant to stacks, queues, vectors and other ordered sequences.
+//!
+//! [`Get`](crate::Get) is not re-exported, since its `get` and `contains` methods
+//! would shadow the inherent methods of slices, which take ranges and values instead of indexes.
+//! Glob-importing this module hence leaves those methods callable:
+//!
+//! ```
+//! use cc_traits::seq::*;
+//!
+//! let vec = vec![1, 2, 3];
+//! assert!(vec.contains(&2));
+//! assert!(vec.contains_value(&3));
+//! assert_eq!(vec.get(1..), Some(&[2, 3][..]));
+//! ```
+pub use crate::{
+	Append, AsMutSlice, AsSlice, Back, BackMut, BinarySearch, Chunks, ChunksMut, Collection,
+	CollectionMut, CollectionRef, Contains, ConvertInto, Dedup, DrainRange, ExtendFromSlice,
+	ExtendFromWithin, Fill, FromCollection, Front, FrontMut, GetManyMut, GetMut, InsertAt,
+	InsertSorted, Iter, IterMut, Len, MakeContiguous, Peek, PopBack, PopFront, PriorityPop,
+	PriorityPush, PushBack, PushFront, PushPop, PushStr, Remove, Resize, Reverse, Rotate, Sort,
+	SortUnstable, SplitAtMut, Swap, SwapRemove, Truncate, Windows,
+};
+
+#[cfg(feature = "rayon")]
+pub use crate::{IntoParIter, ParExtend, ParIter, ParIterMut};
@@ -0,0 +1,208 @@
+//! Serde adapters for generic collections.
+use crate::{Iter, Keyed, MapInsert, MapIter, PushBack};
+use ::serde::{
+	de::{DeserializeSeed, MapAccess, SeqAccess, Visitor},
+	ser::{SerializeMap, SerializeSeq},
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{fmt, marker::PhantomData};
+
+/// Marker type selecting the sequence format of [`SerializeCollection`] and [`DeserializeInto`].
+pub struct AsSeq;
+
+/// Marker type selecting the map format of [`SerializeCollection`] and [`DeserializeInto`].
+pub struct AsMap;
+
+/// Serializable view over a collection.
+///
+/// Any [`Iter`] implementor can be serialized as a sequence using [`SerializeCollection::seq`],
+/// and any [`MapIter`] implementor can be serialized as a map using [`SerializeCollection::map`],
+/// without requiring the collection itself to implement [`Serialize`].
+///
+/// ```
+/// use cc_traits::SerializeCollection;
+///
+/// let vec = vec![1, 2, 3];
+/// assert_eq!(serde_json::to_string(&SerializeCollection::seq(&vec)).unwrap(), "[1,2,3]");
+/// ```
+pub struct SerializeCollection<'a, C: ?Sized, F = AsSeq> {
+	collection: &'a C,
+	format: PhantomData<F>,
+}
+
+impl<'a, C: ?Sized> SerializeCollection<'a, C, AsSeq> {
+	/// Serialize the items of the given collection as a sequence.
+	pub fn seq(collection: &'a C) -> Self {
+		SerializeCollection {
+			collection,
+			format: PhantomData,
+		}
+	}
+}
+
+impl<'a, C: ?Sized> SerializeCollection<'a, C, AsMap> {
+	/// Serialize the key-value pairs of the given collection as a map.
+	pub fn map(collection: &'a C) -> Self {
+		SerializeCollection {
+			collection,
+			format: PhantomData,
+		}
+	}
+}
+
+impl<C: ?Sized, F> Clone for SerializeCollection<'_, C, F> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<C: ?Sized, F> Copy for SerializeCollection<'_, C, F> {}
+
+/// Returns the exact length of the iterator, if known.
+fn exact_len<I: Iterator>(iter: &I) -> Option<usize> {
+	match iter.size_hint() {
+		(min, Some(max)) if min == max => Some(min),
+		_ => None,
+	}
+}
+
+impl<C: ?Sized + Iter> Serialize for SerializeCollection<'_, C, AsSeq>
+where
+	C::Item: Serialize,
+{
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let items = self.collection.iter();
+		let mut seq = serializer.serialize_seq(exact_len(&items))?;
+		for item in items {
+			seq.serialize_element(&*item)?;
+		}
+
+		seq.end()
+	}
+}
+
+impl<C: ?Sized + MapIter> Serialize for SerializeCollection<'_, C, AsMap>
+where
+	C::Key: Serialize,
+	C::Item: Serialize,
+{
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let entries = self.collection.iter();
+		let mut map = serializer.serialize_map(exact_len(&entries))?;
+		for (key, value) in entries {
+			map.serialize_entry(&*key, &*value)?;
+		}
+
+		map.end()
+	}
+}
+
+/// Deserialization seed building any insertable collection.
+///
+/// A sequence can be deserialized into any `Default` + [`PushBack`] implementor
+/// using [`DeserializeInto::seq`], and a map can be deserialized into any
+/// `Default` + [`MapInsert`] implementor using [`DeserializeInto::map`],
+/// without requiring the collection itself to implement [`Deserialize`].
+///
+/// ```
+/// use cc_traits::DeserializeInto;
+/// use serde::de::DeserializeSeed;
+/// use std::collections::VecDeque;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str("[1,2,3]");
+/// let deque: VecDeque<i32> = DeserializeInto::seq().deserialize(&mut deserializer).unwrap();
+/// assert_eq!(deque, [1, 2, 3]);
+/// ```
+pub struct DeserializeInto<C, F = AsSeq> {
+	target: PhantomData<fn() -> (C, F)>,
+}
+
+impl<C> DeserializeInto<C, AsSeq> {
+	/// Deserialize a sequence, pushing each item at the back of the collection.
+	pub fn seq() -> Self {
+		DeserializeInto {
+			target: PhantomData,
+		}
+	}
+}
+
+impl<C> DeserializeInto<C, AsMap> {
+	/// Deserialize a map, inserting each key-value pair in the collection.
+	pub fn map() -> Self {
+		DeserializeInto {
+			target: PhantomData,
+		}
+	}
+}
+
+impl<C, F> Clone for DeserializeInto<C, F> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<C, F> Copy for DeserializeInto<C, F> {}
+
+impl<'de, C: Default + PushBack> DeserializeSeed<'de> for DeserializeInto<C, AsSeq>
+where
+	C::Item: Deserialize<'de>,
+{
+	type Value = C;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<C, D::Error> {
+		deserializer.deserialize_seq(self)
+	}
+}
+
+impl<'de, C: Default + PushBack> Visitor<'de> for DeserializeInto<C, AsSeq>
+where
+	C::Item: Deserialize<'de>,
+{
+	type Value = C;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("a sequence")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+		let mut collection = C::default();
+		while let Some(item) = seq.next_element()? {
+			collection.push_back(item);
+		}
+
+		Ok(collection)
+	}
+}
+
+impl<'de, C: Default + Keyed + MapInsert<C::Key>> DeserializeSeed<'de> for DeserializeInto<C, AsMap>
+where
+	C::Key: Deserialize<'de>,
+	C::Item: Deserialize<'de>,
+{
+	type Value = C;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<C, D::Error> {
+		deserializer.deserialize_map(self)
+	}
+}
+
+impl<'de, C: Default + Keyed + MapInsert<C::Key>> Visitor<'de> for DeserializeInto<C, AsMap>
+where
+	C::Key: Deserialize<'de>,
+	C::Item: Deserialize<'de>,
+{
+	type Value = C;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("a map")
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<C, A::Error> {
+		let mut collection = C::default();
+		while let Some((key, value)) = map.next_entry()? {
+			collection.insert(key, value);
+		}
+
+		Ok(collection)
+	}
+}
@@ -0,0 +1,11 @@
+//! Traits describing sets.
+//!
+//! This module re-exports the traits of this crate
+//! that are relevant to unkeyed collections of distinct elements.
+pub use crate::{
+	Collection, CollectionRef, Contains, Insert, Iter, Len, RangeIter, Remove, Replace, Set,
+	SetCompare, SetEntryApi, SetEntryApiWith, SetInsert, SetMut, SetOps, SetPop, Take,
+};
+
+#[cfg(feature = "rayon")]
+pub use crate::{IntoParIter, ParExtend, ParIter};
@@ -0,0 +1,315 @@
+//! Conformance test-suite for implementors of the traits of this crate.
+//!
+//! Each function exercises the implicit contracts of a group of traits
+//! on a fresh collection, and panics if one of them is not upheld.
+//!
+//! ```
+//! use cc_traits::testsuite::{check_map_laws, check_seq_laws};
+//! use std::collections::{BTreeMap, HashMap};
+//!
+//! check_map_laws::<HashMap<_, _>, _, _>([(1, 'a'), (2, 'b'), (3, 'c')]);
+//! check_map_laws::<BTreeMap<_, _>, _, _>([(1, 'a'), (2, 'b'), (3, 'c')]);
+//! check_seq_laws::<Vec<_>>([1, 2, 3]);
+//! ```
+//!
+//! The `_in` variants check an existing (empty) collection instead,
+//! for collections without a [`Default`] implementation.
+//! Collections implementing only some of the required traits
+//! can be checked with the narrower functions,
+//! such as [`check_map_insert_laws_in`] or [`check_list_laws`].
+use crate::{
+	Back, Collection, Front, Get, GetMut, Iter, Keyed, Len, MapInsert, MapIter, PopBack, PushBack,
+	Remove,
+};
+use std::fmt::Debug;
+
+/// Checks the map laws of `M`, using the given key-value pairs.
+///
+/// This applies to every [`MapMut`](crate::MapMut), as well as to maps
+/// that cannot return their stored keys, such as the `Layered` adapter.
+/// The keys must be distinct.
+pub fn check_map_laws<M, K, V>(entries: impl IntoIterator<Item = (K, V)>)
+where
+	M: Default
+		+ Keyed<Key = K, Item = V>
+		+ Len
+		+ for<'a> Get<&'a K>
+		+ for<'a> GetMut<&'a K>
+		+ MapIter
+		+ MapInsert<K, Output = Option<V>>
+		+ for<'a> Remove<&'a K>,
+	K: Clone + PartialEq + Debug,
+	V: Clone + PartialEq + Debug,
+{
+	check_map_laws_in(&mut M::default(), entries)
+}
+
+/// Checks the map laws of the given empty `map`, using the given key-value pairs.
+///
+/// The keys must be distinct.
+pub fn check_map_laws_in<M, K, V>(map: &mut M, entries: impl IntoIterator<Item = (K, V)>)
+where
+	M: Keyed<Key = K, Item = V>
+		+ Len
+		+ for<'a> Get<&'a K>
+		+ for<'a> GetMut<&'a K>
+		+ MapIter
+		+ MapInsert<K, Output = Option<V>>
+		+ for<'a> Remove<&'a K>,
+	K: Clone + PartialEq + Debug,
+	V: Clone + PartialEq + Debug,
+{
+	let entries: Vec<_> = entries.into_iter().collect();
+	insert_entries(map, &entries);
+
+	let mut count = 0;
+	for (key, value) in map.iter() {
+		count += 1;
+		assert_eq!(
+			map.get(&key).as_deref(),
+			Some(&*value),
+			"iterated entries must agree with `get`"
+		);
+	}
+	assert_eq!(
+		count,
+		entries.len(),
+		"iteration must visit every entry once"
+	);
+
+	for (key, value) in &entries {
+		if let Some(mut item) = map.get_mut(key) {
+			*item = value.clone();
+		} else {
+			panic!("`get_mut` must return the value of key {:?}", key);
+		}
+	}
+
+	remove_entries(map, &entries);
+}
+
+/// Checks the insertion and removal laws of the given empty `map`,
+/// using the given key-value pairs.
+///
+/// Contrarily to [`check_map_laws_in`], this does not require the map
+/// to be iterable nor mutable in place.
+/// The keys must be distinct.
+pub fn check_map_insert_laws_in<M, K, V>(map: &mut M, entries: impl IntoIterator<Item = (K, V)>)
+where
+	M: Collection<Item = V>
+		+ Len
+		+ for<'a> Get<&'a K>
+		+ MapInsert<K, Output = Option<V>>
+		+ for<'a> Remove<&'a K>,
+	K: Clone + PartialEq + Debug,
+	V: Clone + PartialEq + Debug,
+{
+	let entries: Vec<_> = entries.into_iter().collect();
+	insert_entries(map, &entries);
+	remove_entries(map, &entries);
+}
+
+fn insert_entries<M, K, V>(map: &mut M, entries: &[(K, V)])
+where
+	M: Collection<Item = V> + Len + for<'a> Get<&'a K> + MapInsert<K, Output = Option<V>>,
+	K: Clone + PartialEq + Debug,
+	V: Clone + PartialEq + Debug,
+{
+	assert_eq!(map.len(), 0, "a new map must be empty");
+	assert!(map.is_empty(), "a new map must be empty");
+
+	for (i, (key, value)) in entries.iter().enumerate() {
+		assert!(!map.contains(key), "key {:?} present before insertion", key);
+		assert_eq!(
+			map.insert(key.clone(), value.clone()),
+			None,
+			"inserting a new key must not return a value"
+		);
+		assert_eq!(
+			map.len(),
+			i + 1,
+			"inserting a new key must increase the length"
+		);
+		assert_eq!(
+			map.get(key).as_deref(),
+			Some(value),
+			"a key must be bound to its inserted value"
+		);
+	}
+
+	for (key, value) in entries {
+		assert_eq!(
+			map.insert(key.clone(), value.clone()).as_ref(),
+			Some(value),
+			"inserting an existing key must return the previous value"
+		);
+	}
+	assert_eq!(
+		map.len(),
+		entries.len(),
+		"replacing a value must preserve the length"
+	);
+}
+
+fn remove_entries<M, K, V>(map: &mut M, entries: &[(K, V)])
+where
+	M: Collection<Item = V> + Len + for<'a> Get<&'a K> + for<'a> Remove<&'a K>,
+	K: Clone + PartialEq + Debug,
+	V: Clone + PartialEq + Debug,
+{
+	for (i, (key, value)) in entries.iter().enumerate() {
+		assert_eq!(
+			map.remove(key).as_ref(),
+			Some(value),
+			"removing a key must return its value"
+		);
+		assert!(!map.contains(key), "a removed key must not be present");
+		assert_eq!(
+			map.remove(key),
+			None,
+			"removing a key twice must return nothing"
+		);
+		assert_eq!(
+			map.len(),
+			entries.len() - i - 1,
+			"removing a key must decrease the length"
+		);
+	}
+
+	assert!(map.is_empty(), "removing every key must empty the map");
+}
+
+/// Checks the sequence laws of `S`, using the given items.
+pub fn check_seq_laws<S>(items: impl IntoIterator<Item = S::Item>)
+where
+	S: Default + Len + Front + Back + PushBack + PopBack + Get<usize> + Iter,
+	S::Item: Clone + PartialEq + Debug,
+{
+	check_seq_laws_in(&mut S::default(), items)
+}
+
+/// Checks the sequence laws of the given empty `seq`, using the given items.
+pub fn check_seq_laws_in<S>(seq: &mut S, items: impl IntoIterator<Item = S::Item>)
+where
+	S: Len + Front + Back + PushBack + PopBack + Get<usize> + Iter,
+	S::Item: Clone + PartialEq + Debug,
+{
+	let items: Vec<_> = items.into_iter().collect();
+	push_items(seq, &items);
+	check_indexed_laws(seq, &items);
+	pop_items(seq, &items);
+}
+
+/// Checks the sequence laws of `S`, using the given items,
+/// without requiring `S` to be indexable.
+pub fn check_list_laws<S>(items: impl IntoIterator<Item = S::Item>)
+where
+	S: Default + Len + Front + Back + PushBack + PopBack + Iter,
+	S::Item: Clone + PartialEq + Debug,
+{
+	let items: Vec<_> = items.into_iter().collect();
+	let mut seq = S::default();
+	push_items(&mut seq, &items);
+	check_iter_order(&seq, &items);
+	pop_items(&mut seq, &items);
+}
+
+/// Checks that the given `seq` holds exactly the given `items`, in order.
+///
+/// This only requires read access,
+/// so it also applies to fixed-size sequences such as arrays.
+pub fn check_indexed_laws<S>(seq: &S, items: &[S::Item])
+where
+	S: ?Sized + Len + Front + Back + Get<usize> + Iter,
+	S::Item: PartialEq + Debug,
+{
+	assert_eq!(seq.len(), items.len(), "the length must match the items");
+	assert_eq!(
+		seq.front().as_deref(),
+		items.first(),
+		"the front must be the first item"
+	);
+	assert_eq!(
+		seq.back().as_deref(),
+		items.last(),
+		"the back must be the last item"
+	);
+
+	for (i, item) in items.iter().enumerate() {
+		assert_eq!(
+			seq.get(i).as_deref(),
+			Some(item),
+			"items must be indexed in order"
+		);
+	}
+	assert!(
+		seq.get(items.len()).is_none(),
+		"out of bounds index must return nothing"
+	);
+
+	check_iter_order(seq, items);
+}
+
+fn check_iter_order<S>(seq: &S, items: &[S::Item])
+where
+	S: ?Sized + Iter,
+	S::Item: PartialEq + Debug,
+{
+	let mut iter = seq.iter();
+	for item in items {
+		assert_eq!(
+			iter.next().as_deref(),
+			Some(item),
+			"iteration must follow insertion order"
+		);
+	}
+	assert!(
+		iter.next().is_none(),
+		"iteration must visit every item once"
+	);
+}
+
+fn push_items<S>(seq: &mut S, items: &[S::Item])
+where
+	S: Len + Front + Back + PushBack,
+	S::Item: Clone + PartialEq + Debug,
+{
+	assert!(seq.is_empty(), "a new sequence must be empty");
+	assert!(seq.front().is_none(), "a new sequence must have no front");
+	assert!(seq.back().is_none(), "a new sequence must have no back");
+
+	for (i, item) in items.iter().enumerate() {
+		seq.push_back(item.clone());
+		assert_eq!(seq.len(), i + 1, "pushing must increase the length");
+		assert_eq!(
+			seq.back().as_deref(),
+			Some(item),
+			"the back must be the last pushed item"
+		);
+		assert_eq!(
+			seq.front().as_deref(),
+			items.first(),
+			"the front must be the first pushed item"
+		);
+	}
+}
+
+fn pop_items<S>(seq: &mut S, items: &[S::Item])
+where
+	S: Len + PopBack,
+	S::Item: PartialEq + Debug,
+{
+	for (i, item) in items.iter().enumerate().rev() {
+		assert_eq!(
+			seq.pop_back().as_ref(),
+			Some(item),
+			"popping must return the last pushed item"
+		);
+		assert_eq!(seq.len(), i, "popping must decrease the length");
+	}
+
+	assert!(
+		seq.pop_back().is_none(),
+		"popping an empty sequence must return nothing"
+	);
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "async")]
+use cc_traits::{AsyncGet, AsyncInsert, AsyncLen, AsyncRemove, Blocking};
+use std::{
+	collections::{BTreeMap, HashMap},
+	future::Future,
+	pin::pin,
+	task::{Context, Poll, Waker},
+};
+
+fn ready<F: Future>(f: F) -> F::Output {
+	match pin!(f).poll(&mut Context::from_waker(Waker::noop())) {
+		Poll::Ready(output) => output,
+		Poll::Pending => panic!("blocking futures are always ready"),
+	}
+}
+
+fn assert_send<F: Future + Send>(_: F) {}
+
+/// Generic code can send the futures to other threads (e.g. with `tokio::spawn`).
+fn spawnable<M>(map: &M)
+where
+	M: AsyncLen
+		+ for<'a> AsyncGet<&'a &'static str>
+		+ AsyncInsert<&'static str>
+		+ for<'a> AsyncRemove<&'a &'static str>,
+	M::Item: From<u8>,
+{
+	assert_send(map.len());
+	assert_send(map.is_empty());
+	assert_send(map.get(&"a"));
+	assert_send(map.contains(&"a"));
+	assert_send(map.insert("a", 1.into()));
+	assert_send(map.remove(&"a"));
+}
+
+#[test]
+fn blocking_bridges_sync_maps() {
+	let map: Blocking<HashMap<&str, u32>> = Blocking::default();
+	spawnable(&map);
+
+	assert_eq!(ready(map.insert("a", 1)).unwrap(), None);
+	assert_eq!(ready(map.insert("a", 2)).unwrap(), Some(1));
+	assert_eq!(ready(map.insert("b", 3)).unwrap(), None);
+	assert_eq!(ready(map.len()).unwrap(), 2);
+	assert_eq!(ready(map.get(&"a")).unwrap().map(|v| *v), Some(2));
+	assert!(ready(map.contains(&"b")).unwrap());
+	assert_eq!(ready(map.remove(&"b")).unwrap(), Some(3));
+	assert!(!ready(map.contains(&"b")).unwrap());
+	assert_eq!(map.into_inner().len(), 1);
+
+	let map: Blocking<BTreeMap<&str, u32>> = Blocking::new(BTreeMap::new());
+	spawnable(&map);
+	assert!(ready(map.is_empty()).unwrap());
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio_locks_are_spawnable() {
+	spawnable(&tokio::sync::RwLock::new(HashMap::<&str, u32>::new()));
+	spawnable(&tokio::sync::Mutex::new(HashMap::<&str, u32>::new()));
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio_mutex_refs_are_send() {
+	fn assert_send_ref<T: Send>(_: T) {}
+
+	let map = tokio::sync::Mutex::new(HashMap::from([("a", 1)]));
+	let a = ready(map.get(&"a")).unwrap().unwrap();
+	assert_eq!(*a, 1);
+	assert_send_ref(a);
+}
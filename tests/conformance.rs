@@ -0,0 +1,215 @@
+//! Runs the conformance test-suite over every backend and adapter of the crate.
+#![cfg(feature = "testing")]
+use cc_traits::{
+	testsuite::{
+		check_indexed_laws, check_list_laws, check_map_insert_laws_in, check_map_laws,
+		check_map_laws_in, check_seq_laws, check_seq_laws_in,
+	},
+	ArcMap, BoxedMap, Get, Layered, Len, MapInsert, MapIter, Remove, Transactional,
+};
+use std::{
+	borrow::Cow,
+	collections::{BTreeMap, HashMap, LinkedList},
+};
+
+fn entries() -> Vec<(u32, char)> {
+	vec![(1, 'a'), (2, 'b'), (3, 'c'), (10, 'd')]
+}
+
+fn items() -> Vec<u32> {
+	vec![1, 2, 3, 1]
+}
+
+#[test]
+fn std_maps() {
+	check_map_laws::<HashMap<_, _>, _, _>(entries());
+	check_map_laws::<BTreeMap<_, _>, _, _>(entries());
+}
+
+#[test]
+fn std_seqs() {
+	check_seq_laws::<Vec<_>>(items());
+	check_list_laws::<LinkedList<_>>(items());
+	check_indexed_laws(&[1, 2, 3, 1], &items());
+	check_indexed_laws::<[u32; 0]>(&[], &[]);
+}
+
+#[test]
+fn pointers() {
+	check_map_laws::<Box<HashMap<_, _>>, _, _>(entries());
+	check_map_laws::<Cow<HashMap<_, _>>, _, _>(entries());
+	check_map_laws_in(&mut &mut HashMap::new(), entries());
+	check_seq_laws::<Box<Vec<_>>>(items());
+	check_seq_laws::<Cow<Vec<_>>>(items());
+	check_seq_laws_in(&mut &mut Vec::new(), items());
+}
+
+#[test]
+fn adapters() {
+	check_map_laws::<ArcMap<HashMap<_, _>>, _, _>(entries());
+	check_seq_laws::<ArcMap<Vec<_>>>(items());
+	check_map_insert_laws_in(&mut BoxedMap::new(HashMap::new()), entries());
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn either() {
+	use either::Either;
+
+	check_map_laws_in(
+		&mut Either::<HashMap<_, _>, BTreeMap<_, _>>::Left(HashMap::new()),
+		entries(),
+	);
+	check_map_laws_in(
+		&mut Either::<HashMap<_, _>, BTreeMap<_, _>>::Right(BTreeMap::new()),
+		entries(),
+	);
+	check_seq_laws_in(
+		&mut Either::<Vec<_>, Box<Vec<_>>>::Right(Box::default()),
+		items(),
+	);
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn indexmap() {
+	check_map_laws::<indexmap::IndexMap<_, _>, _, _>(entries());
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec() {
+	check_seq_laws::<smallvec::SmallVec<[_; 2]>>(items());
+}
+
+#[cfg(feature = "im")]
+#[test]
+fn im() {
+	check_map_laws::<im::HashMap<_, _>, _, _>(entries());
+	check_map_laws::<im::OrdMap<_, _>, _, _>(entries());
+	check_seq_laws::<im::Vector<_>>(items());
+}
+
+#[cfg(feature = "im-rc")]
+#[test]
+fn im_rc() {
+	check_map_laws::<im_rc::HashMap<_, _>, _, _>(entries());
+	check_map_laws::<im_rc::OrdMap<_, _>, _, _>(entries());
+	check_seq_laws::<im_rc::Vector<_>>(items());
+}
+
+#[cfg(feature = "rpds")]
+#[test]
+fn rpds() {
+	check_map_laws::<rpds::HashTrieMap<_, _>, _, _>(entries());
+	check_map_laws::<rpds::RedBlackTreeMap<_, _>, _, _>(entries());
+	check_seq_laws::<rpds::Vector<_>>(items());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn serde_json() {
+	use serde_json::Value;
+
+	check_map_laws::<serde_json::Map<_, _>, _, _>(vec![
+		("a".to_string(), Value::from(1)),
+		("b".to_string(), Value::from("b")),
+		("c".to_string(), Value::Null),
+	]);
+}
+
+#[cfg(feature = "ijson")]
+#[test]
+fn ijson() {
+	use ijson::{IObject, IString, IValue};
+
+	check_map_laws::<IObject, _, _>(vec![
+		(IString::from("a"), IValue::from(1)),
+		(IString::from("b"), IValue::from("b")),
+		(IString::from("c"), IValue::NULL),
+	]);
+}
+
+/// `Layered` maps return the output of the top layer on insertion,
+/// so they do not fit `check_map_laws`.
+/// The same laws are checked here, with entries starting in the bottom layer.
+#[test]
+fn layered() {
+	let bottom: HashMap<_, _> = entries().into_iter().collect();
+	let mut map = Layered::new(HashMap::new(), bottom.clone());
+	check_layered_laws(&mut map, &bottom);
+	assert_eq!(map.bottom(), &bottom, "the bottom layer must be untouched");
+
+	let mut map: Transactional<_> = Transactional::new(bottom.clone());
+	check_layered_laws(&mut map, &bottom);
+	assert_eq!(map.inner(), &bottom, "the wrapped map must be untouched");
+	map.commit();
+	let committed: HashMap<_, _> = vec![(1, 'a')].into_iter().collect();
+	assert_eq!(
+		map.inner(),
+		&committed,
+		"committing must apply the modifications"
+	);
+}
+
+fn check_layered_laws<M>(map: &mut M, bottom: &HashMap<u32, char>)
+where
+	M: Len
+		+ MapIter<Key = u32, Item = char>
+		+ for<'a> Get<&'a u32>
+		+ MapInsert<u32, Output = Option<Option<char>>>
+		+ for<'a> Remove<&'a u32>,
+{
+	assert_eq!(
+		map.len(),
+		bottom.len(),
+		"the bottom entries must be visible"
+	);
+	for (key, value) in map.iter() {
+		assert_eq!(bottom.get(&*key), Some(&*value));
+	}
+
+	for (key, value) in bottom {
+		assert_eq!(map.get(key).as_deref(), Some(value));
+		assert_eq!(map.insert(*key, 'z'), None, "the top layer starts empty");
+		assert_eq!(map.insert(*key, *value), Some(Some('z')));
+	}
+	assert_eq!(
+		map.len(),
+		bottom.len(),
+		"overriding must preserve the length"
+	);
+
+	for (i, (key, value)) in bottom.iter().enumerate() {
+		assert_eq!(map.remove(key).as_ref(), Some(value));
+		assert!(!map.contains(key), "a removed key must not be present");
+		assert_eq!(
+			map.remove(key),
+			None,
+			"removing a key twice must return nothing"
+		);
+		assert_eq!(map.len(), bottom.len() - i - 1);
+	}
+	assert!(map.is_empty(), "removing every key must empty the map");
+	assert_eq!(map.insert(1, 'a'), Some(None), "inserting over a tombstone");
+}
+
+#[test]
+fn transactional_clear() {
+	use cc_traits::{Clear, GetKeyValue};
+
+	let inner: HashMap<_, _> = entries().into_iter().collect();
+	let mut map: Transactional<_> = Transactional::new(inner.clone());
+	assert_eq!(map.get_key_value(&1).map(|(k, v)| (*k, *v)), Some((1, 'a')));
+
+	map.clear();
+	assert!(map.is_empty(), "clearing must hide the wrapped entries");
+	assert!(map.get_key_value(&1).is_none());
+	assert_eq!(map.inner(), &inner, "the wrapped map must be untouched");
+
+	map.insert(2, 'z');
+	assert_eq!(map.get_key_value(&2).map(|(k, v)| (*k, *v)), Some((2, 'z')));
+	map.commit();
+	let committed: HashMap<_, _> = vec![(2, 'z')].into_iter().collect();
+	assert_eq!(map.inner(), &committed, "committing must replay the clear");
+}
@@ -0,0 +1,65 @@
+use cc_traits::{Counter, MapInsert};
+use std::collections::{BTreeMap, HashMap};
+
+fn check_counter<C>(mut counter: C)
+where
+	C: Counter<Key = &'static str, Item = u32> + MapInsert<&'static str>,
+{
+	assert_eq!(counter.count(&"a"), 0);
+	assert_eq!(counter.increment("a"), 1);
+	assert_eq!(counter.increment("a"), 2);
+	assert_eq!(counter.increment("b"), 1);
+	assert_eq!(counter.count(&"a"), 2);
+
+	assert_eq!(counter.decrement(&"a"), 1);
+	assert_eq!(counter.decrement(&"a"), 0);
+	assert_eq!(counter.count(&"a"), 0);
+	assert_eq!(counter.decrement(&"a"), 0, "absent keys stay at zero");
+	assert_eq!(counter.decrement(&"c"), 0);
+
+	counter.insert("z", 0);
+	assert_eq!(
+		counter.decrement(&"z"),
+		0,
+		"a stored zero must not underflow"
+	);
+	assert_eq!(counter.count(&"z"), 0);
+	assert_eq!(counter.increment("z"), 1);
+
+	assert_eq!(counter.count(&"b"), 1);
+}
+
+#[test]
+fn hashmap_counter() {
+	let mut map = HashMap::new();
+	check_counter(&mut map);
+	assert_eq!(map.len(), 2, "zero counts must be removed");
+	assert!(!map.contains_key("a"));
+}
+
+#[test]
+fn btreemap_counter() {
+	let mut map = BTreeMap::new();
+	check_counter(&mut map);
+	assert_eq!(map.into_iter().collect::<Vec<_>>(), [("b", 1), ("z", 1)]);
+}
+
+#[test]
+fn stored_zero_is_removed() {
+	let mut map: HashMap<&str, u32> = HashMap::new();
+	map.insert("a", 0);
+	assert_eq!(map.decrement(&"a"), 0);
+	assert!(map.is_empty());
+}
+
+#[test]
+fn signed_counts() {
+	let mut map: HashMap<char, i64> = HashMap::new();
+	for c in "hello".chars() {
+		map.increment(c);
+	}
+
+	assert_eq!(map.count(&'l'), 2);
+	assert_eq!(map.decrement(&'h'), 0);
+	assert_eq!(map.len(), 3);
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "derive")]
+use cc_traits::{
+	Collection, CollectionMut, CollectionRef, Iter, IterMut, Keyed, KeyedRef, MapIter, MapIterMut,
+};
+use std::collections::{BTreeMap, HashMap, LinkedList};
+
+#[derive(Collection, CollectionRef, CollectionMut, Iter, IterMut)]
+enum Values {
+	Vec(Vec<u32>),
+	List(LinkedList<u32>),
+}
+
+#[derive(Collection, CollectionRef, CollectionMut, Keyed, KeyedRef, MapIter, MapIterMut)]
+enum Index {
+	Hashed(HashMap<u32, char>),
+	Sorted { map: BTreeMap<u32, char> },
+}
+
+#[test]
+fn enum_iterators_dispatch_to_each_variant() {
+	for mut values in [
+		Values::Vec(vec![1, 2, 3]),
+		Values::List(LinkedList::from([1, 2, 3])),
+	] {
+		for v in values.iter_mut() {
+			*v *= 10;
+		}
+
+		assert_eq!(values.iter().copied().collect::<Vec<_>>(), [10, 20, 30]);
+		assert_eq!(values.iter().next_back().copied(), Some(30));
+		assert_eq!(values.iter().size_hint(), (3, Some(3)));
+	}
+}
+
+#[test]
+fn enum_map_iterators_dispatch_to_each_variant() {
+	let entries = [(1, 'a'), (2, 'b')];
+	for mut index in [
+		Index::Hashed(HashMap::from(entries)),
+		Index::Sorted {
+			map: BTreeMap::from(entries),
+		},
+	] {
+		for (_, v) in MapIterMut::iter_mut(&mut index) {
+			*v = v.to_ascii_uppercase();
+		}
+
+		let mut collected: Vec<_> = MapIter::iter(&index).map(|(k, v)| (*k, *v)).collect();
+		collected.sort();
+		assert_eq!(collected, [(1, 'A'), (2, 'B')]);
+	}
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn vec_and_smallvec() {
+	use smallvec::SmallVec;
+
+	#[derive(Collection, CollectionRef, CollectionMut, Iter, IterMut)]
+	enum Buffer {
+		Heap(Vec<u8>),
+		Inline(SmallVec<[u8; 4]>),
+	}
+
+	let mut buffer = Buffer::Inline(SmallVec::from_slice(&[1, 2]));
+	for b in buffer.iter_mut() {
+		*b += 1;
+	}
+	assert_eq!(buffer.iter().copied().sum::<u8>(), 5);
+	assert!(matches!(buffer.iter(), BufferIter::Inline(_)));
+
+	buffer = Buffer::Heap(vec![1]);
+	assert_eq!(buffer.iter().count(), 1);
+	assert!(matches!(buffer.iter_mut(), BufferIterMut::Heap(_)));
+}
@@ -0,0 +1,79 @@
+use cc_traits::MapDrainRange;
+use std::{collections::BTreeMap, ops::Bound};
+
+fn map() -> BTreeMap<u32, char> {
+	vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e')]
+		.into_iter()
+		.collect()
+}
+
+/// Drains `range` from a fresh map, returning the drained and the remaining keys.
+fn drain<R>(range: R) -> (Vec<u32>, Vec<u32>)
+where
+	BTreeMap<u32, char>: MapDrainRange<R, Key = u32, Item = char>,
+{
+	let mut map = map();
+	let drained: Vec<_> = map.drain_range(range).map(|(key, _)| key).collect();
+	(drained, map.into_keys().collect())
+}
+
+#[test]
+fn inclusive_bounds() {
+	assert_eq!(drain(2..=4), (vec![2, 3, 4], vec![1, 5]));
+	assert_eq!(drain(3..=3), (vec![3], vec![1, 2, 4, 5]));
+	assert_eq!(drain(0..=10), (vec![1, 2, 3, 4, 5], vec![]));
+}
+
+#[test]
+fn exclusive_bounds() {
+	assert_eq!(drain(2..4), (vec![2, 3], vec![1, 4, 5]));
+	assert_eq!(
+		drain((Bound::Excluded(2), Bound::Excluded(5))),
+		(vec![3, 4], vec![1, 2, 5])
+	);
+	assert_eq!(
+		drain((Bound::Excluded(2), Bound::Included(4))),
+		(vec![3, 4], vec![1, 2, 5])
+	);
+}
+
+#[test]
+fn unbounded() {
+	assert_eq!(drain(..3), (vec![1, 2], vec![3, 4, 5]));
+	assert_eq!(drain(..=3), (vec![1, 2, 3], vec![4, 5]));
+	assert_eq!(drain(3..), (vec![3, 4, 5], vec![1, 2]));
+	assert_eq!(
+		drain((Bound::Excluded(3), Bound::Unbounded)),
+		(vec![4, 5], vec![1, 2, 3])
+	);
+	assert_eq!(drain(..), (vec![1, 2, 3, 4, 5], vec![]));
+}
+
+#[test]
+fn empty_ranges() {
+	assert_eq!(drain(3..3), (vec![], vec![1, 2, 3, 4, 5]));
+	assert_eq!(
+		drain((Bound::Excluded(3), Bound::Excluded(4))),
+		(vec![], vec![1, 2, 3, 4, 5])
+	);
+	assert_eq!(drain(6..), (vec![], vec![1, 2, 3, 4, 5]));
+
+	let mut empty = BTreeMap::<u32, char>::new();
+	assert_eq!(empty.drain_range(..).count(), 0);
+}
+
+#[test]
+fn reversed_bounds() {
+	assert_eq!(
+		drain((Bound::Included(4), Bound::Excluded(2))),
+		(vec![], vec![1, 2, 3, 4, 5])
+	);
+	assert_eq!(
+		drain((Bound::Excluded(4), Bound::Included(2))),
+		(vec![], vec![1, 2, 3, 4, 5])
+	);
+	assert_eq!(
+		drain((Bound::Excluded(3), Bound::Excluded(3))),
+		(vec![], vec![1, 2, 3, 4, 5])
+	);
+}
@@ -0,0 +1,32 @@
+use cc_traits::{Get, Len};
+use std::{
+	collections::HashMap,
+	sync::{mpsc, Arc, Mutex},
+	thread,
+};
+
+#[test]
+fn mutex_queries_wait_for_other_threads() {
+	let map = Arc::new(Mutex::new(HashMap::from([(1u32, 1u32)])));
+	let (locked, wait_locked) = mpsc::channel();
+	let (release, wait_release) = mpsc::channel::<()>();
+
+	let holder = {
+		let map = map.clone();
+		thread::spawn(move || {
+			let _guard = map.lock().unwrap();
+			locked.send(()).unwrap();
+			wait_release.recv().unwrap();
+		})
+	};
+
+	wait_locked.recv().unwrap();
+	let reader = {
+		let map = map.clone();
+		thread::spawn(move || (Len::len(&*map), Get::get(&*map, &1).map(|v| *v)))
+	};
+	release.send(()).unwrap();
+
+	assert_eq!(reader.join().unwrap(), (1, Some(1)));
+	holder.join().unwrap();
+}
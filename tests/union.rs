@@ -0,0 +1,35 @@
+use cc_traits::{Combine, Get, Len, Union};
+use std::{cell::Cell, collections::HashMap};
+
+#[test]
+fn len_does_not_resolve_conflicts() {
+	let a: HashMap<_, _> = vec![(1, 1), (2, 2)].into_iter().collect();
+	let b: HashMap<_, _> = vec![(2, 20), (3, 30)].into_iter().collect();
+	let calls = Cell::new(0);
+	let union = Union::with_policy(
+		a,
+		b,
+		Combine(|a: &i32, b: &i32| {
+			calls.set(calls.get() + 1);
+			a + b
+		}),
+	);
+
+	assert_eq!(union.len(), 3);
+	assert!(!union.is_empty());
+	assert_eq!(
+		calls.get(),
+		0,
+		"computing the length must not combine values"
+	);
+
+	assert_eq!(union.get(&2).map(|v| *v), Some(22));
+	assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn empty_union() {
+	let union = Union::new(HashMap::<u32, u32>::new(), HashMap::<u32, u32>::new());
+	assert_eq!(union.len(), 0);
+	assert!(union.is_empty());
+}
@@ -0,0 +1,81 @@
+use cc_traits::{Get, Layered, Len, Update};
+use std::collections::{BTreeMap, HashMap};
+
+#[test]
+fn update_std_maps() {
+	let mut map: HashMap<&str, i32> = HashMap::new();
+	map.update("a", |v| v.or(Some(1)));
+	map.update("a", |v| v.map(|x| x + 10));
+	map.update("b", |_| None);
+	assert_eq!(map.get("a"), Some(&11));
+	assert_eq!(map.len(), 1);
+	map.update("a", |_| None);
+	assert!(map.is_empty());
+
+	let mut map: BTreeMap<&str, i32> = vec![("a", 1), ("b", 2)].into_iter().collect();
+	map.update("a", |v| v.map(|x| x * 3));
+	map.update("b", |_| None);
+	assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 3)]);
+}
+
+#[test]
+fn update_layered_leaves_tombstones() {
+	let bottom: HashMap<&str, i32> = vec![("a", 1), ("b", 2)].into_iter().collect();
+	let mut map = Layered::new(HashMap::new(), bottom);
+
+	map.update("a", |v| v.map(|x| x + 10));
+	map.update("b", |_| None);
+	map.update("c", |_| None);
+	assert_eq!(map.get(&"a").map(|v| *v), Some(11));
+	assert!(map.get(&"b").is_none());
+	assert_eq!(Len::len(&map), 1);
+	assert_eq!(map.top().len(), 2);
+	assert_eq!(map.bottom().len(), 2);
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn update_preserves_indexmap_order() {
+	use indexmap::IndexMap;
+
+	let mut map: IndexMap<&str, i32> = vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)]
+		.into_iter()
+		.collect();
+
+	map.update("a", |v| v.map(|x| x + 10));
+	assert_eq!(
+		map.keys().copied().collect::<Vec<_>>(),
+		["a", "b", "c", "d"]
+	);
+	assert_eq!(map["a"], 11);
+
+	map.update("d", |v| v.map(|x| x + 10));
+	assert_eq!(
+		map.keys().copied().collect::<Vec<_>>(),
+		["a", "b", "c", "d"]
+	);
+
+	map.update("b", |_| None);
+	assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["a", "c", "d"]);
+
+	map.update("e", |v| v.or(Some(5)));
+	assert_eq!(
+		map.keys().copied().collect::<Vec<_>>(),
+		["a", "c", "d", "e"]
+	);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn update_serde_json_map() {
+	let mut map = serde_json::Map::new();
+	map.insert("a".to_string(), 1.into());
+	map.update("a".to_string(), |v| {
+		v.map(|x| (x.as_i64().unwrap() + 1).into())
+	});
+	map.update("b".to_string(), |_| Some(true.into()));
+	assert_eq!(
+		serde_json::Value::Object(map),
+		serde_json::json!({"a": 2, "b": true})
+	);
+}